@@ -17,7 +17,7 @@ fn main() -> bool {
     // A more proper predicate would read the signature from the `predicateData`
     let hi = 0xbd0c9b8792876713afa8bff383eebf31c43437823ed761cc3600d0016de5110c;
     let lo = 0x44ac566bd156b4fc71a4a4cb2655d3dd360c695edb17dc3b64d611e122fea23d;
-    let signature: B512 = ~B512::from(hi, lo);
+    let signature: B512 = ~B512::from((hi, lo));
 
     let msg_hash = eip191_hash(tx_id());
 
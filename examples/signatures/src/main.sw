@@ -11,7 +11,7 @@ const MSG_HASH = 0xee45573606c96c98ba970ff7cf9511f1b8b25e6bcd52ced30b89df1e4a9c4
 fn main() {
     let hi = 0xbd0c9b8792876713afa8bff383eebf31c43437823ed761cc3600d0016de5110c;
     let lo = 0x44ac566bd156b4fc71a4a4cb2655d3dd360c695edb17dc3b64d611e122fea23d;
-    let signature: B512 = ~B512::from(hi, lo);
+    let signature: B512 = ~B512::from((hi, lo));
 
     // A recovered public key pair.
     let public_key = ec_recover(signature, MSG_HASH);
@@ -7,6 +7,7 @@ use forc_util::{
     find_file_name, git_checkouts_directory, kebab_to_snake_case, print_on_failure,
     print_on_success, print_on_success_library, println_yellow_err,
 };
+use fuel_tx::StorageSlot;
 use fuels_types::JsonABI;
 use petgraph::{self, visit::EdgeRef, Directed, Direction};
 use serde::{Deserialize, Serialize};
@@ -18,7 +19,7 @@ use std::{
 };
 use sway_core::{
     semantic_analysis::namespace, source_map::SourceMap, BytecodeCompilationResult,
-    CompileAstResult, CompileError, TreeType, TypedParseTree,
+    CompilationResult, CompileAstResult, CompileError, CompileResult, TreeType, TypedParseTree,
 };
 use sway_utils::constants;
 use url::Url;
@@ -41,6 +42,7 @@ pub struct PinnedId(u64);
 /// The result of successfully compiling a package.
 pub struct Compiled {
     pub json_abi: JsonABI,
+    pub storage_slots: Vec<StorageSlot>,
     pub bytecode: Vec<u8>,
 }
 
@@ -1025,18 +1027,48 @@ pub fn compile(
         CompileAstResult::Success {
             parse_tree,
             tree_type,
-            warnings,
+            ..
         } => {
             let json_abi = generate_json_abi(&*parse_tree);
+            let storage_slots_res = parse_tree.get_storage_slots();
+            let storage_slots = match storage_slots_res.value {
+                Some(storage_slots) => storage_slots,
+                None => {
+                    let CompileResult {
+                        warnings, errors, ..
+                    } = storage_slots_res;
+                    print_on_failure(silent_mode, &warnings, &errors);
+                    bail!("Failed to compile {}", pkg.name);
+                }
+            };
             match tree_type {
-                // If we're compiling a library, we don't need to compile any further.
-                // Instead, we update the namespace with the library's top-level module.
+                // If we're compiling a library, we only lower it to IR so that it is verified
+                // once here rather than within every package that depends upon it. We then update
+                // the namespace with the library's top-level module.
                 TreeType::Library { .. } => {
-                    print_on_success_library(silent_mode, &pkg.name, warnings);
-                    let bytecode = vec![];
-                    let lib_namespace = parse_tree.namespace().clone();
-                    let compiled = Compiled { json_abi, bytecode };
-                    Ok((compiled, Some(lib_namespace.into())))
+                    match sway_core::ast_to_asm(ast_res, &sway_build_config) {
+                        CompilationResult::Library {
+                            namespace,
+                            warnings,
+                            ..
+                        } => {
+                            print_on_success_library(silent_mode, &pkg.name, &warnings);
+                            let bytecode = vec![];
+                            let compiled = Compiled {
+                                json_abi,
+                                storage_slots,
+                                bytecode,
+                            };
+                            Ok((compiled, Some(*namespace)))
+                        }
+                        CompilationResult::Success { .. } => {
+                            bail!("Library {} unexpectedly compiled to asm", pkg.name);
+                        }
+                        CompilationResult::Failure { warnings, errors } => {
+                            print_on_failure(silent_mode, &warnings, &errors);
+                            bail!("Failed to compile {}", pkg.name);
+                        }
+                    }
                 }
 
                 // For all other program types, we'll compile the bytecode.
@@ -1048,7 +1080,11 @@ pub fn compile(
                         BytecodeCompilationResult::Success { bytes, warnings } => {
                             print_on_success(silent_mode, &pkg.name, &warnings, &tree_type);
                             let bytecode = bytes;
-                            let compiled = Compiled { json_abi, bytecode };
+                            let compiled = Compiled {
+                                json_abi,
+                                storage_slots,
+                                bytecode,
+                            };
                             Ok((compiled, None))
                         }
                         BytecodeCompilationResult::Library { .. } => {
@@ -1078,6 +1114,7 @@ pub fn build(
     let mut namespace_map = Default::default();
     let mut source_map = SourceMap::new();
     let mut json_abi = vec![];
+    let mut storage_slots = vec![];
     let mut bytecode = vec![];
     for &node in &plan.compilation_order {
         let dep_namespace =
@@ -1091,10 +1128,15 @@ pub fn build(
             namespace_map.insert(node, namespace.into());
         }
         json_abi.extend(compiled.json_abi);
+        storage_slots = compiled.storage_slots;
         bytecode = compiled.bytecode;
         source_map.insert_dependency(path.clone());
     }
-    let compiled = Compiled { bytecode, json_abi };
+    let compiled = Compiled {
+        bytecode,
+        json_abi,
+        storage_slots,
+    };
     Ok((compiled, source_map))
 }
 
@@ -6,6 +6,7 @@ use std::{
     fs::{self, File},
     path::PathBuf,
 };
+use sway_core::TreeType;
 use tracing::info;
 
 pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
@@ -120,6 +121,12 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
         };
         res?;
     }
+    if let TreeType::Contract = manifest.program_type()? {
+        let storage_slots_stem = format!("{}-storage_slots", manifest.project.name);
+        let storage_slots_path = output_dir.join(&storage_slots_stem).with_extension("json");
+        let file = File::create(storage_slots_path)?;
+        serde_json::to_writer_pretty(&file, &compiled.storage_slots)?;
+    }
 
     info!("  Bytecode size is {} bytes.", compiled.bytecode.len());
 
@@ -6,7 +6,7 @@ use crate::{
 use anyhow::{bail, Result};
 use forc_pkg::ManifestFile;
 use fuel_gql_client::client::FuelClient;
-use fuel_tx::{Output, Salt, Transaction};
+use fuel_tx::{Output, Salt, StorageSlot, Transaction};
 use fuel_vm::prelude::*;
 use std::path::PathBuf;
 use sway_core::TreeType;
@@ -58,6 +58,7 @@ pub async fn deploy(command: DeployCommand) -> Result<fuel_tx::ContractId> {
         compiled.bytecode,
         Vec::<fuel_tx::Input>::new(),
         Vec::<fuel_tx::Output>::new(),
+        compiled.storage_slots,
     );
 
     let node_url = match &manifest.network {
@@ -85,6 +86,7 @@ fn create_contract_tx(
     compiled_contract: Vec<u8>,
     inputs: Vec<Input>,
     outputs: Vec<Output>,
+    storage_slots: Vec<StorageSlot>,
 ) -> (Transaction, fuel_tx::ContractId) {
     let gas_price = 0;
     let gas_limit = fuel_tx::consts::MAX_GAS_PER_TX;
@@ -95,11 +97,10 @@ fn create_contract_tx(
 
     let salt = Salt::new([0; 32]);
     let static_contracts = vec![];
-    let storage_slots = vec![];
 
     let contract = Contract::from(compiled_contract);
     let root = contract.root();
-    let state_root = Contract::default_state_root();
+    let state_root = Contract::initial_state_root(storage_slots.iter());
     let id = contract.id(&salt, &root, &state_root);
     info!("Contract id: 0x{}", hex::encode(id));
     let outputs = [
@@ -55,6 +55,7 @@ pub(crate) fn convert_expression_to_asm(
             arguments,
             function_body,
             selector,
+            ..
         } => {
             if let Some(metadata) = selector {
                 convert_contract_call_to_asm(
@@ -357,6 +358,20 @@ pub(crate) fn convert_expression_to_asm(
                 register_sequencer,
                 exp.span.clone(),
             ),
+            BuiltinProperty::StrLen => convert_str_len_to_asm(
+                type_id,
+                namespace,
+                return_register,
+                register_sequencer,
+                exp.span.clone(),
+            ),
+            BuiltinProperty::ConstGenericValue => convert_const_generic_value_to_asm(
+                type_id,
+                namespace,
+                return_register,
+                register_sequencer,
+                exp.span.clone(),
+            ),
         },
         TypedExpressionVariant::SizeOfValue { expr } => convert_size_of_to_asm(
             Some(expr),
@@ -476,6 +491,72 @@ fn convert_is_ref_type_to_asm(
     ok(asm_buf, warnings, errors)
 }
 
+fn convert_str_len_to_asm(
+    type_id: &TypeId,
+    namespace: &mut AsmNamespace,
+    return_register: &VirtualRegister,
+    register_sequencer: &mut RegisterSequencer,
+    span: Span,
+) -> CompileResult<Vec<Op>> {
+    let warnings = vec![];
+    let mut errors = vec![];
+    let mut asm_buf = vec![Op::new_comment("str_len".to_string())];
+    let len = match resolve_type(*type_id, &span) {
+        Ok(TypeInfo::Str(len)) => len,
+        Ok(other) => {
+            errors.push(CompileError::StrLenNotStr {
+                ty: other.friendly_type_str(),
+                span,
+            });
+            return err(warnings, errors);
+        }
+        Err(e) => {
+            errors.push(e.into());
+            return err(warnings, errors);
+        }
+    };
+    let mut ops = convert_literal_to_asm(
+        &Literal::U64(len),
+        namespace,
+        return_register,
+        register_sequencer,
+        span,
+    );
+    asm_buf.append(&mut ops);
+    ok(asm_buf, warnings, errors)
+}
+
+fn convert_const_generic_value_to_asm(
+    type_id: &TypeId,
+    namespace: &mut AsmNamespace,
+    return_register: &VirtualRegister,
+    register_sequencer: &mut RegisterSequencer,
+    span: Span,
+) -> CompileResult<Vec<Op>> {
+    let warnings = vec![];
+    let mut errors = vec![];
+    let mut asm_buf = vec![Op::new_comment("const generic value".to_string())];
+    let value = match look_up_type_id(*type_id) {
+        TypeInfo::ConstGenericValue(value) => value,
+        _ => {
+            errors.push(CompileError::Internal(
+                "Const generic value has not been inferred.",
+                span,
+            ));
+            return err(warnings, errors);
+        }
+    };
+    let mut ops = convert_literal_to_asm(
+        &Literal::U64(value as u64),
+        namespace,
+        return_register,
+        register_sequencer,
+        span,
+    );
+    asm_buf.append(&mut ops);
+    ok(asm_buf, warnings, errors)
+}
+
 fn convert_size_of_to_asm(
     expr: Option<&TypedExpression>,
     type_id: &TypeId,
@@ -51,11 +51,15 @@ pub fn compile_ir_to_asm(ir: &Context, build_config: &BuildConfig) -> CompileRes
             program_section: AbstractInstructionSet { ops: bytecode },
             data_section,
         },
+        Kind::Predicate => SwayAsmSet::PredicateMain {
+            program_section: AbstractInstructionSet { ops: bytecode },
+            data_section,
+        },
         Kind::Contract => SwayAsmSet::ContractAbi {
             program_section: AbstractInstructionSet { ops: bytecode },
             data_section,
         },
-        Kind::Library | Kind::Predicate => todo!("libraries and predicates coming soon!"),
+        Kind::Library => todo!("libraries coming soon!"),
     };
 
     if build_config.print_intermediate_asm {
@@ -88,8 +92,10 @@ fn compile_module_to_asm(
 ) -> CompileResult<(DataSection, Vec<Op>, RegisterSequencer)> {
     let mut builder = AsmBuilder::new(DataSection::default(), reg_seqr, context);
     match module.get_kind(context) {
-        Kind::Script => {
+        Kind::Script | Kind::Predicate => {
             // We can't do function calls yet, so we expect everything to be inlined into `main`.
+            // Predicates follow the same convention as scripts, returning their `bool` result
+            // from `main` via `RET`.
             let function = module
                 .function_iter(context)
                 .find(|func| &context.functions[func.0].name == "main")
@@ -134,7 +140,7 @@ fn compile_module_to_asm(
                 errors,
             )
         }
-        Kind::Library | Kind::Predicate => todo!("libraries and predicates coming soon!"),
+        Kind::Library => todo!("libraries coming soon!"),
     }
 }
 
@@ -465,6 +471,9 @@ impl<'ir> AsmBuilder<'ir> {
                         errors
                     )
                 }
+                Instruction::BinaryOp { op, lhs, rhs } => {
+                    self.compile_binary_op(instr_val, op, lhs, rhs)
+                }
                 Instruction::BitCast(val, ty) => self.compile_bitcast(instr_val, val, ty),
                 Instruction::Branch(to_block) => self.compile_branch(block, to_block),
                 Instruction::Call(..) => {
@@ -720,6 +729,36 @@ impl<'ir> AsmBuilder<'ir> {
         ok((), warnings, errors)
     }
 
+    fn compile_binary_op(
+        &mut self,
+        instr_val: &Value,
+        op: &BinaryOpKind,
+        lhs_value: &Value,
+        rhs_value: &Value,
+    ) {
+        let lhs_reg = self.value_to_register(lhs_value);
+        let rhs_reg = self.value_to_register(rhs_value);
+        let res_reg = self.reg_seqr.next();
+        let opcode = match op {
+            BinaryOpKind::Add => VirtualOp::ADD(res_reg.clone(), lhs_reg, rhs_reg),
+            BinaryOpKind::Sub => VirtualOp::SUB(res_reg.clone(), lhs_reg, rhs_reg),
+            BinaryOpKind::Mul => VirtualOp::MUL(res_reg.clone(), lhs_reg, rhs_reg),
+            BinaryOpKind::Div => VirtualOp::DIV(res_reg.clone(), lhs_reg, rhs_reg),
+            BinaryOpKind::Mod => VirtualOp::MOD(res_reg.clone(), lhs_reg, rhs_reg),
+            BinaryOpKind::And => VirtualOp::AND(res_reg.clone(), lhs_reg, rhs_reg),
+            BinaryOpKind::Or => VirtualOp::OR(res_reg.clone(), lhs_reg, rhs_reg),
+            BinaryOpKind::Xor => VirtualOp::XOR(res_reg.clone(), lhs_reg, rhs_reg),
+            BinaryOpKind::Lsh => VirtualOp::SLL(res_reg.clone(), lhs_reg, rhs_reg),
+            BinaryOpKind::Rsh => VirtualOp::SRL(res_reg.clone(), lhs_reg, rhs_reg),
+        };
+        self.bytecode.push(Op {
+            opcode: Either::Left(opcode),
+            comment: String::new(),
+            owning_span: instr_val.get_span(self.context),
+        });
+        self.reg_map.insert(*instr_val, res_reg);
+    }
+
     fn compile_bitcast(&mut self, instr_val: &Value, bitcast_val: &Value, to_type: &Type) {
         let val_reg = self.value_to_register(bitcast_val);
         let reg = if let Type::Bool = to_type {
@@ -769,14 +808,36 @@ impl<'ir> AsmBuilder<'ir> {
         let lhs_reg = self.value_to_register(lhs_value);
         let rhs_reg = self.value_to_register(rhs_value);
         let res_reg = self.reg_seqr.next();
-        match pred {
-            Predicate::Equal => {
-                self.bytecode.push(Op {
-                    opcode: Either::Left(VirtualOp::EQ(res_reg.clone(), lhs_reg, rhs_reg)),
-                    comment: String::new(),
-                    owning_span: instr_val.get_span(self.context),
-                });
+        let owning_span = instr_val.get_span(self.context);
+
+        // The other predicates are each the opposite of one of `eq`, `lt` or `gt`, so use that and
+        // negate the result.  Like `core::ops::not()` this is an `eq` with zero, since `not` is
+        // bitwise.
+        let (cmp_op, negate) = match pred {
+            Predicate::Equal => (VirtualOp::EQ(res_reg.clone(), lhs_reg, rhs_reg), false),
+            Predicate::NotEqual => (VirtualOp::EQ(res_reg.clone(), lhs_reg, rhs_reg), true),
+            Predicate::LessThan => (VirtualOp::LT(res_reg.clone(), lhs_reg, rhs_reg), false),
+            Predicate::GreaterThan => (VirtualOp::GT(res_reg.clone(), lhs_reg, rhs_reg), false),
+            Predicate::LessThanOrEqual => (VirtualOp::GT(res_reg.clone(), lhs_reg, rhs_reg), true),
+            Predicate::GreaterThanOrEqual => {
+                (VirtualOp::LT(res_reg.clone(), lhs_reg, rhs_reg), true)
             }
+        };
+        self.bytecode.push(Op {
+            opcode: Either::Left(cmp_op),
+            comment: String::new(),
+            owning_span: owning_span.clone(),
+        });
+        if negate {
+            self.bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::EQ(
+                    res_reg.clone(),
+                    res_reg.clone(),
+                    VirtualRegister::Constant(ConstantRegister::Zero),
+                )),
+                comment: "negate comparison".into(),
+                owning_span,
+            });
         }
         self.reg_map.insert(*instr_val, res_reg);
     }
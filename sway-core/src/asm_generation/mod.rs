@@ -435,6 +435,10 @@ impl fmt::Display for DataSection {
                 Literal::U16(num) => format!(".u16 {:#04x}", num),
                 Literal::U32(num) => format!(".u32 {:#04x}", num),
                 Literal::U64(num) => format!(".u64 {:#04x}", num),
+                Literal::I8(num) => format!(".i8 {:#04x}", num),
+                Literal::I16(num) => format!(".i16 {:#04x}", num),
+                Literal::I32(num) => format!(".i32 {:#04x}", num),
+                Literal::I64(num) => format!(".i64 {:#04x}", num),
                 Literal::Numeric(num) => format!(".u64 {:#04x}", num),
                 Literal::Boolean(b) => format!(".bool {}", if *b { "0x01" } else { "0x00" }),
                 Literal::String(st) => format!(".str \"{}\"", st.as_str()),
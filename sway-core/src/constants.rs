@@ -24,6 +24,9 @@ pub const DEFAULT_ENTRY_POINT_FN_NAME: &str = "main";
 /// The default prefix for the compiler generated names of tuples
 pub const TUPLE_NAME_PREFIX: &str = "__tuple_";
 
+/// The default prefix for the compiler generated names of `for` loop variables
+pub const FOR_LOOP_NAME_PREFIX: &str = "__for_";
+
 /// The valid attribute strings related to storage and purity.
 pub const STORAGE_PURITY_ATTRIBUTE_NAME: &str = "storage";
 pub const STORAGE_PURITY_READ_NAME: &str = "read";
@@ -153,7 +153,9 @@ fn connect_node(
             }
             NodeConnection::NextStep(vec![node])
         }
-        TypedAstNodeContent::Expression(TypedExpression { .. }) => {
+        TypedAstNodeContent::Expression(TypedExpression { .. })
+        | TypedAstNodeContent::Break
+        | TypedAstNodeContent::Continue => {
             let entry = graph.add_node(node.into());
             // insert organizational dominator node
             // connected to all current leaves
@@ -183,7 +185,9 @@ fn connect_declaration(
         | StructDeclaration(_)
         | EnumDeclaration(_)
         | StorageDeclaration(_)
-        | GenericTypeForFunctionScope { .. } => leaves.to_vec(),
+        | TypeAliasDeclaration(_)
+        | GenericTypeForFunctionScope { .. }
+        | ConstGenericForFunctionScope { .. } => leaves.to_vec(),
         VariableDeclaration(_) | ConstantDeclaration(_) => {
             let entry_node = graph.add_node(node.into());
             for leaf in leaves {
@@ -312,6 +312,16 @@ fn connect_node(
                 exit_node,
             )
         }
+        TypedAstNodeContent::Break | TypedAstNodeContent::Continue => {
+            // control leaves the current iteration of the loop, so nothing following this node
+            // within the loop body is reachable through it. The enclosing while loop already
+            // accounts for being exited or repeated.
+            let this_index = graph.add_node(node.into());
+            for leaf in leaves {
+                graph.add_edge(*leaf, this_index, "".into());
+            }
+            (vec![], exit_node)
+        }
         TypedAstNodeContent::SideEffect => (leaves.to_vec(), exit_node),
         TypedAstNodeContent::Declaration(decl) => {
             // all leaves connect to this node, then this node is the singular leaf
@@ -413,7 +423,10 @@ fn connect_declaration(
             connect_storage_declaration(storage, graph, entry_node, tree_type);
             Ok(leaves.to_vec())
         }
-        ErrorRecovery | GenericTypeForFunctionScope { .. } => Ok(leaves.to_vec()),
+        ErrorRecovery
+        | TypeAliasDeclaration(_)
+        | GenericTypeForFunctionScope { .. }
+        | ConstGenericForFunctionScope { .. } => Ok(leaves.to_vec()),
     }
 }
 
@@ -1023,6 +1036,13 @@ fn connect_expression(
             }
             Ok(vec![node])
         }
+        GetStorageKey => {
+            let node = graph.add_node("Get Storage Key".into());
+            for leaf in leaves {
+                graph.add_edge(*leaf, node, "".into());
+            }
+            Ok(vec![node])
+        }
         SizeOfValue { expr } => {
             let expr = connect_expression(
                 &(*expr).expression,
@@ -1047,7 +1067,39 @@ fn connect_expression(
             }
             Ok(leaves.to_vec())
         }
-        FunctionParameter => Ok(leaves.to_vec()),
+        FunctionParameter | MutRef { .. } => Ok(leaves.to_vec()),
+        Closure { body, .. } => connect_expression(
+            &body.expression,
+            graph,
+            leaves,
+            exit_node,
+            "closure body",
+            tree_type,
+            body.span.clone(),
+        ),
+        ClosureCall { closure, arguments } => {
+            let mut current_leaf = connect_expression(
+                &closure.expression,
+                graph,
+                leaves,
+                exit_node,
+                "closure",
+                tree_type,
+                closure.span.clone(),
+            )?;
+            for arg in arguments {
+                current_leaf = connect_expression(
+                    &arg.expression,
+                    graph,
+                    &current_leaf,
+                    exit_node,
+                    "closure argument",
+                    tree_type,
+                    arg.span.clone(),
+                )?;
+            }
+            Ok(current_leaf)
+        }
     }
 }
 
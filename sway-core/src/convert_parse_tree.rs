@@ -7,26 +7,29 @@ use {
         error::{err, ok, CompileError, CompileResult, CompileWarning},
         parse_tree::desugar_match_expression,
         type_engine::{insert_type, AbiName, IntegerBits},
-        AbiDeclaration, AsmExpression, AsmOp, AsmRegister, AsmRegisterDeclaration, AstNode,
-        AstNodeContent, BuiltinProperty, CallPath, CatchAll, CodeBlock, ConstantDeclaration,
-        Declaration, EnumDeclaration, EnumVariant, Expression, FunctionDeclaration,
-        FunctionParameter, ImplSelf, ImplTrait, ImportType, IncludeStatement, LazyOp, Literal,
-        MatchBranch, MatchCondition, MethodName, ParseTree, Purity, Reassignment,
+        AbiDeclaration, AsmExpression, AsmOp, AsmRegister, AsmRegisterDeclaration, AssociatedType,
+        AstNode, AstNodeContent, BuiltinProperty, CallPath, CatchAll, CodeBlock,
+        ConstantDeclaration, Declaration, DelayedArrayLengthResolution, DelayedResolutionVariant,
+        EnumDeclaration, EnumVariant, Expression, FunctionDeclaration, FunctionParameter, ImplSelf,
+        ImplTrait, ImportType, IncludeStatement, LazyOp, Literal, MatchBranch, MatchCondition,
+        MethodName, ModuleDeclaration, ModuleDeclarationKind, ParseTree, Purity, Reassignment,
         ReassignmentTarget, ReturnStatement, Scrutinee, StorageDeclaration, StorageField,
         StructDeclaration, StructExpressionField, StructField, StructScrutineeField, Supertrait,
-        SwayParseTree, TraitConstraint, TraitDeclaration, TraitFn, TreeType, TypeArgument,
-        TypeInfo, TypeParameter, UseStatement, VariableDeclaration, Visibility, WhileLoop,
+        SwayParseTree, TraitConstraint, TraitDeclaration, TraitFn, TreeType, TypeAliasDeclaration,
+        TypeArgument, TypeInfo, TypeParameter, UseStatement, VariableDeclaration, Visibility,
+        WhileLoop,
     },
     std::{collections::HashMap, convert::TryFrom, iter, mem::MaybeUninit, ops::ControlFlow},
     sway_parse::{
         AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
         Dependency, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition,
-        IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConst, ItemEnum, ItemFn, ItemImpl,
-        ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemUse, LitInt, LitIntType, MatchBranchKind,
-        PathExpr, PathExprSegment, PathType, PathTypeSegment, Pattern, PatternStructField, Program,
-        ProgramKind, PubToken, QualifiedPathRoot, Statement, StatementLet, Traits, Ty, TypeField,
-        UseTree, WhereClause,
+        ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs, GenericParam, GenericParams,
+        IfCondition, IfExpr, ImplItem, Instruction, Intrinsic, Item, ItemAbi, ItemConst, ItemEnum,
+        ItemFn, ItemImpl, ItemKind, ItemMod, ItemModKind, ItemStorage, ItemStruct, ItemTrait,
+        ItemTypeAlias, ItemUse, LitInt, LitIntType, MatchBranchKind, PathExpr, PathExprSegment,
+        PathType, PathTypeSegment, Pattern, PatternStructField, Program, ProgramKind, PubToken,
+        QualifiedPathRoot, Statement, StatementLet, TraitItem, TraitItemConst, Traits, Ty,
+        TypeField, UseTree, WhereClause,
     },
     sway_types::{Ident, Span, Spanned},
     thiserror::Error,
@@ -97,6 +100,14 @@ pub enum ConvertParseTreeError {
     ReturnOutsideOfBlock { span: Span },
     #[error("while expressions are not allowed outside of blocks")]
     WhileOutsideOfBlock { span: Span },
+    #[error("break expressions are not allowed outside of blocks")]
+    BreakOutsideOfBlock { span: Span },
+    #[error("continue expressions are not allowed outside of blocks")]
+    ContinueOutsideOfBlock { span: Span },
+    #[error("for expressions are not allowed outside of blocks")]
+    ForOutsideOfBlock { span: Span },
+    #[error("range expressions are only supported as the iterator of a for loop")]
+    RangeNotSupportedHere { span: Span },
     #[error("functions used in applications may not be arbitrary expressions")]
     FunctionArbitraryExpression { span: Span },
     #[error("generics are not supported here")]
@@ -111,8 +122,14 @@ pub enum ConvertParseTreeError {
     IsReferenceTypeTooManyArgs { span: Span },
     #[error("__is_reference_type requires exactly one generic argument")]
     IsReferenceTypeOneGenericArg { span: Span },
+    #[error("__str_len does not take arguments")]
+    StrLenTooManyArgs { span: Span },
+    #[error("__str_len requires exactly one generic argument")]
+    StrLenOneGenericArg { span: Span },
     #[error("__size_of_val requires exactly one argument")]
     SizeOfValOneArg { span: Span },
+    #[error("__get_storage_key does not take arguments")]
+    GetStorageKeyTooManyArgs { span: Span },
     #[error("tuple index out of range")]
     TupleIndexOutOfRange { span: Span },
     #[error("shift-left expressions are not implemented")]
@@ -147,8 +164,14 @@ pub enum ConvertParseTreeError {
     U32LiteralOutOfRange { span: Span },
     #[error("u64 literal out of range")]
     U64LiteralOutOfRange { span: Span },
-    #[error("signed integers are not supported")]
-    SignedIntegersNotSupported { span: Span },
+    #[error("i8 literal out of range")]
+    I8LiteralOutOfRange { span: Span },
+    #[error("i16 literal out of range")]
+    I16LiteralOutOfRange { span: Span },
+    #[error("i32 literal out of range")]
+    I32LiteralOutOfRange { span: Span },
+    #[error("i64 literal out of range")]
+    I64LiteralOutOfRange { span: Span },
     #[error("literal patterns not supported in this position")]
     LiteralPatternsNotSupportedHere { span: Span },
     #[error("constant patterns not supported in this position")]
@@ -179,6 +202,18 @@ pub enum ConvertParseTreeError {
     InvalidAttributeArgument { attribute: String, span: Span },
     #[error("cannot find type \"{ty_name}\" in this scope")]
     ConstrainedNonExistentType { ty_name: Ident, span: Span },
+    #[error("associated types are only supported in traits and trait implementations")]
+    AssociatedTypesNotSupportedHere { span: Span },
+    #[error("const generic parameters must have type u64")]
+    ConstGenericNotU64 { span: Span },
+    #[error("`&mut` types are only supported for function parameters")]
+    MutRefTypeNotSupportedHere { span: Span },
+    #[error("closure parameters cannot be `&mut` references")]
+    MutRefClosureParameter { span: Span },
+    #[error("modules can only be declared at the top level of a module")]
+    ModuleNotAllowedHere { span: Span },
+    #[error("associated types cannot have generic parameters")]
+    GenericAssociatedType { span: Span },
 }
 
 impl ConvertParseTreeError {
@@ -187,6 +222,10 @@ impl ConvertParseTreeError {
             ConvertParseTreeError::PubUseNotSupported { span } => span.clone(),
             ConvertParseTreeError::ReturnOutsideOfBlock { span } => span.clone(),
             ConvertParseTreeError::WhileOutsideOfBlock { span } => span.clone(),
+            ConvertParseTreeError::BreakOutsideOfBlock { span } => span.clone(),
+            ConvertParseTreeError::ContinueOutsideOfBlock { span } => span.clone(),
+            ConvertParseTreeError::ForOutsideOfBlock { span } => span.clone(),
+            ConvertParseTreeError::RangeNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::FunctionArbitraryExpression { span } => span.clone(),
             ConvertParseTreeError::GenericsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::FullyQualifiedPathsNotSupportedHere { span } => span.clone(),
@@ -194,7 +233,10 @@ impl ConvertParseTreeError {
             ConvertParseTreeError::SizeOfOneGenericArg { span } => span.clone(),
             ConvertParseTreeError::IsReferenceTypeTooManyArgs { span } => span.clone(),
             ConvertParseTreeError::IsReferenceTypeOneGenericArg { span } => span.clone(),
+            ConvertParseTreeError::StrLenTooManyArgs { span } => span.clone(),
+            ConvertParseTreeError::StrLenOneGenericArg { span } => span.clone(),
             ConvertParseTreeError::SizeOfValOneArg { span } => span.clone(),
+            ConvertParseTreeError::GetStorageKeyTooManyArgs { span } => span.clone(),
             ConvertParseTreeError::TupleIndexOutOfRange { span } => span.clone(),
             ConvertParseTreeError::ShlNotImplemented { span } => span.clone(),
             ConvertParseTreeError::ShrNotImplemented { span } => span.clone(),
@@ -212,7 +254,10 @@ impl ConvertParseTreeError {
             ConvertParseTreeError::U16LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U32LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U64LiteralOutOfRange { span } => span.clone(),
-            ConvertParseTreeError::SignedIntegersNotSupported { span } => span.clone(),
+            ConvertParseTreeError::I8LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::I16LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::I32LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::I64LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::LiteralPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstantPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span } => span.clone(),
@@ -228,6 +273,12 @@ impl ConvertParseTreeError {
             ConvertParseTreeError::ContractCallerNamedTypeGenericArg { span } => span.clone(),
             ConvertParseTreeError::InvalidAttributeArgument { span, .. } => span.clone(),
             ConvertParseTreeError::ConstrainedNonExistentType { span, .. } => span.clone(),
+            ConvertParseTreeError::AssociatedTypesNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::ConstGenericNotU64 { span } => span.clone(),
+            ConvertParseTreeError::MutRefTypeNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::MutRefClosureParameter { span } => span.clone(),
+            ConvertParseTreeError::ModuleNotAllowedHere { span } => span.clone(),
+            ConvertParseTreeError::GenericAssociatedType { span } => span.clone(),
         }
     }
 }
@@ -245,6 +296,21 @@ pub fn convert_parse_tree(program: Program) -> CompileResult<SwayParseTree> {
     }
 }
 
+/// Convert the items of a module declared with `mod foo;`, which live in their own file without a
+/// program kind header.
+pub fn convert_module_items(items: Vec<Item>) -> CompileResult<Vec<AstNode>> {
+    let mut ec = ErrorContext {
+        warnings: Vec::new(),
+        errors: Vec::new(),
+    };
+    let res = items_to_ast_nodes(&mut ec, items);
+    let ErrorContext { warnings, errors } = ec;
+    match res {
+        Ok(ast_nodes) => ok(ast_nodes, warnings, errors),
+        Err(_error_emitted) => err(warnings, errors),
+    }
+}
+
 pub fn program_to_sway_parse_tree(
     ec: &mut ErrorContext,
     program: Program,
@@ -272,10 +338,7 @@ pub fn program_to_sway_parse_tree(
                 })
                 .collect()
         };
-        for item in program.items {
-            let ast_nodes = item_to_ast_nodes(ec, item)?;
-            root_nodes.extend(ast_nodes);
-        }
+        root_nodes.extend(items_to_ast_nodes(ec, program.items)?);
         root_nodes
     };
     Ok(SwayParseTree {
@@ -284,6 +347,17 @@ pub fn program_to_sway_parse_tree(
     })
 }
 
+fn items_to_ast_nodes(
+    ec: &mut ErrorContext,
+    items: Vec<Item>,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let mut ast_nodes = Vec::new();
+    for item in items {
+        ast_nodes.extend(item_to_ast_nodes(ec, item)?);
+    }
+    Ok(ast_nodes)
+}
+
 fn item_to_ast_nodes(ec: &mut ErrorContext, item: Item) -> Result<Vec<AstNode>, ErrorEmitted> {
     let attributes = item_attrs_to_map(&item.attribute_list)?;
 
@@ -342,6 +416,17 @@ fn item_to_ast_nodes(ec: &mut ErrorContext, item: Item) -> Result<Vec<AstNode>,
                 Declaration::StorageDeclaration(storage_declaration),
             )]
         }
+        ItemKind::Mod(item_mod) => {
+            let module_declaration = item_mod_to_module_declaration(ec, item_mod)?;
+            vec![AstNodeContent::ModuleDeclaration(module_declaration)]
+        }
+        ItemKind::TypeAlias(item_type_alias) => {
+            let type_alias_declaration =
+                item_type_alias_to_type_alias_declaration(ec, item_type_alias)?;
+            vec![AstNodeContent::Declaration(
+                Declaration::TypeAliasDeclaration(type_alias_declaration),
+            )]
+        }
     };
     Ok(contents
         .into_iter()
@@ -597,17 +682,32 @@ fn item_trait_to_trait_declaration(
     item_trait: ItemTrait,
 ) -> Result<TraitDeclaration, ErrorEmitted> {
     let name = item_trait.name;
-    let interface_surface = {
-        item_trait
-            .trait_items
-            .into_inner()
-            .into_iter()
-            .map(|(fn_signature, _semicolon_token)| {
-                let attributes = item_attrs_to_map(&fn_signature.attribute_list)?;
-                fn_signature_to_trait_fn(ec, fn_signature.value, &attributes)
-            })
-            .collect::<Result<_, _>>()?
-    };
+    let type_parameters = generic_params_opt_to_type_parameters(ec, item_trait.generics, None)?;
+    let mut associated_types = Vec::new();
+    let mut interface_surface = Vec::new();
+    let mut constants = Vec::new();
+    for (trait_item, _semicolon_token) in item_trait.trait_items.into_inner() {
+        match trait_item.value {
+            TraitItem::Fn(fn_signature) => {
+                let attributes = item_attrs_to_map(&trait_item.attribute_list)?;
+                interface_surface.push(fn_signature_to_trait_fn(ec, fn_signature, &attributes)?);
+            }
+            TraitItem::Const(trait_item_const) => {
+                let (trait_fn, constant_opt) = trait_item_const_to_trait_fn(ec, trait_item_const)?;
+                interface_surface.push(trait_fn);
+                constants.extend(constant_opt);
+            }
+            TraitItem::Type(trait_item_type) => associated_types.push(TypeParameter {
+                type_id: insert_type(TypeInfo::Custom {
+                    name: trait_item_type.name.clone(),
+                    type_arguments: Vec::new(),
+                }),
+                name_ident: trait_item_type.name,
+                trait_constraints: Vec::new(),
+                is_const: false,
+            }),
+        }
+    }
     let methods = match item_trait.trait_defs_opt {
         None => Vec::new(),
         Some(trait_defs) => trait_defs
@@ -626,13 +726,44 @@ fn item_trait_to_trait_declaration(
     let visibility = pub_token_opt_to_visibility(item_trait.visibility);
     Ok(TraitDeclaration {
         name,
+        type_parameters,
+        associated_types,
         interface_surface,
         methods,
+        constants,
         supertraits,
         visibility,
     })
 }
 
+/// An associated constant of a trait is part of its interface surface as a function without
+/// parameters, and its default value, if any, is type checked for each implementation of the
+/// trait which doesn't give it a value.
+fn trait_item_const_to_trait_fn(
+    ec: &mut ErrorContext,
+    trait_item_const: TraitItemConst,
+) -> Result<(TraitFn, Option<ConstantDeclaration>), ErrorEmitted> {
+    let return_type_span = trait_item_const.ty.span();
+    let return_type = ty_to_type_info(ec, trait_item_const.ty)?;
+    let constant_opt = match trait_item_const.eq_expr_opt {
+        Some((_eq_token, expr)) => Some(ConstantDeclaration {
+            name: trait_item_const.name.clone(),
+            type_ascription: return_type.clone(),
+            value: expr_to_expression(ec, expr)?,
+            visibility: Visibility::Public,
+        }),
+        None => None,
+    };
+    let trait_fn = TraitFn {
+        name: trait_item_const.name,
+        purity: Purity::Pure,
+        parameters: Vec::new(),
+        return_type,
+        return_type_span,
+    };
+    Ok((trait_fn, constant_opt))
+}
+
 fn item_impl_to_declaration(
     ec: &mut ErrorContext,
     item_impl: ItemImpl,
@@ -640,17 +771,23 @@ fn item_impl_to_declaration(
     let block_span = item_impl.span();
     let type_implementing_for_span = item_impl.ty.span();
     let type_implementing_for = ty_to_type_info(ec, item_impl.ty)?;
-    let functions = {
-        item_impl
-            .contents
-            .into_inner()
-            .into_iter()
-            .map(|item| {
+    let mut functions = Vec::new();
+    let mut constants = Vec::new();
+    let mut associated_types = Vec::new();
+    for item in item_impl.contents.into_inner() {
+        match item.value {
+            ImplItem::Fn(item_fn) => {
                 let attributes = item_attrs_to_map(&item.attribute_list)?;
-                item_fn_to_function_declaration(ec, item.value, &attributes)
-            })
-            .collect::<Result<_, _>>()?
-    };
+                functions.push(item_fn_to_function_declaration(ec, item_fn, &attributes)?);
+            }
+            ImplItem::Const(item_const) => {
+                constants.push(item_const_to_constant_declaration(ec, item_const)?);
+            }
+            ImplItem::Type(item_type_alias) => {
+                associated_types.push(item_type_alias_to_associated_type(ec, item_type_alias)?);
+            }
+        }
+    }
 
     let type_parameters = generic_params_opt_to_type_parameters(
         ec,
@@ -660,22 +797,34 @@ fn item_impl_to_declaration(
 
     match item_impl.trait_opt {
         Some((path_type, _for_token)) => {
+            let (trait_name, trait_type_arguments) =
+                path_type_to_call_path_type_args(ec, path_type)?;
             let impl_trait = ImplTrait {
-                trait_name: path_type_to_call_path(ec, path_type)?,
+                trait_name,
+                trait_type_arguments,
                 type_implementing_for,
                 type_implementing_for_span,
                 type_arguments: type_parameters,
                 functions,
+                constants,
+                associated_types,
                 block_span,
             };
             Ok(Declaration::ImplTrait(impl_trait))
         }
         None => {
+            if let Some(associated_type) = associated_types.first() {
+                let error = ConvertParseTreeError::AssociatedTypesNotSupportedHere {
+                    span: associated_type.name.span().clone(),
+                };
+                return Err(ec.error(error));
+            }
             let impl_self = ImplSelf {
                 type_implementing_for,
                 type_implementing_for_span,
                 type_parameters,
                 functions,
+                constants,
                 block_span,
             };
             Ok(Declaration::ImplSelf(impl_self))
@@ -683,6 +832,43 @@ fn item_impl_to_declaration(
     }
 }
 
+fn item_type_alias_to_associated_type(
+    ec: &mut ErrorContext,
+    item_type_alias: ItemTypeAlias,
+) -> Result<AssociatedType, ErrorEmitted> {
+    if let Some(generics) = &item_type_alias.generics {
+        let error = ConvertParseTreeError::GenericAssociatedType {
+            span: generics.parameters.span(),
+        };
+        return Err(ec.error(error));
+    }
+    let span = item_type_alias.ty.span();
+    let type_info = ty_to_type_info(ec, item_type_alias.ty)?;
+    Ok(AssociatedType {
+        name: item_type_alias.name,
+        type_argument: TypeArgument {
+            type_id: insert_type(type_info),
+            span,
+        },
+    })
+}
+
+fn item_type_alias_to_type_alias_declaration(
+    ec: &mut ErrorContext,
+    item_type_alias: ItemTypeAlias,
+) -> Result<TypeAliasDeclaration, ErrorEmitted> {
+    let span = item_type_alias.span();
+    let ty_span = item_type_alias.ty.span();
+    Ok(TypeAliasDeclaration {
+        name: item_type_alias.name,
+        type_parameters: generic_params_opt_to_type_parameters(ec, item_type_alias.generics, None)?,
+        ty: ty_to_type_info(ec, item_type_alias.ty)?,
+        ty_span,
+        visibility: pub_token_opt_to_visibility(item_type_alias.visibility),
+        span,
+    })
+}
+
 fn item_abi_to_abi_declaration(
     ec: &mut ErrorContext,
     item_abi: ItemAbi,
@@ -690,6 +876,7 @@ fn item_abi_to_abi_declaration(
     let span = item_abi.span();
     Ok(AbiDeclaration {
         name: item_abi.name,
+        type_parameters: generic_params_opt_to_type_parameters(ec, item_abi.generics, None)?,
         interface_surface: {
             item_abi
                 .abi_items
@@ -733,6 +920,25 @@ fn item_const_to_constant_declaration(
     })
 }
 
+fn item_mod_to_module_declaration(
+    ec: &mut ErrorContext,
+    item_mod: ItemMod,
+) -> Result<ModuleDeclaration, ErrorEmitted> {
+    let span = item_mod.span();
+    let kind = match item_mod.kind {
+        ItemModKind::Inline(items) => {
+            ModuleDeclarationKind::Inline(items_to_ast_nodes(ec, items.into_inner())?)
+        }
+        ItemModKind::File(_semicolon_token) => ModuleDeclarationKind::File,
+    };
+    Ok(ModuleDeclaration {
+        name: item_mod.name,
+        visibility: pub_token_opt_to_visibility(item_mod.visibility),
+        kind,
+        span,
+    })
+}
+
 fn item_storage_to_storage_declaration(
     ec: &mut ErrorContext,
     item_storage: ItemStorage,
@@ -786,15 +992,8 @@ fn generic_params_opt_to_type_parameters(
             .parameters
             .into_inner()
             .into_iter()
-            .map(|ident| TypeParameter {
-                type_id: insert_type(TypeInfo::Custom {
-                    name: ident.clone(),
-                    type_arguments: Vec::new(),
-                }),
-                name_ident: ident,
-                trait_constraints: Vec::new(),
-            })
-            .collect::<Vec<_>>(),
+            .map(|generic_param| generic_param_to_type_parameter(ec, generic_param))
+            .collect::<Result<Vec<_>, _>>()?,
         None => Vec::new(),
     };
 
@@ -831,6 +1030,36 @@ fn generic_params_opt_to_type_parameters(
     Ok(params)
 }
 
+fn generic_param_to_type_parameter(
+    ec: &mut ErrorContext,
+    generic_param: GenericParam,
+) -> Result<TypeParameter, ErrorEmitted> {
+    let (name_ident, is_const) = match generic_param {
+        GenericParam::Type(name) => (name, false),
+        GenericParam::Const { name, ty, .. } => {
+            let span = ty.span();
+            match ty_to_type_info(ec, ty)? {
+                TypeInfo::UnsignedInteger(IntegerBits::SixtyFour) => (),
+                _ => {
+                    let error = ConvertParseTreeError::ConstGenericNotU64 { span };
+                    return Err(ec.error(error));
+                }
+            }
+            (name, true)
+        }
+    };
+    let type_parameter = TypeParameter {
+        type_id: insert_type(TypeInfo::Custom {
+            name: name_ident.clone(),
+            type_arguments: Vec::new(),
+        }),
+        name_ident,
+        trait_constraints: Vec::new(),
+        is_const,
+    };
+    Ok(type_parameter)
+}
+
 fn pub_token_opt_to_visibility(pub_token_opt: Option<PubToken>) -> Visibility {
     match pub_token_opt {
         Some(..) => Visibility::Public,
@@ -887,6 +1116,7 @@ fn fn_args_to_function_parameters(
             .map(|fn_arg| fn_arg_to_function_parameter(ec, fn_arg))
             .collect::<Result<_, _>>()?,
         FnArgs::NonStatic {
+            ref_self,
             self_token,
             args_opt,
         } => {
@@ -894,6 +1124,7 @@ fn fn_args_to_function_parameters(
                 name: Ident::new(self_token.span()),
                 type_id: insert_type(TypeInfo::SelfType),
                 type_span: self_token.span(),
+                is_reference: ref_self.is_some(),
             }];
             if let Some((_comma_token, args)) = args_opt {
                 for arg in args {
@@ -913,6 +1144,10 @@ fn type_name_to_type_info_opt(name: &Ident) -> Option<TypeInfo> {
         "u16" => Some(TypeInfo::UnsignedInteger(IntegerBits::Sixteen)),
         "u32" => Some(TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)),
         "u64" => Some(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+        "i8" => Some(TypeInfo::SignedInteger(IntegerBits::Eight)),
+        "i16" => Some(TypeInfo::SignedInteger(IntegerBits::Sixteen)),
+        "i32" => Some(TypeInfo::SignedInteger(IntegerBits::ThirtyTwo)),
+        "i64" => Some(TypeInfo::SignedInteger(IntegerBits::SixtyFour)),
         "bool" => Some(TypeInfo::Boolean),
         "unit" => Some(TypeInfo::Tuple(Vec::new())),
         "byte" => Some(TypeInfo::Byte),
@@ -934,13 +1169,41 @@ fn ty_to_type_info(ec: &mut ErrorContext, ty: Ty) -> Result<TypeInfo, ErrorEmitt
         ),
         Ty::Array(bracketed_ty_array_descriptor) => {
             let ty_array_descriptor = bracketed_ty_array_descriptor.into_inner();
-            TypeInfo::Array(
-                crate::type_engine::insert_type(ty_to_type_info(ec, *ty_array_descriptor.ty)?),
-                expr_to_usize(ec, *ty_array_descriptor.length)?,
-            )
+            let elem_type_id =
+                crate::type_engine::insert_type(ty_to_type_info(ec, *ty_array_descriptor.ty)?);
+            match *ty_array_descriptor.length {
+                // A length which is a name, e.g. `[T; N]`, refers to a const generic parameter.
+                Expr::Path(path_expr) => TypeInfo::GenericArray(
+                    elem_type_id,
+                    insert_type(TypeInfo::Custom {
+                        name: path_expr_to_ident(ec, path_expr)?,
+                        type_arguments: Vec::new(),
+                    }),
+                ),
+                length => TypeInfo::Array(elem_type_id, expr_to_usize(ec, length)?),
+            }
         }
         Ty::Str { length, .. } => TypeInfo::Str(expr_to_u64(ec, *length.into_inner())?),
         Ty::Infer { .. } => TypeInfo::Unknown,
+        Ty::Fn {
+            arguments,
+            return_type_opt,
+            ..
+        } => TypeInfo::Function {
+            parameters: arguments
+                .into_inner()
+                .into_iter()
+                .map(|ty| ty_to_type_argument(ec, ty))
+                .collect::<Result<_, _>>()?,
+            return_type: insert_type(match return_type_opt {
+                Some((_right_arrow_token, ty)) => ty_to_type_info(ec, *ty)?,
+                None => TypeInfo::Tuple(Vec::new()),
+            }),
+        },
+        Ty::MutRef { .. } => {
+            let error = ConvertParseTreeError::MutRefTypeNotSupportedHere { span: ty.span() };
+            return Err(ec.error(error));
+        }
     };
     Ok(type_info)
 }
@@ -1032,6 +1295,51 @@ fn path_type_to_call_path(
     Ok(call_path)
 }
 
+/// Similar to [path_type_to_call_path], but allows for the last segment of the path to carry type
+/// arguments, e.g. `From<b256>` in `impl From<b256> for Address`.
+fn path_type_to_call_path_type_args(
+    ec: &mut ErrorContext,
+    path_type: PathType,
+) -> Result<(CallPath, Vec<TypeArgument>), ErrorEmitted> {
+    let PathType {
+        root_opt,
+        prefix,
+        mut suffix,
+    } = path_type;
+    let is_absolute = path_root_opt_to_bool(ec, root_opt)?;
+    let (call_path, type_arguments) = match suffix.pop() {
+        Some((_double_colon_token, call_path_suffix)) => {
+            let mut prefixes = vec![path_type_segment_to_ident(ec, prefix)?];
+            for (_double_colon_token, call_path_prefix) in suffix {
+                let ident = path_type_segment_to_ident(ec, call_path_prefix)?;
+                prefixes.push(ident);
+            }
+            let (suffix, ty_args) =
+                path_type_segment_to_ident_or_type_argument(ec, call_path_suffix)?;
+            (
+                CallPath {
+                    prefixes,
+                    suffix,
+                    is_absolute,
+                },
+                ty_args,
+            )
+        }
+        None => {
+            let (suffix, ty_args) = path_type_segment_to_ident_or_type_argument(ec, prefix)?;
+            (
+                CallPath {
+                    prefixes: Vec::new(),
+                    suffix,
+                    is_absolute,
+                },
+                ty_args,
+            )
+        }
+    };
+    Ok((call_path, type_arguments))
+}
+
 fn expr_to_ast_node(
     ec: &mut ErrorContext,
     expr: Expr,
@@ -1061,6 +1369,29 @@ fn expr_to_ast_node(
             }),
             span,
         },
+        Expr::For {
+            value_pattern,
+            iterator,
+            block,
+            ..
+        } => AstNode {
+            content: AstNodeContent::Expression(for_expr_to_expression(
+                ec,
+                value_pattern,
+                *iterator,
+                block,
+                span.clone(),
+            )?),
+            span,
+        },
+        Expr::Break { .. } => AstNode {
+            content: AstNodeContent::Break,
+            span,
+        },
+        Expr::Continue { .. } => AstNode {
+            content: AstNodeContent::Continue,
+            span,
+        },
         Expr::Reassignment {
             assignable, expr, ..
         } => AstNode {
@@ -1089,6 +1420,168 @@ fn expr_to_ast_node(
     Ok(ast_node)
 }
 
+/// Desugars a `for` loop into a code block containing a `while` loop.
+///
+/// `for x in start..end { body }` becomes:
+///
+/// ```ignore
+/// {
+///     let mut __for_index_N = start;
+///     let __for_end_N = end;
+///     while __for_index_N < __for_end_N {
+///         let x = __for_index_N;
+///         __for_index_N = __for_index_N + 1;
+///         body
+///     }
+/// }
+/// ```
+///
+/// and `for x in array { body }` becomes:
+///
+/// ```ignore
+/// {
+///     let __for_array_N = array;
+///     let mut __for_index_N = 0;
+///     while __for_index_N < <length of __for_array_N> {
+///         let x = __for_array_N[__for_index_N];
+///         __for_index_N = __for_index_N + 1;
+///         body
+///     }
+/// }
+/// ```
+///
+/// The index is incremented before the body so that a `continue` within it moves on to the next
+/// iteration.  The length of an array is resolved during type checking.
+fn for_expr_to_expression(
+    ec: &mut ErrorContext,
+    value_pattern: Pattern,
+    iterator: Expr,
+    block: Braces<CodeBlockContents>,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    // Generate deterministic names for the loop variables. Because the parser is single
+    // threaded, the names generated below will be stable.
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let loop_num = COUNTER.fetch_add(1, Ordering::SeqCst);
+    let generate_name = |kind: &str| {
+        let name = format!(
+            "{}{}_{}",
+            crate::constants::FOR_LOOP_NAME_PREFIX,
+            kind,
+            loop_num
+        );
+        Ident::new_with_override(Box::leak(name.into_boxed_str()), span.clone())
+    };
+    let variable_expression = |name: &Ident| Expression::VariableExpression {
+        name: name.clone(),
+        span: span.clone(),
+    };
+    let variable_declaration = |name: &Ident, body: Expression, is_mutable: bool| AstNode {
+        content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+            VariableDeclaration {
+                name: name.clone(),
+                type_ascription: TypeInfo::Unknown,
+                type_ascription_span: None,
+                body,
+                is_mutable,
+            },
+        )),
+        span: span.clone(),
+    };
+
+    let index_name = generate_name("index");
+    let mut contents = Vec::new();
+    let (end, value) = match iterator {
+        Expr::Range { start, end, .. } => {
+            let end_name = generate_name("end");
+            let start = expr_to_expression(ec, *start)?;
+            contents.push(variable_declaration(&index_name, start, true));
+            let end = expr_to_expression(ec, *end)?;
+            contents.push(variable_declaration(&end_name, end, false));
+            (
+                variable_expression(&end_name),
+                variable_expression(&index_name),
+            )
+        }
+        array => {
+            let array_name = generate_name("array");
+            let array = expr_to_expression(ec, array)?;
+            contents.push(variable_declaration(&array_name, array, false));
+            let zero = Expression::Literal {
+                value: Literal::Numeric(0),
+                span: span.clone(),
+            };
+            contents.push(variable_declaration(&index_name, zero, true));
+            let end = Expression::DelayedMatchTypeResolution {
+                variant: DelayedResolutionVariant::ArrayLength(DelayedArrayLengthResolution {
+                    exp: Box::new(variable_expression(&array_name)),
+                }),
+                span: span.clone(),
+            };
+            let value = Expression::ArrayIndex {
+                prefix: Box::new(variable_expression(&array_name)),
+                index: Box::new(variable_expression(&index_name)),
+                span: span.clone(),
+            };
+            (end, value)
+        }
+    };
+
+    // There's no need to bind the value for each iteration when it is ignored.
+    let mut body = match value_pattern {
+        Pattern::Wildcard { .. } => Vec::new(),
+        value_pattern => {
+            let pattern_span = value_pattern.span();
+            pattern_to_ast_nodes(ec, value_pattern, None, value, pattern_span)?
+        }
+    };
+    let one = Expression::Literal {
+        value: Literal::Numeric(1),
+        span: span.clone(),
+    };
+    let increment = op_call(
+        "add",
+        span.clone(),
+        span.clone(),
+        vec![variable_expression(&index_name), one],
+    );
+    body.push(AstNode {
+        content: AstNodeContent::Declaration(Declaration::Reassignment(Reassignment {
+            lhs: ReassignmentTarget::VariableExpression(Box::new(variable_expression(&index_name))),
+            rhs: increment,
+            span: span.clone(),
+        })),
+        span: span.clone(),
+    });
+    let block = braced_code_block_contents_to_code_block(ec, block)?;
+    body.extend(block.contents);
+
+    let condition = op_call(
+        "lt",
+        span.clone(),
+        span.clone(),
+        vec![variable_expression(&index_name), end],
+    );
+    contents.push(AstNode {
+        content: AstNodeContent::WhileLoop(WhileLoop {
+            condition,
+            body: CodeBlock {
+                contents: body,
+                whole_block_span: block.whole_block_span,
+            },
+        }),
+        span: span.clone(),
+    });
+
+    Ok(Expression::CodeBlock {
+        contents: CodeBlock {
+            contents,
+            whole_block_span: span.clone(),
+        },
+        span,
+    })
+}
+
 fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, ErrorEmitted> {
     let span = expr.span();
     let expression = match expr {
@@ -1099,10 +1592,11 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
         },
         Expr::AbiCast { args, .. } => {
             let AbiCastArgs { name, address, .. } = args.into_inner();
-            let abi_name = path_type_to_call_path(ec, name)?;
+            let (abi_name, type_arguments) = path_type_to_call_path_type_args(ec, name)?;
             let address = Box::new(expr_to_expression(ec, *address)?);
             Expression::AbiCast {
                 abi_name,
+                type_arguments,
                 address,
                 span,
             }
@@ -1231,6 +1725,28 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
             };
             return Err(ec.error(error));
         }
+        Expr::For { for_token, .. } => {
+            let error = ConvertParseTreeError::ForOutsideOfBlock {
+                span: for_token.span(),
+            };
+            return Err(ec.error(error));
+        }
+        Expr::Range { .. } => {
+            let error = ConvertParseTreeError::RangeNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Expr::Break { break_token } => {
+            let error = ConvertParseTreeError::BreakOutsideOfBlock {
+                span: break_token.span(),
+            };
+            return Err(ec.error(error));
+        }
+        Expr::Continue { continue_token } => {
+            let error = ConvertParseTreeError::ContinueOutsideOfBlock {
+                span: continue_token.span(),
+            };
+            return Err(ec.error(error));
+        }
         Expr::FuncApp { func, args } => {
             let path_expr = match *func {
                 Expr::Path(path_expr) => path_expr,
@@ -1401,6 +1917,34 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
                             type_span,
                             span,
                         }
+                    } else if call_path.prefixes.is_empty()
+                        && !call_path.is_absolute
+                        && Intrinsic::try_from_str(call_path.suffix.as_str())
+                            == Some(Intrinsic::StrLen)
+                    {
+                        if !arguments.is_empty() {
+                            let error = ConvertParseTreeError::StrLenTooManyArgs { span };
+                            return Err(ec.error(error));
+                        }
+                        let ty = match {
+                            generics_opt.and_then(|(_double_colon_token, generic_args)| {
+                                iter_to_array(generic_args.parameters.into_inner())
+                            })
+                        } {
+                            Some([ty]) => ty,
+                            None => {
+                                let error = ConvertParseTreeError::StrLenOneGenericArg { span };
+                                return Err(ec.error(error));
+                            }
+                        };
+                        let type_span = ty.span();
+                        let type_name = ty_to_type_info(ec, ty)?;
+                        Expression::BuiltinGetTypeProperty {
+                            builtin: BuiltinProperty::StrLen,
+                            type_name,
+                            type_span,
+                            span,
+                        }
                     } else if call_path.prefixes.is_empty()
                         && !call_path.is_absolute
                         && Intrinsic::try_from_str(call_path.suffix.as_str())
@@ -1414,6 +1958,16 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
                             }
                         };
                         Expression::SizeOfVal { exp, span }
+                    } else if call_path.prefixes.is_empty()
+                        && !call_path.is_absolute
+                        && Intrinsic::try_from_str(call_path.suffix.as_str())
+                            == Some(Intrinsic::GetStorageKey)
+                    {
+                        if !arguments.is_empty() {
+                            let error = ConvertParseTreeError::GetStorageKeyTooManyArgs { span };
+                            return Err(ec.error(error));
+                        }
+                        Expression::BuiltinGetStorageKey { span }
                     } else {
                         let type_arguments = match generics_opt {
                             Some((_double_colon_token, generic_args)) => {
@@ -1528,6 +2082,10 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
         Expr::Deref { deref_token, expr } => {
             unary_op_call(ec, "deref", deref_token.span(), span, *expr)?
         }
+        Expr::MutRef { expr, .. } => Expression::MutRef {
+            expr: Box::new(expr_to_expression(ec, *expr)?),
+            span,
+        },
         Expr::Not { bang_token, expr } => unary_op_call(ec, "not", bang_token.span(), span, *expr)?,
         Expr::Mul {
             lhs,
@@ -1625,6 +2183,37 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
             let error = ConvertParseTreeError::ReassignmentOutsideOfBlock { span };
             return Err(ec.error(error));
         }
+        Expr::Closure {
+            params,
+            return_type_opt,
+            body,
+        } => {
+            let parameters: Vec<FunctionParameter> = params
+                .into_args()
+                .into_iter()
+                .map(|fn_arg| fn_arg_to_function_parameter(ec, fn_arg))
+                .collect::<Result<_, _>>()?;
+            if let Some(parameter) = parameters.iter().find(|p| p.is_reference) {
+                let error = ConvertParseTreeError::MutRefClosureParameter {
+                    span: parameter.type_span.clone(),
+                };
+                return Err(ec.error(error));
+            }
+            let (return_type, return_type_span) = match return_type_opt {
+                Some((_right_arrow_token, ty)) => {
+                    let return_type_span = ty.span();
+                    (ty_to_type_info(ec, ty)?, return_type_span)
+                }
+                None => (TypeInfo::Unknown, body.span()),
+            };
+            Expression::Closure {
+                parameters,
+                return_type,
+                return_type_span,
+                body: Box::new(expr_to_expression(ec, *body)?),
+                span,
+            }
+        }
     };
     Ok(expression)
 }
@@ -1659,7 +2248,17 @@ fn binary_op_call(
     lhs: Expr,
     rhs: Expr,
 ) -> Result<Expression, ErrorEmitted> {
-    Ok(Expression::MethodApplication {
+    let arguments = vec![expr_to_expression(ec, lhs)?, expr_to_expression(ec, rhs)?];
+    Ok(op_call(name, op_span, span, arguments))
+}
+
+fn op_call(
+    name: &'static str,
+    op_span: Span,
+    span: Span,
+    arguments: Vec<Expression>,
+) -> Expression {
+    Expression::MethodApplication {
         method_name: MethodName::FromType {
             call_path: CallPath {
                 prefixes: vec![
@@ -1673,10 +2272,10 @@ fn binary_op_call(
             type_name_span: None,
         },
         contract_call_params: Vec::new(),
-        arguments: vec![expr_to_expression(ec, lhs)?, expr_to_expression(ec, rhs)?],
+        arguments,
         type_arguments: Vec::new(),
         span,
-    })
+    }
 }
 
 fn storage_field_to_storage_field(
@@ -1686,7 +2285,10 @@ fn storage_field_to_storage_field(
     let storage_field = StorageField {
         name: storage_field.name,
         r#type: ty_to_type_info(ec, storage_field.ty)?,
-        //initializer: expr_to_expression(storage_field.expr),
+        initializer: storage_field
+            .initializer
+            .map(|(_eq_token, expr)| expr_to_expression(ec, expr))
+            .transpose()?,
     };
     Ok(storage_field)
 }
@@ -1697,6 +2299,15 @@ fn statement_to_ast_nodes(
 ) -> Result<Vec<AstNode>, ErrorEmitted> {
     let ast_nodes = match statement {
         Statement::Let(statement_let) => statement_let_to_ast_nodes(ec, statement_let)?,
+        Statement::Item(Item {
+            value: ItemKind::Mod(item_mod),
+            ..
+        }) => {
+            let error = ConvertParseTreeError::ModuleNotAllowedHere {
+                span: item_mod.span(),
+            };
+            return Err(ec.error(error));
+        }
         Statement::Item(item) => item_to_ast_nodes(ec, item)?,
         Statement::Expr { expr, .. } => vec![expr_to_ast_node(ec, expr, false)?],
     };
@@ -1745,10 +2356,15 @@ fn fn_arg_to_function_parameter(
             return Err(ec.error(error));
         }
     };
+    let (ty, is_reference) = match fn_arg.ty {
+        Ty::MutRef { ty, .. } => (*ty, true),
+        ty => (ty, false),
+    };
     let function_parameter = FunctionParameter {
         name,
-        type_id: insert_type(ty_to_type_info(ec, fn_arg.ty)?),
+        type_id: insert_type(ty_to_type_info(ec, ty)?),
         type_span,
+        is_reference,
     };
     Ok(function_parameter)
 }
@@ -1884,6 +2500,32 @@ fn path_type_segment_to_ident(
     Ok(name)
 }
 
+/// Similar to [path_type_segment_to_ident], but also returns the type arguments of the segment,
+/// if any.
+fn path_type_segment_to_ident_or_type_argument(
+    ec: &mut ErrorContext,
+    path_type_segment: PathTypeSegment,
+) -> Result<(Ident, Vec<TypeArgument>), ErrorEmitted> {
+    let PathTypeSegment {
+        fully_qualified,
+        name,
+        generics_opt,
+    } = path_type_segment;
+    if let Some(tilde_token) = fully_qualified {
+        let error = ConvertParseTreeError::FullyQualifiedPathsNotSupportedHere {
+            span: tilde_token.span(),
+        };
+        return Err(ec.error(error));
+    }
+    let type_arguments = match generics_opt {
+        Some((_double_colon_token, generic_args)) => {
+            generic_args_to_type_arguments(ec, generic_args)?
+        }
+        None => Vec::new(),
+    };
+    Ok((name, type_arguments))
+}
+
 /// Similar to [path_type_segment_to_ident], but allows for the item to be either
 /// type arguments _or_ an ident.
 fn path_expr_segment_to_ident_or_type_argument(
@@ -1950,6 +2592,25 @@ fn path_expr_to_expression(
             },
             _ => Expression::VariableExpression { name, span },
         }
+    } else if let Some(type_name) = associated_const_type_name_opt(&path_expr) {
+        // `Self::DECIMALS` refers to an associated constant, which is a method without parameters
+        let type_name_span = path_expr.prefix.name.span().clone();
+        let (_double_colon_token, suffix) = path_expr.suffix.into_iter().next().unwrap();
+        Expression::MethodApplication {
+            method_name: MethodName::FromType {
+                call_path: CallPath {
+                    prefixes: Vec::new(),
+                    suffix: path_expr_segment_to_ident(ec, suffix)?,
+                    is_absolute: false,
+                },
+                type_name: Some(type_name),
+                type_name_span: Some(type_name_span),
+            },
+            contract_call_params: Vec::new(),
+            arguments: Vec::new(),
+            type_arguments: Vec::new(),
+            span,
+        }
     } else {
         let call_path = path_expr_to_call_path(ec, path_expr)?;
         Expression::DelineatedPath {
@@ -1962,6 +2623,22 @@ fn path_expr_to_expression(
     Ok(expression)
 }
 
+/// Paths like `Self::DECIMALS` or `u64::MAX`, where the prefix is a built-in type, can only refer
+/// to an associated constant. Other prefixes, such as type parameters, are resolved during type
+/// checking.
+fn associated_const_type_name_opt(path_expr: &PathExpr) -> Option<TypeInfo> {
+    match (&path_expr.root_opt, &path_expr.suffix[..]) {
+        (None, [_suffix]) => {
+            if path_expr.prefix.fully_qualified.is_some() || path_expr.prefix.generics_opt.is_some()
+            {
+                return None;
+            }
+            type_name_to_type_info_opt(&path_expr.prefix.name)
+        }
+        _ => None,
+    }
+}
+
 fn braced_code_block_contents_to_expression(
     ec: &mut ErrorContext,
     braced_code_block_contents: Braces<CodeBlockContents>,
@@ -2148,9 +2825,45 @@ fn literal_to_literal(
                         };
                         Literal::U64(value)
                     }
-                    LitIntType::I8 | LitIntType::I16 | LitIntType::I32 | LitIntType::I64 => {
-                        let error = ConvertParseTreeError::SignedIntegersNotSupported { span };
-                        return Err(ec.error(error));
+                    LitIntType::I8 => {
+                        let value = match i8::try_from(parsed) {
+                            Ok(value) => value,
+                            Err(..) => {
+                                let error = ConvertParseTreeError::I8LiteralOutOfRange { span };
+                                return Err(ec.error(error));
+                            }
+                        };
+                        Literal::I8(value)
+                    }
+                    LitIntType::I16 => {
+                        let value = match i16::try_from(parsed) {
+                            Ok(value) => value,
+                            Err(..) => {
+                                let error = ConvertParseTreeError::I16LiteralOutOfRange { span };
+                                return Err(ec.error(error));
+                            }
+                        };
+                        Literal::I16(value)
+                    }
+                    LitIntType::I32 => {
+                        let value = match i32::try_from(parsed) {
+                            Ok(value) => value,
+                            Err(..) => {
+                                let error = ConvertParseTreeError::I32LiteralOutOfRange { span };
+                                return Err(ec.error(error));
+                            }
+                        };
+                        Literal::I32(value)
+                    }
+                    LitIntType::I64 => {
+                        let value = match i64::try_from(parsed) {
+                            Ok(value) => value,
+                            Err(..) => {
+                                let error = ConvertParseTreeError::I64LiteralOutOfRange { span };
+                                return Err(ec.error(error));
+                            }
+                        };
+                        Literal::I64(value)
                     }
                 },
             }
@@ -2346,130 +3059,9 @@ fn statement_let_to_ast_nodes(
     ec: &mut ErrorContext,
     statement_let: StatementLet,
 ) -> Result<Vec<AstNode>, ErrorEmitted> {
-    fn unfold(
-        ec: &mut ErrorContext,
-        pattern: Pattern,
-        ty_opt: Option<Ty>,
-        expression: Expression,
-        span: Span,
-    ) -> Result<Vec<AstNode>, ErrorEmitted> {
-        let ast_nodes = match pattern {
-            Pattern::Wildcard { .. } => {
-                let ast_node = AstNode {
-                    content: AstNodeContent::Expression(expression),
-                    span,
-                };
-                vec![ast_node]
-            }
-            Pattern::Var { mutable, name } => {
-                let (type_ascription, type_ascription_span) = match ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(ec, ty)?;
-                        (type_ascription, Some(type_ascription_span))
-                    }
-                    None => (TypeInfo::Unknown, None),
-                };
-                let ast_node = AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        VariableDeclaration {
-                            name,
-                            type_ascription,
-                            type_ascription_span,
-                            body: expression,
-                            is_mutable: mutable.is_some(),
-                        },
-                    )),
-                    span,
-                };
-                vec![ast_node]
-            }
-            Pattern::Literal(..) => {
-                let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Constant(..) => {
-                let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Constructor { .. } => {
-                let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Struct { .. } => {
-                let error = ConvertParseTreeError::StructPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Tuple(pat_tuple) => {
-                let mut ast_nodes = Vec::new();
-
-                // Generate a deterministic name for the tuple. Because the parser is single
-                // threaded, the name generated below will be stable.
-                static COUNTER: AtomicUsize = AtomicUsize::new(0);
-                let tuple_name = format!(
-                    "{}{}",
-                    crate::constants::TUPLE_NAME_PREFIX,
-                    COUNTER.load(Ordering::SeqCst)
-                );
-                COUNTER.fetch_add(1, Ordering::SeqCst);
-                let name =
-                    Ident::new_with_override(Box::leak(tuple_name.into_boxed_str()), span.clone());
-
-                let (type_ascription, type_ascription_span) = match &ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(ec, ty.clone())?;
-                        (type_ascription, Some(type_ascription_span))
-                    }
-                    None => (TypeInfo::Unknown, None),
-                };
-                let save_body_first = VariableDeclaration {
-                    name: name.clone(),
-                    type_ascription,
-                    type_ascription_span,
-                    body: expression,
-                    is_mutable: false,
-                };
-                ast_nodes.push(AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        save_body_first,
-                    )),
-                    span: span.clone(),
-                });
-                let new_expr = Expression::VariableExpression {
-                    name,
-                    span: span.clone(),
-                };
-                let tuple_tys_opt = match ty_opt {
-                    Some(Ty::Tuple(tys)) => Some(tys.into_inner().into_iter().collect::<Vec<_>>()),
-                    _ => None,
-                };
-                for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
-                    let ty_opt = match &tuple_tys_opt {
-                        Some(tys) => tys.get(index).cloned(),
-                        None => None,
-                    };
-                    ast_nodes.extend(unfold(
-                        ec,
-                        pattern,
-                        ty_opt,
-                        Expression::TupleIndex {
-                            prefix: Box::new(new_expr.clone()),
-                            index,
-                            index_span: span.clone(),
-                            span: span.clone(),
-                        },
-                        span.clone(),
-                    )?);
-                }
-                ast_nodes
-            }
-        };
-        Ok(ast_nodes)
-    }
     let span = statement_let.span();
     let initial_expression = expr_to_expression(ec, statement_let.expr)?;
-    unfold(
+    pattern_to_ast_nodes(
         ec,
         statement_let.pattern,
         statement_let.ty_opt.map(|(_colon_token, ty)| ty),
@@ -2478,6 +3070,128 @@ fn statement_let_to_ast_nodes(
     )
 }
 
+fn pattern_to_ast_nodes(
+    ec: &mut ErrorContext,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let ast_nodes = match pattern {
+        Pattern::Wildcard { .. } => {
+            let ast_node = AstNode {
+                content: AstNodeContent::Expression(expression),
+                span,
+            };
+            vec![ast_node]
+        }
+        Pattern::Var { mutable, name } => {
+            let (type_ascription, type_ascription_span) = match ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(ec, ty)?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
+            let ast_node = AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    VariableDeclaration {
+                        name,
+                        type_ascription,
+                        type_ascription_span,
+                        body: expression,
+                        is_mutable: mutable.is_some(),
+                    },
+                )),
+                span,
+            };
+            vec![ast_node]
+        }
+        Pattern::Literal(..) => {
+            let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Constant(..) => {
+            let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Constructor { .. } => {
+            let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Struct { .. } => {
+            let error = ConvertParseTreeError::StructPatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Tuple(pat_tuple) => {
+            let mut ast_nodes = Vec::new();
+
+            // Generate a deterministic name for the tuple. Because the parser is single
+            // threaded, the name generated below will be stable.
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let tuple_name = format!(
+                "{}{}",
+                crate::constants::TUPLE_NAME_PREFIX,
+                COUNTER.load(Ordering::SeqCst)
+            );
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+            let name =
+                Ident::new_with_override(Box::leak(tuple_name.into_boxed_str()), span.clone());
+
+            let (type_ascription, type_ascription_span) = match &ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(ec, ty.clone())?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
+            let save_body_first = VariableDeclaration {
+                name: name.clone(),
+                type_ascription,
+                type_ascription_span,
+                body: expression,
+                is_mutable: false,
+            };
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
+            let new_expr = Expression::VariableExpression {
+                name,
+                span: span.clone(),
+            };
+            let tuple_tys_opt = match ty_opt {
+                Some(Ty::Tuple(tys)) => Some(tys.into_inner().into_iter().collect::<Vec<_>>()),
+                _ => None,
+            };
+            for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
+                let ty_opt = match &tuple_tys_opt {
+                    Some(tys) => tys.get(index).cloned(),
+                    None => None,
+                };
+                ast_nodes.extend(pattern_to_ast_nodes(
+                    ec,
+                    pattern,
+                    ty_opt,
+                    Expression::TupleIndex {
+                        prefix: Box::new(new_expr.clone()),
+                        index,
+                        index_span: span.clone(),
+                        span: span.clone(),
+                    },
+                    span.clone(),
+                )?);
+            }
+            ast_nodes
+        }
+    };
+    Ok(ast_nodes)
+}
+
 fn dependency_to_include_statement(dependency: Dependency) -> IncludeStatement {
     IncludeStatement {
         alias: None,
@@ -2616,11 +3330,14 @@ fn ty_to_type_parameter(ec: &mut ErrorContext, ty: Ty) -> Result<TypeParameter,
                 type_id: insert_type(TypeInfo::Unknown),
                 name_ident: underscore_token.into(),
                 trait_constraints: Default::default(),
+                is_const: false,
             })
         }
         Ty::Tuple(..) => panic!("tuple types are not allowed in this position"),
         Ty::Array(..) => panic!("array types are not allowed in this position"),
         Ty::Str { .. } => panic!("str types are not allowed in this position"),
+        Ty::Fn { .. } => panic!("fn types are not allowed in this position"),
+        Ty::MutRef { .. } => panic!("reference types are not allowed in this position"),
     };
     Ok(TypeParameter {
         type_id: insert_type(TypeInfo::Custom {
@@ -2629,6 +3346,7 @@ fn ty_to_type_parameter(ec: &mut ErrorContext, ty: Ty) -> Result<TypeParameter,
         }),
         name_ident,
         trait_constraints: Vec::new(),
+        is_const: false,
     })
 }
 
@@ -2769,6 +3487,18 @@ fn path_type_to_type_info(
         prefix,
         suffix,
     } = path_type;
+    // `Self::Item` refers to an associated type, which is in scope by name within a trait and
+    // its implementations.
+    if let (None, true, [(_double_colon_token, associated_type)]) =
+        (&root_opt, is_self_type_segment(&prefix), &suffix[..])
+    {
+        if associated_type.fully_qualified.is_none() && associated_type.generics_opt.is_none() {
+            return Ok(TypeInfo::Custom {
+                name: associated_type.name.clone(),
+                type_arguments: Vec::new(),
+            });
+        }
+    }
     if root_opt.is_some() || !suffix.is_empty() {
         let error = ConvertParseTreeError::FullySpecifiedTypesNotSupported { span };
         return Err(ec.error(error));
@@ -2840,6 +3570,12 @@ fn path_type_to_type_info(
     Ok(type_info)
 }
 
+fn is_self_type_segment(path_type_segment: &PathTypeSegment) -> bool {
+    path_type_segment.fully_qualified.is_none()
+        && path_type_segment.generics_opt.is_none()
+        && path_type_segment.name.as_str() == "Self"
+}
+
 fn iter_to_array<I, T, const N: usize>(iter: I) -> Option<[T; N]>
 where
     I: IntoIterator<Item = T>,
@@ -554,6 +554,14 @@ pub enum CompileError {
         missing_functions: String,
         span: Span,
     },
+    #[error("Associated type \"{name}\" is not a part of trait \"{trait_name}\".")]
+    AssociatedTypeNotAPartOfTrait {
+        name: Ident,
+        trait_name: Ident,
+        span: Span,
+    },
+    #[error("Associated types are missing from this trait implementation: {missing_types}")]
+    MissingAssociatedTypes { missing_types: String, span: Span },
     #[error("Expected {expected} type arguments, but instead found {given}.")]
     IncorrectNumberOfTypeArguments {
         given: usize,
@@ -659,6 +667,8 @@ pub enum CompileError {
     SymbolNotFound { name: Ident },
     #[error("Symbol \"{name}\" is private.")]
     ImportPrivateSymbol { name: Ident },
+    #[error("Module \"{name}\" is private.")]
+    ModuleIsPrivate { name: Ident },
     #[error(
         "Because this if expression's value is used, an \"else\" branch is required and it must \
          return type \"{r#type}\""
@@ -857,6 +867,10 @@ pub enum CompileError {
     ShadowsOtherSymbol { name: Ident },
     #[error("The name \"{name}\" is already used for a generic parameter in this scope.")]
     GenericShadowsGeneric { name: Ident },
+    #[error(
+        "\"{name}\" is not a const generic parameter, so it cannot be used as an array length."
+    )]
+    ArrayLengthNotConstGeneric { name: Ident },
     #[error("The name \"{name}\" imported through `*` shadows another symbol with the same name.")]
     StarImportShadowsOtherSymbol { name: Ident },
     #[error(
@@ -959,8 +973,77 @@ pub enum CompileError {
     Lex { error: sway_parse::LexError },
     #[error("{}", error)]
     Parse { error: sway_parse::ParseError },
-    #[error("\"where\" clauses are not yet supported")]
-    WhereClauseNotYetSupported { span: Span },
+    #[error(
+        "The trait constraint \"{param}: {trait_name}\" is not satisfied, as type \"{ty}\" does \
+         not implement trait \"{trait_name}\"."
+    )]
+    TraitConstraintNotSatisfied {
+        param: String,
+        ty: String,
+        trait_name: String,
+        span: Span,
+    },
+    #[error("\"break\" used outside of a loop")]
+    BreakOutsideLoop { span: Span },
+    #[error("\"continue\" used outside of a loop")]
+    ContinueOutsideLoop { span: Span },
+    #[error(
+        "A \"for\" loop can only iterate over a range or an array, but this has type \"{ty}\"."
+    )]
+    ForLoopNotIterable { ty: String, span: Span },
+    #[error(
+        "\"__get_storage_key\" can only be used in a method that is called directly on a \
+         storage field."
+    )]
+    GetStorageKeyNotInStorageMethod { span: Span },
+    #[error("\"__str_len\" can only be applied to a string type, but this has type \"{ty}\".")]
+    StrLenNotStr { ty: String, span: Span },
+    #[error(
+        "Storage field \"{field_name}\" has type \"{ty}\", which cannot be kept in contract \
+         storage."
+    )]
+    StorageFieldTypeNotSupported {
+        field_name: Ident,
+        ty: String,
+        span: Span,
+    },
+    #[error(
+        "The initializer for storage field \"{field_name}\" must be a constant expression which \
+         can be evaluated at compile time."
+    )]
+    StorageInitializerNotConstant { field_name: Ident, span: Span },
+    #[error(
+        "Closures cannot capture variables from their environment, but this closure uses \
+         \"{name}\". Pass it in as a parameter instead."
+    )]
+    ClosureCapturesVariable { name: Ident, span: Span },
+    #[error(
+        "This closure cannot be inlined. Closures may only be bound with `let`, passed as \
+         arguments to functions or called."
+    )]
+    ClosureNotInlinable { span: Span },
+    #[error(
+        "This expression cannot be evaluated at compile time, which is required for a constant \
+         initializer. Only literals, other constants, tuples, arrays, structs, enums, arithmetic \
+         and calls to functions without side effects may be used."
+    )]
+    NonConstantExpression { span: Span },
+    #[error("This constant expression overflows its type.")]
+    ConstantOverflow { span: Span },
+    #[error("This constant expression divides by zero.")]
+    ConstantDivisionByZero { span: Span },
+    #[error("`&mut` may only be used for an argument to a `&mut` parameter.")]
+    MutRefNotAllowedHere { span: Span },
+    #[error("Only a variable can be passed by `&mut` reference.")]
+    MutRefNotAVariable { span: Span },
+    #[error(
+        "Cannot borrow immutable variable {name} as mutable. Variable {name} is not declared as          mutable."
+    )]
+    MutRefOfImmutable { name: Ident },
+    #[error("Parameter \"{param}\" is a `&mut` reference. Pass a mutable variable with `&mut`.")]
+    ExpectedMutRefArgument { param: Ident, span: Span },
+    #[error("ABI methods cannot take `&mut` parameters.")]
+    AbiMethodMutRefParameter { span: Span },
 }
 
 impl std::convert::From<TypeError> for CompileError {
@@ -1047,6 +1130,8 @@ impl CompileError {
             UnknownTrait { span, .. } => span.clone(),
             FunctionNotAPartOfInterfaceSurface { span, .. } => span.clone(),
             MissingInterfaceSurfaceMethods { span, .. } => span.clone(),
+            AssociatedTypeNotAPartOfTrait { span, .. } => span.clone(),
+            MissingAssociatedTypes { span, .. } => span.clone(),
             IncorrectNumberOfTypeArguments { span, .. } => span.clone(),
             DoesNotTakeTypeArguments { span, .. } => span.clone(),
             NeedsTypeArguments { span, .. } => span.clone(),
@@ -1064,6 +1149,7 @@ impl CompileError {
             FieldNotFound { field_name, .. } => field_name.span().clone(),
             SymbolNotFound { name, .. } => name.span().clone(),
             ImportPrivateSymbol { name } => name.span().clone(),
+            ModuleIsPrivate { name } => name.span().clone(),
             NoElseBranch { span, .. } => span.clone(),
             UnqualifiedSelfType { span, .. } => span.clone(),
             NotAType { span, .. } => span.clone(),
@@ -1117,6 +1203,7 @@ impl CompileError {
             ArrayOutOfBounds { span, .. } => span.clone(),
             ShadowsOtherSymbol { name } => name.span().clone(),
             GenericShadowsGeneric { name } => name.span().clone(),
+            ArrayLengthNotConstGeneric { name } => name.span().clone(),
             StarImportShadowsOtherSymbol { name } => name.span().clone(),
             MatchWrongType { span, .. } => span.clone(),
             MatchExpressionNonExhaustive { span, .. } => span.clone(),
@@ -1148,7 +1235,24 @@ impl CompileError {
             UnexpectedDeclaration { span, .. } => span.clone(),
             ContractAddressMustBeKnown { span, .. } => span.clone(),
             ConvertParseTree { error } => error.span(),
-            WhereClauseNotYetSupported { span, .. } => span.clone(),
+            TraitConstraintNotSatisfied { span, .. } => span.clone(),
+            BreakOutsideLoop { span } => span.clone(),
+            ContinueOutsideLoop { span } => span.clone(),
+            ForLoopNotIterable { span, .. } => span.clone(),
+            GetStorageKeyNotInStorageMethod { span } => span.clone(),
+            StrLenNotStr { span, .. } => span.clone(),
+            StorageFieldTypeNotSupported { span, .. } => span.clone(),
+            StorageInitializerNotConstant { span, .. } => span.clone(),
+            ClosureCapturesVariable { span, .. } => span.clone(),
+            ClosureNotInlinable { span } => span.clone(),
+            NonConstantExpression { span } => span.clone(),
+            ConstantOverflow { span } => span.clone(),
+            ConstantDivisionByZero { span } => span.clone(),
+            MutRefNotAllowedHere { span } => span.clone(),
+            MutRefNotAVariable { span } => span.clone(),
+            MutRefOfImmutable { name } => name.span().clone(),
+            ExpectedMutRefArgument { span, .. } => span.clone(),
+            AbiMethodMutRefParameter { span } => span.clone(),
             Lex { error } => error.span(),
             Parse { error } => error.span.clone(),
             EnumNotFound { span, .. } => span.clone(),
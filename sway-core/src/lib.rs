@@ -89,8 +89,15 @@ pub enum AstNodeContent {
     /// A control flow element which loops continually until some boolean expression evaluates as
     /// `false`.
     WhileLoop(WhileLoop),
+    /// A `break` statement, which exits the innermost enclosing loop.
+    Break,
+    /// A `continue` statement, which skips to the next iteration of the innermost enclosing
+    /// loop.
+    Continue,
     /// A statement of the form `dep foo::bar;` which imports/includes another source file.
     IncludeStatement(IncludeStatement),
+    /// A nested module declared with `mod foo { ... }` or `mod foo;`.
+    ModuleDeclaration(ModuleDeclaration),
 }
 
 /// Given an input `Arc<str>` and an optional [BuildConfig], parse the input into a [SwayParseTree].
@@ -133,6 +140,26 @@ pub fn parse(input: Arc<str>, config: Option<&BuildConfig>) -> CompileResult<Swa
     ok(sway_parse_tree, warnings, errors)
 }
 
+/// Parse the contents of a module file introduced with `mod foo;`, which contains only items.
+pub(crate) fn parse_module(input: Arc<str>, config: &BuildConfig) -> CompileResult<ParseTree> {
+    let span = span::Span::new(input.clone(), 0, input.len(), Some(config.path())).unwrap();
+    let items = match sway_parse::parse_module_file(input, Some(config.path())) {
+        Ok(items) => items,
+        Err(error) => {
+            let errors = match error {
+                sway_parse::ParseFileError::Lex(error) => vec![CompileError::Lex { error }],
+                sway_parse::ParseFileError::Parse(errors) => errors
+                    .into_iter()
+                    .map(|error| CompileError::Parse { error })
+                    .collect(),
+            };
+            return err(vec![], errors);
+        }
+    };
+    crate::convert_parse_tree::convert_module_items(items)
+        .map(|root_nodes| ParseTree { root_nodes, span })
+}
+
 /// Represents the result of compiling Sway code via [compile_to_asm].
 /// Contains the compiled assets or resulting errors, and any warnings generated.
 pub enum CompilationResult {
@@ -221,15 +248,70 @@ pub(crate) fn compile_inner_dependency(
         None => library_name,
     };
 
-    let mut dep_namespace = parent_namespace.enter_submodule(dep_name);
+    check!(
+        compile_submodule(
+            parse_tree,
+            dep_name,
+            Visibility::Public,
+            &dep_build_config,
+            parent_namespace,
+            dead_code_graph,
+        ),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
+    ok((), warnings, errors)
+}
+
+/// Type-check the items of a module declared with `mod foo { ... }` or `mod foo;` as a submodule
+/// of the current module.
+pub(crate) fn compile_inner_module(
+    module_name: Ident,
+    visibility: Visibility,
+    tree: ParseTree,
+    module_build_config: &BuildConfig,
+    parent_namespace: &mut Namespace,
+    dead_code_graph: &mut ControlFlowGraph,
+) -> CompileResult<()> {
+    let parse_tree = SwayParseTree {
+        tree_type: TreeType::Library {
+            name: module_name.clone(),
+        },
+        tree,
+    };
+    compile_submodule(
+        parse_tree,
+        module_name,
+        visibility,
+        module_build_config,
+        parent_namespace,
+        dead_code_graph,
+    )
+}
+
+fn compile_submodule(
+    parse_tree: SwayParseTree,
+    module_name: Ident,
+    visibility: Visibility,
+    build_config: &BuildConfig,
+    parent_namespace: &mut Namespace,
+    dead_code_graph: &mut ControlFlowGraph,
+) -> CompileResult<()> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let mut module_namespace = parent_namespace.enter_submodule(module_name);
+    module_namespace.set_visibility(visibility);
 
     // Type-check the module, populating its namespace.
     let typed_parse_tree = check!(
         TypedParseTree::type_check(
             parse_tree.tree,
-            &mut dep_namespace,
+            &mut module_namespace,
             &parse_tree.tree_type,
-            &dep_build_config,
+            build_config,
             dead_code_graph,
         ),
         return err(warnings, errors),
@@ -369,11 +451,23 @@ pub fn ast_to_asm(ast_res: CompileAstResult, build_config: &BuildConfig) -> Comp
                     }
                     CompilationResult::Success { asm, warnings }
                 }
-                TreeType::Library { name } => CompilationResult::Library {
-                    warnings,
-                    name,
-                    namespace: Box::new(parse_tree.into_namespace().into()),
-                },
+                TreeType::Library { name } => {
+                    // Libraries don't produce any asm, but we still lower them to IR so that any
+                    // errors are reported when the library itself is built.
+                    if !build_config.use_orig_asm {
+                        check!(
+                            compile_ast_to_ir(*parse_tree.clone(), build_config),
+                            return CompilationResult::Failure { errors, warnings },
+                            warnings,
+                            errors
+                        );
+                    }
+                    CompilationResult::Library {
+                        warnings,
+                        name,
+                        namespace: Box::new(parse_tree.into_namespace().into()),
+                    }
+                }
             }
         }
     }
@@ -381,6 +475,69 @@ pub fn ast_to_asm(ast_res: CompileAstResult, build_config: &BuildConfig) -> Comp
 
 use sway_ir::{context::Context, function::Function};
 
+/// Lower a library to IR, verify it and run the usual optimisations over each of its public
+/// functions.  Libraries have no entry point so the resulting IR isn't compiled any further.
+pub(crate) fn compile_ast_to_ir(
+    ast: TypedParseTree,
+    build_config: &BuildConfig,
+) -> CompileResult<Context> {
+    let mut warnings = Vec::new();
+    let mut errors = Vec::new();
+
+    check!(
+        ast.finalize_types(),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
+    let mut ir = match optimize::compile_ast(ast) {
+        Ok(ir) => ir,
+        Err(e) => {
+            errors.push(e);
+            return err(warnings, errors);
+        }
+    };
+
+    // There's no entry point to inline into, so each public function is treated as one.
+    let mut public_functions = Vec::new();
+    for (idx, fc) in &ir.functions {
+        if fc.is_public {
+            public_functions.push(::sway_ir::function::Function(idx));
+        }
+    }
+    check!(
+        inline_function_calls(&mut ir, &public_functions),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    check!(
+        combine_constants(&mut ir, &public_functions),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
+    // The optimisations above must leave the IR in a valid state.
+    let ir = match ir.verify() {
+        Ok(ir) => ir,
+        Err(ir_error) => {
+            errors.push(CompileError::InternalOwned(
+                ir_error.to_string(),
+                span::Span::dummy(),
+            ));
+            return err(warnings, errors);
+        }
+    };
+
+    if build_config.print_ir {
+        tracing::info!("{}", ir);
+    }
+
+    ok(ir, warnings, errors)
+}
+
 pub(crate) fn compile_ast_to_ir_to_asm(
     ast: TypedParseTree,
     tree_type: TreeType,
@@ -434,9 +591,17 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         errors
     );
 
-    // The only other optimisation we have at the moment is constant combining.  In lieu of a
-    // forthcoming pass manager we can just call it here now.  We can re-use the inline functions
-    // list.
+    // Now that everything is inlined, keep what locals we can in registers rather than on the
+    // stack.
+    check!(
+        promote_locals(&mut ir, &functions_to_inline_to),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
+    // In lieu of a forthcoming pass manager we can just call the other optimisations here now.
+    // We can re-use the inline functions list.
     check!(
         combine_constants(&mut ir, &functions_to_inline_to),
         return err(warnings, errors),
@@ -444,6 +609,23 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         errors
     );
 
+    // Inlining leaves behind unused values and blocks, so clean them up.
+    check!(
+        dce(&mut ir, &functions_to_inline_to),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
+    // Finally, `if` and `match` lowering and the passes above leave lots of small blocks which
+    // can be merged or removed.
+    check!(
+        simplify_cfg(&mut ir, &functions_to_inline_to),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
     if build_config.print_ir {
         tracing::info!("{}", ir);
     }
@@ -481,6 +663,51 @@ fn combine_constants(ir: &mut Context, functions: &[Function]) -> CompileResult<
     ok((), Vec::new(), Vec::new())
 }
 
+fn promote_locals(ir: &mut Context, functions: &[Function]) -> CompileResult<()> {
+    for function in functions {
+        if let Err(ir_error) = sway_ir::optimize::mem2reg(ir, function) {
+            return err(
+                Vec::new(),
+                vec![CompileError::InternalOwned(
+                    ir_error.to_string(),
+                    span::Span::new("".into(), 0, 0, None).unwrap(),
+                )],
+            );
+        }
+    }
+    ok((), Vec::new(), Vec::new())
+}
+
+fn dce(ir: &mut Context, functions: &[Function]) -> CompileResult<()> {
+    for function in functions {
+        if let Err(ir_error) = sway_ir::optimize::dce(ir, function) {
+            return err(
+                Vec::new(),
+                vec![CompileError::InternalOwned(
+                    ir_error.to_string(),
+                    span::Span::new("".into(), 0, 0, None).unwrap(),
+                )],
+            );
+        }
+    }
+    ok((), Vec::new(), Vec::new())
+}
+
+fn simplify_cfg(ir: &mut Context, functions: &[Function]) -> CompileResult<()> {
+    for function in functions {
+        if let Err(ir_error) = sway_ir::optimize::simplify_cfg(ir, function) {
+            return err(
+                Vec::new(),
+                vec![CompileError::InternalOwned(
+                    ir_error.to_string(),
+                    span::Span::new("".into(), 0, 0, None).unwrap(),
+                )],
+            );
+        }
+    }
+    ok((), Vec::new(), Vec::new())
+}
+
 /// Given input Sway source code, compile to a [BytecodeCompilationResult] which contains the asm in
 /// bytecode form.
 pub fn compile_to_bytecode(
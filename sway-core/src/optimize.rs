@@ -1,12 +1,13 @@
 use fuel_crypto::Hasher;
+use fuel_tx::{Bytes32, StorageSlot};
 use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     asm_generation::from_ir::ir_type_size_in_bytes,
     constants,
     error::CompileError,
-    parse_tree::{AsmOp, AsmRegister, BuiltinProperty, LazyOp, Literal, Visibility},
-    semantic_analysis::{ast_node::*, *},
+    parse_tree::{AsmOp, AsmRegister, BuiltinProperty, CallPath, LazyOp, Literal, Visibility},
+    semantic_analysis::{ast_node::*, declaration::TypedStorageField, *},
     type_engine::*,
 };
 
@@ -14,6 +15,9 @@ use sway_types::{ident::Ident, span::Span, state::StateIndex};
 
 use sway_ir::*;
 
+mod const_eval;
+use const_eval::{const_eval_expression, ConstantLookup, ModuleConstants};
+
 // -------------------------------------------------------------------------------------------------
 
 pub(crate) fn compile_ast(ast: TypedParseTree) -> Result<Context, CompileError> {
@@ -26,11 +30,11 @@ pub(crate) fn compile_ast(ast: TypedParseTree) -> Result<Context, CompileError>
             all_nodes: _,
         } => compile_script(&mut ctx, main_function, &namespace, declarations),
         TypedParseTree::Predicate {
-            namespace: _,
-            main_function: _,
-            declarations: _,
+            namespace,
+            main_function,
+            declarations,
             all_nodes: _,
-        } => unimplemented!("compile predicate to ir"),
+        } => compile_predicate(&mut ctx, main_function, &namespace, declarations),
         TypedParseTree::Contract {
             abi_entries,
             namespace,
@@ -38,9 +42,9 @@ pub(crate) fn compile_ast(ast: TypedParseTree) -> Result<Context, CompileError>
             all_nodes: _,
         } => compile_contract(&mut ctx, abi_entries, &namespace, declarations),
         TypedParseTree::Library {
-            namespace: _,
-            all_nodes: _,
-        } => unimplemented!("compile library to ir"),
+            namespace,
+            all_nodes,
+        } => compile_library(&mut ctx, &namespace, all_nodes),
     }?;
     ctx.verify()
         .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))
@@ -58,7 +62,25 @@ fn compile_script(
 
     compile_constants(context, module, namespace, false)?;
     compile_declarations(context, module, declarations)?;
-    compile_function(context, module, main_function)?;
+    compile_function(context, module, main_function, None, HashMap::new())?;
+
+    Ok(module)
+}
+
+fn compile_predicate(
+    context: &mut Context,
+    main_function: TypedFunctionDeclaration,
+    namespace: &namespace::Module,
+    declarations: Vec<TypedDeclaration>,
+) -> Result<Module, CompileError> {
+    // A predicate is compiled exactly like a script; its `main()` has already been checked to
+    // return a `bool` during type checking and the result is returned directly with a `RET`, which
+    // is what the VM inspects when evaluating the predicate.
+    let module = Module::new(context, Kind::Predicate);
+
+    compile_constants(context, module, namespace, false)?;
+    compile_declarations(context, module, declarations)?;
+    compile_function(context, module, main_function, None, HashMap::new())?;
 
     Ok(module)
 }
@@ -80,6 +102,60 @@ fn compile_contract(
     Ok(module)
 }
 
+fn compile_library(
+    context: &mut Context,
+    namespace: &namespace::Module,
+    all_nodes: Vec<TypedAstNode>,
+) -> Result<Module, CompileError> {
+    let module = Module::new(context, Kind::Library);
+
+    compile_constants(context, module, namespace, false)?;
+
+    // Every public function is a potential entry point for a library so they're all lowered.  Any
+    // generic functions are skipped by compile_function() as they can only be monomorphised at
+    // their call sites.
+    for node in all_nodes {
+        if let TypedAstNodeContent::Declaration(TypedDeclaration::FunctionDeclaration(decl)) =
+            node.content
+        {
+            if decl.visibility == Visibility::Public {
+                compile_function(context, module, decl, None, HashMap::new())?;
+            }
+        }
+    }
+    for submodule_ns in namespace.submodules().values() {
+        compile_submodule_functions(context, module, submodule_ns)?;
+    }
+
+    Ok(module)
+}
+
+// The public functions of the library's submodules, e.g., those introduced with `dep`, are lowered
+// too, but not those of its external dependencies which have been lowered when they were built.
+fn compile_submodule_functions(
+    context: &mut Context,
+    module: Module,
+    module_ns: &namespace::Module,
+) -> Result<(), CompileError> {
+    if module_ns.is_external() {
+        return Ok(());
+    }
+
+    for decl in module_ns.get_all_declared_symbols() {
+        if let TypedDeclaration::FunctionDeclaration(decl) = decl {
+            if decl.visibility == Visibility::Public {
+                compile_function(context, module, decl.clone(), None, HashMap::new())?;
+            }
+        }
+    }
+
+    for submodule_ns in module_ns.submodules().values() {
+        compile_submodule_functions(context, module, submodule_ns)?;
+    }
+
+    Ok(())
+}
+
 // -------------------------------------------------------------------------------------------------
 
 fn compile_constants(
@@ -88,8 +164,32 @@ fn compile_constants(
     module_ns: &namespace::Module,
     public_only: bool,
 ) -> Result<(), CompileError> {
+    // Constants may refer to each other, including across modules, so they're all gathered up
+    // front and then evaluated on demand.
+    let mut initializers = Vec::new();
+    collect_constant_initializers(module_ns, public_only, &mut initializers);
+
+    let mut module_consts = ModuleConstants::new(
+        module,
+        initializers
+            .iter()
+            .map(|(name, value)| (name.as_str().to_owned(), *value))
+            .collect(),
+    );
+    for (name, _) in &initializers {
+        module_consts.lookup_constant(context, name.as_str())?;
+    }
+
+    Ok(())
+}
+
+fn collect_constant_initializers<'a>(
+    module_ns: &'a namespace::Module,
+    public_only: bool,
+    initializers: &mut Vec<(&'a Ident, &'a TypedExpression)>,
+) {
     for decl in module_ns.get_all_declared_symbols() {
-        let decl_name_value = match decl {
+        match decl {
             TypedDeclaration::ConstantDeclaration(TypedConstantDeclaration {
                 name,
                 value,
@@ -97,9 +197,7 @@ fn compile_constants(
             }) => {
                 // XXX Do we really only add public constants?
                 if !public_only || matches!(visibility, Visibility::Public) {
-                    Some((name, value))
-                } else {
-                    None
+                    initializers.push((name, value));
                 }
             }
 
@@ -108,37 +206,163 @@ fn compile_constants(
                 body,
                 const_decl_origin,
                 ..
-            }) if *const_decl_origin => Some((name, body)),
-
-            _otherwise => None,
-        };
+            }) if *const_decl_origin => initializers.push((name, body)),
 
-        if let Some((name, value)) = decl_name_value {
-            let const_val = compile_constant_expression(context, value)?;
-            module.add_global_constant(context, name.as_str().to_owned(), const_val);
+            _otherwise => (),
         }
     }
 
     for submodule_ns in module_ns.submodules().values() {
-        compile_constants(context, module, submodule_ns, true)?;
+        collect_constant_initializers(submodule_ns, true, initializers);
     }
+}
 
-    Ok(())
+// -------------------------------------------------------------------------------------------------
+// Storage fields with an initializer are laid out into slots here so that the contract may be
+// deployed with them already set.  The layout must match the one used by
+// FnCompiler::compile_storage_read_or_write() below.
+
+pub(crate) fn compile_storage_slots(
+    fields: &[TypedStorageField],
+    module_ns: &namespace::Module,
+) -> Result<Vec<StorageSlot>, CompileError> {
+    let mut context = Context::default();
+    let module = Module::new(&mut context, Kind::Contract);
+
+    // Initializers are evaluated just like `const` initializers, so they may refer to constants.
+    let mut initializers = Vec::new();
+    collect_constant_initializers(module_ns, false, &mut initializers);
+    let mut module_consts = ModuleConstants::new(
+        module,
+        initializers
+            .iter()
+            .map(|(name, value)| (name.as_str().to_owned(), *value))
+            .collect(),
+    );
+
+    let mut slots = Vec::new();
+    for (ix, field) in fields.iter().enumerate() {
+        if let Some(initializer) = &field.initializer {
+            let r#type = convert_resolved_typeid(&mut context, &field.r#type, &initializer.span)?;
+            let constant = const_eval_expression(&mut context, &mut module_consts, initializer)
+                .map_err(|err| match err {
+                    CompileError::NonConstantExpression { span } => {
+                        CompileError::StorageInitializerNotConstant {
+                            field_name: field.name.clone(),
+                            span,
+                        }
+                    }
+                    otherwise => otherwise,
+                })?;
+            add_storage_slots(
+                &context,
+                &StateIndex::new(ix),
+                vec![],
+                &r#type,
+                &constant,
+                &mut slots,
+            );
+        }
+    }
+
+    // The create transaction requires its storage slots to be sorted by key.
+    slots.sort_by(|lhs, rhs| lhs.key().cmp(rhs.key()));
+    Ok(slots)
 }
 
-fn compile_constant_expression(
-    context: &mut Context,
-    const_expr: &TypedExpression,
-) -> Result<Value, CompileError> {
-    if let TypedExpressionVariant::Literal(literal) = &const_expr.expression {
-        let span_md_idx = MetadataIndex::from_span(context, &const_expr.span);
-        Ok(convert_literal_to_value(context, literal, span_md_idx))
-    } else {
-        Err(CompileError::Internal(
-            "Unsupported constant expression type.",
-            const_expr.span.clone(),
-        ))
+fn add_storage_slots(
+    context: &Context,
+    ix: &StateIndex,
+    indices: Vec<u64>,
+    r#type: &Type,
+    constant: &Constant,
+    slots: &mut Vec<StorageSlot>,
+) {
+    match (r#type, &constant.value) {
+        (Type::Struct(aggregate), ConstantValue::Struct(fields)) => {
+            let field_types = context.aggregates[aggregate.0].field_types();
+            for (field_idx, (field_type, field)) in field_types.iter().zip(fields).enumerate() {
+                let mut new_indices = indices.clone();
+                new_indices.push(field_idx as u64);
+                add_storage_slots(context, ix, new_indices, field_type, field, slots);
+            }
+        }
+        (Type::Array(aggregate), ConstantValue::Array(elems)) => {
+            let (elem_type, _) = context.aggregates[aggregate.0].array_type();
+            for (elem_idx, elem) in elems.iter().enumerate() {
+                let mut new_indices = indices.clone();
+                new_indices.push(elem_idx as u64);
+                add_storage_slots(context, ix, new_indices, elem_type, elem, slots);
+            }
+        }
+        (Type::Unit, _) => (),
+        (Type::String(_) | Type::Union(_), _) => {
+            // Blobs are padded out to a whole number of slots, with each 32 byte chunk indexed as
+            // though it were a field.
+            let mut bytes = serialize_storage_constant(context, r#type, constant);
+            bytes.resize((bytes.len() + 31) / 32 * 32, 0);
+            for (slot_idx, chunk) in bytes.chunks(32).enumerate() {
+                let mut new_indices = indices.clone();
+                new_indices.push(slot_idx as u64);
+                let mut value = [0; 32];
+                value.copy_from_slice(chunk);
+                slots.push(StorageSlot::new(
+                    get_storage_key(ix, &new_indices),
+                    Bytes32::new(value),
+                ));
+            }
+        }
+        _otherwise => {
+            // Words are stored in the first 8 bytes of their slot, the rest of it being zeroed.
+            let mut value = [0; 32];
+            let bytes = serialize_storage_constant(context, r#type, constant);
+            value[..bytes.len()].copy_from_slice(&bytes);
+            slots.push(StorageSlot::new(
+                get_storage_key(ix, &indices),
+                Bytes32::new(value),
+            ));
+        }
+    }
+}
+
+// Serialize a constant to big endian bytes, laid out as it would be in memory.
+fn serialize_storage_constant(context: &Context, r#type: &Type, constant: &Constant) -> Vec<u8> {
+    let mut bytes = match (r#type, &constant.value) {
+        (Type::Struct(aggregate), ConstantValue::Struct(fields)) => context.aggregates[aggregate.0]
+            .field_types()
+            .iter()
+            .zip(fields)
+            .flat_map(|(field_type, field)| serialize_storage_constant(context, field_type, field))
+            .collect(),
+        (Type::Array(aggregate), ConstantValue::Array(elems)) => {
+            let (elem_type, _) = context.aggregates[aggregate.0].array_type();
+            elems
+                .iter()
+                .flat_map(|elem| serialize_storage_constant(context, elem_type, elem))
+                .collect()
+        }
+        // Union variants are all at offset 0, padded out to the size of the largest.
+        (Type::Union(_), _) => serialize_storage_constant(context, &constant.ty, constant),
+        (_, ConstantValue::Bool(b)) => (*b as u64).to_be_bytes().to_vec(),
+        (_, ConstantValue::Uint(n)) => n.to_be_bytes().to_vec(),
+        (_, ConstantValue::B256(bs)) => bs.to_vec(),
+        (_, ConstantValue::String(s)) => s.clone(),
+        _otherwise => vec![],
+    };
+    bytes.resize(ir_type_size_in_bytes(context, r#type) as usize, 0);
+    bytes
+}
+
+fn get_storage_key(ix: &StateIndex, indices: &[u64]) -> Bytes32 {
+    let mut storage_slot_to_hash = format!(
+        "{}{}",
+        sway_utils::constants::STORAGE_DOMAIN_SEPARATOR,
+        ix.to_usize()
+    );
+    for ix in indices {
+        storage_slot_to_hash = format!("{}_{}", storage_slot_to_hash, ix);
     }
+    Hasher::hash(storage_slot_to_hash)
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -161,7 +385,13 @@ fn compile_declarations(
         match declaration {
             TypedDeclaration::ConstantDeclaration(decl) => {
                 // These are in the global scope for the module, so they can be added there.
-                let const_val = compile_constant_expression(context, &decl.value)?;
+                let constant = const_eval_expression(
+                    context,
+                    &mut ModuleConstants::new(module, HashMap::new()),
+                    &decl.value,
+                )?;
+                let span_md_idx = MetadataIndex::from_span(context, &decl.value.span);
+                let const_val = Value::new_constant(context, constant, span_md_idx);
                 module.add_global_constant(context, decl.name.as_str().to_owned(), const_val);
             }
 
@@ -195,7 +425,9 @@ fn compile_declarations(
             | TypedDeclaration::StorageReassignment(_)
             | TypedDeclaration::AbiDeclaration(_)
             | TypedDeclaration::GenericTypeForFunctionScope { .. }
+            | TypedDeclaration::ConstGenericForFunctionScope { .. }
             | TypedDeclaration::StorageDeclaration(_)
+            | TypedDeclaration::TypeAliasDeclaration(_)
             | TypedDeclaration::ErrorRecovery => (),
         }
     }
@@ -256,25 +488,47 @@ fn compile_function(
     context: &mut Context,
     module: Module,
     ast_fn_decl: TypedFunctionDeclaration,
+    self_state_idx: Option<StateIndex>,
+    closures: HashMap<String, TypedExpression>,
 ) -> Result<Option<Function>, CompileError> {
     // Currently monomorphisation of generics is inlined into main() and the functions with generic
-    // args are still present in the AST declarations, but they can be ignored.
-    if !ast_fn_decl.type_parameters.is_empty() {
+    // args are still present in the AST declarations, but they can be ignored.  The same goes for
+    // functions taking closures, which only exist once the closures are bound at a call site.
+    if !ast_fn_decl.type_parameters.is_empty()
+        || ast_fn_decl
+            .parameters
+            .iter()
+            .any(|param| is_fn_type(param.r#type) && !closures.contains_key(param.name.as_str()))
+    {
         Ok(None)
     } else {
         let args = ast_fn_decl
             .parameters
             .iter()
+            .filter(|param| !is_fn_type(param.r#type))
             .map(|param| {
                 convert_resolved_typeid(context, &param.r#type, &param.type_span)
                     .map(|ty| (param.name.as_str().into(), ty, param.name.span().clone()))
             })
             .collect::<Result<Vec<(String, Type, Span)>, CompileError>>()?;
 
-        compile_fn_with_args(context, module, ast_fn_decl, args, None).map(&Some)
+        compile_fn_with_args(
+            context,
+            module,
+            ast_fn_decl,
+            args,
+            None,
+            self_state_idx,
+            closures,
+        )
+        .map(&Some)
     }
 }
 
+fn is_fn_type(type_id: TypeId) -> bool {
+    matches!(look_up_type_id(type_id), TypeInfo::Function { .. })
+}
+
 // -------------------------------------------------------------------------------------------------
 
 fn compile_fn_with_args(
@@ -283,19 +537,33 @@ fn compile_fn_with_args(
     ast_fn_decl: TypedFunctionDeclaration,
     args: Vec<(String, Type, Span)>,
     selector: Option<[u8; 4]>,
+    self_state_idx: Option<StateIndex>,
+    closures: HashMap<String, TypedExpression>,
 ) -> Result<Function, CompileError> {
     let TypedFunctionDeclaration {
         name,
         body,
+        parameters,
         return_type,
         return_type_span,
         visibility,
         ..
     } = ast_fn_decl;
 
+    // `&mut` parameters become pointer args, which are accessed like locals.
     let args = args
         .into_iter()
-        .map(|(name, ty, span)| (name, ty, MetadataIndex::from_span(context, &span)))
+        .map(|(name, ty, span)| {
+            let is_pointer = parameters
+                .iter()
+                .any(|param| param.is_reference && param.name.as_str() == name);
+            (
+                name,
+                ty,
+                is_pointer,
+                MetadataIndex::from_span(context, &span),
+            )
+        })
         .collect();
     let ret_type = convert_resolved_typeid(context, &return_type, &return_type_span)?;
     let func = Function::new(
@@ -310,7 +578,7 @@ fn compile_fn_with_args(
 
     // We clone the struct symbols here, as they contain the globals; any new local declarations
     // may remain within the function scope.
-    let mut compiler = FnCompiler::new(context, module, func);
+    let mut compiler = FnCompiler::new(context, module, func, self_state_idx, closures);
 
     let mut ret_val = compiler.compile_code_block(context, body)?;
 
@@ -371,7 +639,7 @@ fn compile_impl(
             })
             .collect::<Result<Vec<(String, Type, Span)>, CompileError>>()?;
 
-        compile_fn_with_args(context, module, method, args, None)?;
+        compile_fn_with_args(context, module, method, args, None, None, HashMap::new())?;
     }
     Ok(())
 }
@@ -414,7 +682,15 @@ fn compile_abi_method(
         })
         .collect::<Result<Vec<(String, Type, Span)>, CompileError>>()?;
 
-    compile_fn_with_args(context, module, ast_fn_decl, args, Some(selector))
+    compile_fn_with_args(
+        context,
+        module,
+        ast_fn_decl,
+        args,
+        Some(selector),
+        None,
+        HashMap::new(),
+    )
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -424,6 +700,37 @@ struct FnCompiler {
     function: Function,
     current_block: Block,
     lexical_map: LexicalMap,
+    // The (continue, break) target blocks of the loops currently being compiled, innermost last.
+    loop_stack: Vec<(Block, Block)>,
+    // The storage field this function was called on as a method, if any.
+    self_state_idx: Option<StateIndex>,
+    // The closures bound to names in this function, either by `let` or as arguments.  They are
+    // never lowered to values; instead each call through them compiles the closure body afresh.
+    closures: HashMap<String, TypedExpression>,
+    // The compile time values of the constants declared in this function, by local name, so that
+    // later constants may refer to them.
+    local_constants: HashMap<String, Constant>,
+}
+
+impl ConstantLookup for FnCompiler {
+    fn lookup_constant(
+        &mut self,
+        context: &mut Context,
+        name: &str,
+    ) -> Result<Option<Constant>, CompileError> {
+        // Locals and args shadow the module constants, but only constant locals have a value at
+        // compile time.
+        if let Some(local_name) = self.lexical_map.get(name) {
+            return Ok(self.local_constants.get(local_name).cloned());
+        }
+        Ok(self
+            .module
+            .get_global_constant(context, name)
+            .and_then(|value| match &context.values[value.0].value {
+                ValueDatum::Constant(constant) => Some(constant.clone()),
+                _otherwise => None,
+            }))
+    }
 }
 
 pub enum StateAccessType {
@@ -431,8 +738,21 @@ pub enum StateAccessType {
     Write,
 }
 
+/// A `core::ops` operator on primitive integers which is compiled to an instruction rather than a
+/// call.
+enum PrimitiveOp {
+    Binary(BinaryOpKind),
+    Cmp(Predicate),
+}
+
 impl FnCompiler {
-    fn new(context: &mut Context, module: Module, function: Function) -> Self {
+    fn new(
+        context: &mut Context,
+        module: Module,
+        function: Function,
+        self_state_idx: Option<StateIndex>,
+        closures: HashMap<String, TypedExpression>,
+    ) -> Self {
         let lexical_map = LexicalMap::from_iter(
             function
                 .args_iter(context)
@@ -443,6 +763,10 @@ impl FnCompiler {
             function,
             current_block: function.get_entry_block(context),
             lexical_map,
+            loop_stack: Vec::new(),
+            self_state_idx,
+            closures,
+            local_constants: HashMap::new(),
         }
     }
 
@@ -535,13 +859,19 @@ impl FnCompiler {
                             let span_md_idx = MetadataIndex::from_span(context, &span);
                             Ok(Constant::get_unit(context, span_md_idx))
                         }
+                        TypedDeclaration::TypeAliasDeclaration(decl) => {
+                            // Aliases are expanded during type checking.
+                            let span_md_idx = MetadataIndex::from_span(context, &decl.span);
+                            Ok(Constant::get_unit(context, span_md_idx))
+                        }
                         TypedDeclaration::AbiDeclaration(_) => {
                             Err(CompileError::UnexpectedDeclaration {
                                 decl_type: "abi",
                                 span: ast_node.span,
                             })
                         }
-                        TypedDeclaration::GenericTypeForFunctionScope { .. } => {
+                        TypedDeclaration::GenericTypeForFunctionScope { .. }
+                        | TypedDeclaration::ConstGenericForFunctionScope { .. } => {
                             Err(CompileError::UnexpectedDeclaration {
                                 decl_type: "abi",
                                 span: ast_node.span,
@@ -570,6 +900,12 @@ impl FnCompiler {
                     TypedAstNodeContent::WhileLoop(twl) => {
                         self.compile_while_loop(context, twl, span_md_idx)
                     }
+                    TypedAstNodeContent::Break => {
+                        self.compile_loop_jump(context, true, ast_node.span, span_md_idx)
+                    }
+                    TypedAstNodeContent::Continue => {
+                        self.compile_loop_jump(context, false, ast_node.span, span_md_idx)
+                    }
                     // a side effect can be () because it just impacts the type system/namespacing.
                     // There should be no new IR generated.
                     TypedAstNodeContent::SideEffect => Ok(Constant::get_unit(context, None)),
@@ -591,6 +927,9 @@ impl FnCompiler {
         ast_expr: TypedExpression,
     ) -> Result<Value, CompileError> {
         let span_md_idx = MetadataIndex::from_span(context, &ast_expr.span);
+        if let Some(constant) = self.try_const_eval_call(context, &ast_expr) {
+            return Ok(Value::new_constant(context, constant, span_md_idx));
+        }
         match ast_expr.expression {
             TypedExpressionVariant::Literal(l) => {
                 Ok(convert_literal_to_value(context, &l, span_md_idx))
@@ -601,6 +940,8 @@ impl FnCompiler {
                 arguments,
                 function_body,
                 selector,
+                self_state_idx,
+                ..
             } => {
                 if let Some(metadata) = selector {
                     self.compile_contract_call(
@@ -612,12 +953,15 @@ impl FnCompiler {
                         ast_expr.return_type,
                         span_md_idx,
                     )
+                } else if let Some(op) = Self::get_primitive_op(&name, &arguments) {
+                    self.compile_primitive_op(context, op, arguments, span_md_idx)
                 } else {
                     self.compile_fn_call(
                         context,
                         name.suffix.as_str(),
                         arguments,
                         Some(function_body),
+                        self_state_idx,
                         span_md_idx,
                     )
                 }
@@ -716,6 +1060,20 @@ impl FnCompiler {
                 let span_md_idx = MetadataIndex::from_span(context, &access.span());
                 self.compile_storage_access(context, &access.fields, &access.ix, span_md_idx)
             }
+            TypedExpressionVariant::TypeProperty {
+                property: BuiltinProperty::ConstGenericValue,
+                type_id,
+                span,
+            } => match look_up_type_id(type_id) {
+                TypeInfo::ConstGenericValue(value) => {
+                    let span_md_idx = MetadataIndex::from_span(context, &span);
+                    Ok(Constant::get_uint(context, 64, value as u64, span_md_idx))
+                }
+                _ => Err(CompileError::Internal(
+                    "Const generic value has not been inferred.",
+                    span,
+                )),
+            },
             TypedExpressionVariant::TypeProperty {
                 property,
                 type_id,
@@ -732,6 +1090,16 @@ impl FnCompiler {
                     BuiltinProperty::IsRefType => {
                         Ok(Constant::get_bool(context, !ir_type.is_copy_type(), None))
                     }
+                    BuiltinProperty::StrLen => match ir_type {
+                        Type::String(len) => Ok(Constant::get_uint(context, 64, len, None)),
+                        _ => Err(CompileError::StrLenNotStr {
+                            ty: look_up_type_id(type_id).friendly_type_str(),
+                            span,
+                        }),
+                    },
+                    BuiltinProperty::ConstGenericValue => {
+                        unreachable!("const generic values are compiled above")
+                    }
                 }
             }
             TypedExpressionVariant::SizeOfValue { expr } => {
@@ -745,14 +1113,67 @@ impl FnCompiler {
                     None,
                 ))
             }
+            TypedExpressionVariant::GetStorageKey => {
+                let ix =
+                    self.self_state_idx
+                        .ok_or(CompileError::GetStorageKeyNotInStorageMethod {
+                            span: ast_expr.span,
+                        })?;
+                let storage_slot_to_hash = format!(
+                    "{}{}",
+                    sway_utils::constants::STORAGE_DOMAIN_SEPARATOR,
+                    ix.to_usize()
+                );
+                let hashed_storage_slot = Hasher::hash(storage_slot_to_hash);
+                Ok(convert_literal_to_value(
+                    context,
+                    &Literal::B256(hashed_storage_slot.into()),
+                    span_md_idx,
+                ))
+            }
             TypedExpressionVariant::AbiName(_) => {
                 Ok(Value::new_constant(context, Constant::new_unit(), None))
             }
+            TypedExpressionVariant::Closure { .. } => Err(CompileError::ClosureNotInlinable {
+                span: ast_expr.span,
+            }),
+            TypedExpressionVariant::ClosureCall { closure, arguments } => {
+                self.compile_closure_call(context, *closure, arguments, span_md_idx)
+            }
+            TypedExpressionVariant::MutRef { name } => {
+                self.compile_mut_ref(context, name.as_str(), span_md_idx)
+            }
         }
     }
 
     // ---------------------------------------------------------------------------------------------
 
+    // A `&mut` argument is a pointer to the variable's local, to be passed as a pointer arg.
+    fn compile_mut_ref(
+        &mut self,
+        context: &mut Context,
+        name: &str,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let ptr = self
+            .lexical_map
+            .get(name)
+            .and_then(|local_name| self.function.get_local_ptr(context, local_name))
+            .ok_or_else(|| {
+                CompileError::InternalOwned(
+                    format!("Unable to find local for mutable reference to '{name}'."),
+                    Span::dummy(),
+                )
+            })?;
+        let ptr_ty = *ptr.get_type(context);
+        Ok(self
+            .current_block
+            .ins(context)
+            .get_ptr(ptr, ptr_ty, 0, span_md_idx))
+    }
+
+    // ---------------------------------------------------------------------------------------------
+
     fn compile_return_statement(
         &mut self,
         context: &mut Context,
@@ -820,6 +1241,120 @@ impl FnCompiler {
         Ok(final_block.get_phi(context))
     }
 
+    // Calls without arguments, such as to the associated constants like `T::DECIMALS` which are
+    // type checked as methods, are evaluated at compile time when their bodies are constant.
+    // Calls returning unit are only worth making for their side effects, so they're left alone.
+    fn try_const_eval_call(
+        &self,
+        context: &mut Context,
+        ast_expr: &TypedExpression,
+    ) -> Option<Constant> {
+        match &ast_expr.expression {
+            TypedExpressionVariant::FunctionApplication {
+                arguments,
+                selector: None,
+                ..
+            } if arguments.is_empty() => (),
+            _otherwise => return None,
+        }
+        if let TypeInfo::Tuple(fields) = look_up_type_id(ast_expr.return_type) {
+            if fields.is_empty() {
+                return None;
+            }
+        }
+
+        // The callee can only refer to the module constants, not to any locals here.
+        let mut module_consts = ModuleConstants::new(self.module, HashMap::new());
+        const_eval_expression(context, &mut module_consts, ast_expr).ok()
+    }
+
+    fn get_primitive_op(
+        name: &CallPath,
+        ast_args: &[(Ident, TypedExpression)],
+    ) -> Option<PrimitiveOp> {
+        // Operators are desugared to calls to `core::ops` methods.  The implementations for the
+        // unsigned integers are single instructions, so we can emit those directly.
+        let is_core_op = name.is_absolute
+            && name.prefixes.len() == 2
+            && name.prefixes[0].as_str() == "core"
+            && name.prefixes[1].as_str() == "ops";
+        if !is_core_op || ast_args.len() != 2 {
+            return None;
+        }
+
+        let op = match name.suffix.as_str() {
+            "add" => PrimitiveOp::Binary(BinaryOpKind::Add),
+            "subtract" => PrimitiveOp::Binary(BinaryOpKind::Sub),
+            "multiply" => PrimitiveOp::Binary(BinaryOpKind::Mul),
+            "divide" => PrimitiveOp::Binary(BinaryOpKind::Div),
+            "modulo" => PrimitiveOp::Binary(BinaryOpKind::Mod),
+            "binary_and" => PrimitiveOp::Binary(BinaryOpKind::And),
+            "binary_or" => PrimitiveOp::Binary(BinaryOpKind::Or),
+            "binary_xor" => PrimitiveOp::Binary(BinaryOpKind::Xor),
+            "lsh" => PrimitiveOp::Binary(BinaryOpKind::Lsh),
+            "rsh" => PrimitiveOp::Binary(BinaryOpKind::Rsh),
+            "eq" => PrimitiveOp::Cmp(Predicate::Equal),
+            "neq" => PrimitiveOp::Cmp(Predicate::NotEqual),
+            "lt" => PrimitiveOp::Cmp(Predicate::LessThan),
+            "gt" => PrimitiveOp::Cmp(Predicate::GreaterThan),
+            "le" => PrimitiveOp::Cmp(Predicate::LessThanOrEqual),
+            "ge" => PrimitiveOp::Cmp(Predicate::GreaterThanOrEqual),
+            _otherwise => return None,
+        };
+
+        // Signed integers are sign-extended to the full word, so they are equal, and combine
+        // bitwise, exactly as the words do.  The rest of the signed ops are left as calls, as the
+        // VM has no signed instructions and `core::ops` implements them, with their overflow
+        // checks, from unsigned ones.
+        let arg_types = ast_args
+            .iter()
+            .map(|(_, arg)| look_up_type_id(arg.return_type))
+            .collect::<Vec<_>>();
+        let is_unsigned = arg_types
+            .iter()
+            .all(|ty| matches!(ty, TypeInfo::UnsignedInteger(_)));
+        let is_signed = arg_types
+            .iter()
+            .all(|ty| matches!(ty, TypeInfo::SignedInteger(_)));
+        let is_sign_agnostic = matches!(
+            op,
+            PrimitiveOp::Binary(BinaryOpKind::And | BinaryOpKind::Or | BinaryOpKind::Xor)
+                | PrimitiveOp::Cmp(Predicate::Equal | Predicate::NotEqual)
+        );
+        if is_unsigned || (is_signed && is_sign_agnostic) {
+            Some(op)
+        } else {
+            None
+        }
+    }
+
+    fn compile_primitive_op(
+        &mut self,
+        context: &mut Context,
+        op: PrimitiveOp,
+        ast_args: Vec<(Ident, TypedExpression)>,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let mut args = ast_args
+            .into_iter()
+            .map(|(_, arg)| self.compile_expression(context, arg))
+            .collect::<Result<Vec<_>, CompileError>>()?;
+        let rhs_val = args.pop().unwrap();
+        let lhs_val = args.pop().unwrap();
+        Ok(match op {
+            PrimitiveOp::Binary(op) => {
+                self.current_block
+                    .ins(context)
+                    .binary_op(op, lhs_val, rhs_val, span_md_idx)
+            }
+            PrimitiveOp::Cmp(pred) => {
+                self.current_block
+                    .ins(context)
+                    .cmp(pred, lhs_val, rhs_val, span_md_idx)
+            }
+        })
+    }
+
     // ---------------------------------------------------------------------------------------------
 
     #[allow(clippy::too_many_arguments)]
@@ -1042,6 +1577,7 @@ impl FnCompiler {
         _ast_name: &str,
         ast_args: Vec<(Ident, TypedExpression)>,
         callee_body: Option<TypedCodeBlock>,
+        self_state_idx: Option<StateIndex>,
         span_md_idx: Option<MetadataIndex>,
     ) -> Result<Value, CompileError> {
         // XXX OK, now, the old compiler inlines everything very lazily.  Function calls include
@@ -1071,6 +1607,7 @@ impl FnCompiler {
                     name: name.clone(),
                     r#type: expr.return_type,
                     type_span: crate::span::Span::new(" ".into(), 0, 0, None).unwrap(),
+                    is_reference: matches!(expr.expression, TypedExpressionVariant::MutRef { .. }),
                 })
                 .collect();
 
@@ -1097,10 +1634,29 @@ impl FnCompiler {
                 purity: Default::default(),
             };
 
-            let callee = compile_function(context, self.module, callee_fn_decl)?;
+            // Any closures passed as arguments are bound by name in the callee rather than being
+            // passed as values.
+            let (closure_args, value_args): (Vec<_>, Vec<_>) = ast_args
+                .into_iter()
+                .partition(|(_, expr)| is_fn_type(expr.return_type));
+            let closures = closure_args
+                .into_iter()
+                .map(|(name, expr)| {
+                    self.resolve_closure(expr)
+                        .map(|closure| (name.as_str().to_owned(), closure))
+                })
+                .collect::<Result<HashMap<_, _>, CompileError>>()?;
+
+            let callee = compile_function(
+                context,
+                self.module,
+                callee_fn_decl,
+                self_state_idx,
+                closures,
+            )?;
 
             // Now actually call the new function.
-            let args = ast_args
+            let args = value_args
                 .into_iter()
                 .map(|(_, expr)| self.compile_expression(context, expr))
                 .collect::<Result<Vec<Value>, CompileError>>()?;
@@ -1111,6 +1667,62 @@ impl FnCompiler {
         }
     }
 
+    fn compile_closure_call(
+        &mut self,
+        context: &mut Context,
+        closure: TypedExpression,
+        ast_args: Vec<TypedExpression>,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        // A closure call is compiled just like a call to a regular function, with the closure
+        // body wrapped up as the body of the single-use callee.
+        let closure = self.resolve_closure(closure)?;
+        match closure.expression {
+            TypedExpressionVariant::Closure { parameters, body } => {
+                let whole_block_span = body.span.clone();
+                let callee_body = TypedCodeBlock {
+                    contents: vec![TypedAstNode {
+                        content: TypedAstNodeContent::ImplicitReturnExpression(*body),
+                        span: whole_block_span.clone(),
+                    }],
+                    whole_block_span,
+                };
+                let ast_args = parameters
+                    .into_iter()
+                    .map(|param| param.name)
+                    .zip(ast_args.into_iter())
+                    .collect();
+                self.compile_fn_call(
+                    context,
+                    "closure",
+                    ast_args,
+                    Some(callee_body),
+                    None,
+                    span_md_idx,
+                )
+            }
+            _otherwise => unreachable!("resolve_closure() only returns closures"),
+        }
+    }
+
+    // Find the closure expression a function typed expression refers to.  Closures only ever
+    // reach the IR as literals or through the names they have been bound to.
+    fn resolve_closure(&self, ast_expr: TypedExpression) -> Result<TypedExpression, CompileError> {
+        match &ast_expr.expression {
+            TypedExpressionVariant::Closure { .. } => Ok(ast_expr),
+            TypedExpressionVariant::VariableExpression { name } => self
+                .closures
+                .get(name.as_str())
+                .cloned()
+                .ok_or(CompileError::ClosureNotInlinable {
+                    span: ast_expr.span,
+                }),
+            _otherwise => Err(CompileError::ClosureNotInlinable {
+                span: ast_expr.span,
+            }),
+        }
+    }
+
     fn get_codeblock_return_type(codeblock: &TypedCodeBlock) -> Option<TypeId> {
         if codeblock.contents.is_empty() {
             Some(insert_type(TypeInfo::Tuple(Vec::new())))
@@ -1324,7 +1936,9 @@ impl FnCompiler {
     ) -> Result<Value, CompileError> {
         // We're dancing around a bit here to make the blocks sit in the right order.  Ideally we
         // have the cond block, followed by the body block which may contain other blocks, and the
-        // final block comes after any body block(s).
+        // final block comes after any body block(s).  The final block must exist before the body
+        // is compiled so that any `break` can jump to it, so it is created up front and then moved
+        // after the body blocks.
 
         // Jump to the while cond block.
         let cond_block = self.function.create_block(context, Some("while".into()));
@@ -1332,20 +1946,31 @@ impl FnCompiler {
             .ins(context)
             .branch(cond_block, None, None);
 
-        // Fill in the body block now, jump unconditionally to the cond block at its end.
         let body_block = self
             .function
             .create_block(context, Some("while_body".into()));
+        let final_block = self
+            .function
+            .create_block(context, Some("end_while".into()));
+
+        // Fill in the body block now, jump unconditionally to the cond block at its end.  A
+        // `continue` jumps to the cond block and a `break` jumps to the final block.
         self.current_block = body_block;
-        self.compile_code_block(context, ast_while_loop.body)?;
+        self.loop_stack.push((cond_block, final_block));
+        let body_result = self.compile_code_block(context, ast_while_loop.body);
+        self.loop_stack.pop();
+        body_result?;
         self.current_block
             .ins(context)
             .branch(cond_block, None, None);
-
-        // Create the final block after we're finished with the body.
-        let final_block = self
-            .function
-            .create_block(context, Some("end_while".into()));
+        self.function
+            .move_block_to_end(context, &final_block)
+            .map_err(|ir_error| {
+                CompileError::InternalOwned(
+                    ir_error.to_string(),
+                    ast_while_loop.condition.span.clone(),
+                )
+            })?;
 
         // Add the conditional which jumps into the body or out to the final block.
         self.current_block = cond_block;
@@ -1364,6 +1989,38 @@ impl FnCompiler {
 
     // ---------------------------------------------------------------------------------------------
 
+    fn compile_loop_jump(
+        &mut self,
+        context: &mut Context,
+        is_break: bool,
+        span: Span,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let (continue_block, break_block) = match self.loop_stack.last() {
+            Some(blocks) => *blocks,
+            None => {
+                return Err(CompileError::Internal(
+                    "Unable to find an enclosing loop for break or continue.",
+                    span,
+                ))
+            }
+        };
+        let dest_block = if is_break {
+            break_block
+        } else {
+            continue_block
+        };
+        self.current_block
+            .ins(context)
+            .branch(dest_block, None, span_md_idx);
+        // BR is a terminator so we must create a new block here.  If anything is added to it then
+        // it'll almost certainly be dead code.
+        self.current_block = self.function.create_block(context, None);
+        Ok(Constant::get_unit(context, span_md_idx))
+    }
+
+    // ---------------------------------------------------------------------------------------------
+
     fn compile_var_expr(
         &mut self,
         context: &mut Context,
@@ -1411,6 +2068,7 @@ impl FnCompiler {
             name,
             body,
             is_mutable,
+            const_decl_origin,
             ..
         } = ast_var_decl;
         // Nothing to do for an abi cast declarations. The address specified in them is already
@@ -1424,6 +2082,17 @@ impl FnCompiler {
             return Ok(Constant::get_unit(context, span_md_idx));
         }
 
+        // Closures aren't values either; they're only bound here and compiled when called.
+        if is_fn_type(body.return_type) {
+            let closure = self.resolve_closure(body)?;
+            self.closures.insert(name.as_str().to_owned(), closure);
+            return Ok(Constant::get_unit(context, span_md_idx));
+        }
+
+        if const_decl_origin {
+            return self.compile_const_local(context, &name, &body, span_md_idx);
+        }
+
         // Grab these before we move body into compilation.
         let return_type = convert_resolved_typeid(context, &body.return_type, &body.span)?;
 
@@ -1462,29 +2131,55 @@ impl FnCompiler {
         // This is local to the function, so we add it to the locals, rather than the module
         // globals like other const decls.
         let TypedConstantDeclaration { name, value, .. } = ast_const_decl;
+        self.compile_const_local(context, &name, &value, span_md_idx)
+    }
 
-        if let TypedExpressionVariant::Literal(literal) = &value.expression {
-            let initialiser = convert_literal_to_constant(literal);
-            let return_type = convert_resolved_typeid(context, &value.return_type, &value.span)?;
-            let name = name.as_str().to_owned();
-            self.function
-                .new_local_ptr(context, name.clone(), return_type, false, Some(initialiser))
+    fn compile_const_local(
+        &mut self,
+        context: &mut Context,
+        name: &Ident,
+        value: &TypedExpression,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        // Evaluate before inserting the name, as the initializer is still in the previous scope.
+        let constant = const_eval_expression(context, self, value)?;
+        let return_type = convert_resolved_typeid(context, &value.return_type, &value.span)?;
+        let local_name = self.lexical_map.insert(name.as_str().to_owned());
+        self.local_constants
+            .insert(local_name.clone(), constant.clone());
+
+        // Scalar constants are initialised in the data section, but aggregates must be stored
+        // into their local at runtime.
+        if matches!(
+            constant.value,
+            ConstantValue::Array(_) | ConstantValue::Struct(_)
+        ) {
+            let ptr = self
+                .function
+                .new_local_ptr(context, local_name, return_type, false, None)
                 .map_err(|ir_error| {
                     CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
                 })?;
-
-            // We still insert this into the symbol table, as itself... can they be shadowed?
-            // (Hrmm, name resolution in the variable expression code could be smarter about var
-            // decls vs const decls, for now they're essentially the same...)
-            self.lexical_map.insert(name);
-
-            Ok(Constant::get_unit(context, span_md_idx))
+            let ptr_ty = *ptr.get_type(context);
+            if ir_type_size_in_bytes(context, &ptr_ty) > 0 {
+                let ptr_val = self
+                    .current_block
+                    .ins(context)
+                    .get_ptr(ptr, ptr_ty, 0, span_md_idx);
+                let const_val = Value::new_constant(context, constant, span_md_idx);
+                self.current_block
+                    .ins(context)
+                    .store(ptr_val, const_val, span_md_idx);
+            }
         } else {
-            Err(CompileError::Internal(
-                "Unsupported constant declaration type, expecting a literal.",
-                name.span().clone(),
-            ))
+            self.function
+                .new_local_ptr(context, local_name, return_type, false, Some(constant))
+                .map_err(|ir_error| {
+                    CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
+                })?;
         }
+
+        Ok(Constant::get_unit(context, span_md_idx))
     }
 
     // ---------------------------------------------------------------------------------------------
@@ -1666,7 +2361,11 @@ impl FnCompiler {
                         array_expr_span)
                 })
             }
-            ValueDatum::Argument(Type::Array(aggregate)) => Ok(*aggregate),
+            ValueDatum::Argument(Type::Array(aggregate))
+            | ValueDatum::Constant(Constant {
+                ty: Type::Array(aggregate),
+                ..
+            }) => Ok(*aggregate),
             otherwise => Err(CompileError::InternalOwned(
                 format!("Unsupported array value for index expression: {otherwise:?}"),
                 array_expr_span,
@@ -1766,7 +2465,11 @@ impl FnCompiler {
                     )
                 })
             }
-            ValueDatum::Argument(Type::Struct(aggregate)) => Ok(*aggregate),
+            ValueDatum::Argument(Type::Struct(aggregate))
+            | ValueDatum::Constant(Constant {
+                ty: Type::Struct(aggregate),
+                ..
+            }) => Ok(*aggregate),
             otherwise => Err(CompileError::InternalOwned(
                 format!("Unsupported struct value for field expression: {otherwise:?}",),
                 ast_struct_expr_span,
@@ -2076,145 +2779,314 @@ impl FnCompiler {
                 }
                 struct_val
             }
-            Type::Bool | Type::Uint(_) | Type::B256 => {
-                // Calculate the storage location hash for the given field
-                let mut storage_slot_to_hash = format!(
-                    "{}{}",
-                    sway_utils::constants::STORAGE_DOMAIN_SEPARATOR,
-                    ix.to_usize()
-                );
-                for ix in &indices {
-                    storage_slot_to_hash = format!("{}_{}", storage_slot_to_hash, ix);
+            Type::Array(aggregate) => {
+                let mut array_val =
+                    Constant::get_undef(context, Type::Array(*aggregate), span_md_idx);
+
+                let (elem_type, count) = context.aggregates[aggregate.0].array_type();
+                let (elem_type, count) = (*elem_type, *count);
+                for elem_idx in 0..count {
+                    // Array elements are laid out just like struct fields, using the element
+                    // index in place of the field index.
+                    let mut new_indices = indices.clone();
+                    new_indices.push(elem_idx);
+
+                    let elem_idx_val = Constant::get_uint(context, 64, elem_idx, span_md_idx);
+                    match access_type {
+                        StateAccessType::Read => {
+                            let val_to_insert = self.compile_storage_read_or_write(
+                                context,
+                                access_type,
+                                ix,
+                                new_indices,
+                                &elem_type,
+                                rhs,
+                                span_md_idx,
+                            )?;
+                            array_val = self.current_block.ins(context).insert_element(
+                                array_val,
+                                *aggregate,
+                                val_to_insert,
+                                elem_idx_val,
+                                span_md_idx,
+                            );
+                        }
+                        StateAccessType::Write => {
+                            let rhs = self.current_block.ins(context).extract_element(
+                                rhs.expect("expecting a rhs for write"),
+                                *aggregate,
+                                elem_idx_val,
+                                span_md_idx,
+                            );
+                            self.compile_storage_read_or_write(
+                                context,
+                                access_type,
+                                ix,
+                                new_indices,
+                                &elem_type,
+                                &Some(rhs),
+                                span_md_idx,
+                            )?;
+                        }
+                    }
                 }
-                let hashed_storage_slot = Hasher::hash(storage_slot_to_hash);
+                array_val
+            }
+            Type::Unit => {
+                // Unit has no contents, so there is nothing to actually load or store.  This is
+                // mostly for enum variants without a value.
+                match access_type {
+                    StateAccessType::Read => Constant::get_unit(context, span_md_idx),
+                    StateAccessType::Write => rhs.expect("expecting a rhs for write"),
+                }
+            }
+            Type::String(_) | Type::Union(_) => {
+                // There are no fields to recurse into so the whole value is spread across as many
+                // consecutive storage slots as it needs.
+                self.compile_storage_blob_read_or_write(
+                    context,
+                    access_type,
+                    ix,
+                    indices,
+                    r#type,
+                    rhs,
+                    span_md_idx,
+                )?
+            }
+            Type::Bool | Type::Uint(_) => {
+                let key_ptr_val = self.compile_storage_key(context, ix, &indices, span_md_idx)?;
+
+                // These types fit in a word. use state_store_word/state_load_word
+                match access_type {
+                    StateAccessType::Read => {
+                        // `state_load_word` always returns a `u64`. Cast the result back
+                        // to the right type before returning
+                        let load_val = self
+                            .current_block
+                            .ins(context)
+                            .state_load_word(key_ptr_val, span_md_idx);
+                        self.current_block
+                            .ins(context)
+                            .bitcast(load_val, *r#type, span_md_idx)
+                    }
+                    StateAccessType::Write => {
+                        // `state_store_word` requires a `u64`. Cast the value to store to
+                        // `u64` first before actually storing.
+                        let rhs_u64 = self.current_block.ins(context).bitcast(
+                            rhs.expect("expecting a rhs for write"),
+                            Type::Uint(64),
+                            span_md_idx,
+                        );
+                        self.current_block.ins(context).state_store_word(
+                            rhs_u64,
+                            key_ptr_val,
+                            span_md_idx,
+                        );
+                        rhs.expect("expecting a rhs for write")
+                    }
+                }
+            }
+            Type::B256 => {
+                let key_ptr_val = self.compile_storage_key(context, ix, &indices, span_md_idx)?;
 
-                // New name for the key
-                let mut key_name = format!("{}{}", "key_for_", ix.to_usize());
+                // B256 requires 4 words. Use state_load_quad_word/state_store_quad_word
+                // First, create a name for the value to load from or store to
+                let mut value_name = format!("{}{}", "val_for_", ix.to_usize());
                 for ix in &indices {
-                    key_name = format!("{}_{}", key_name, ix);
+                    value_name = format!("{}_{}", value_name, ix);
                 }
-                let alias_key_name = self.lexical_map.insert(key_name.as_str().to_owned());
+                let alias_value_name = self.lexical_map.insert(value_name.as_str().to_owned());
 
-                // Local pointer for the key
-                let key_ptr = self
+                // Local pointer to hold the B256
+                let value_ptr = self
                     .function
-                    .new_local_ptr(context, alias_key_name, Type::B256, true, None)
+                    .new_local_ptr(context, alias_value_name, *r#type, true, None)
                     .map_err(|ir_error| {
                         CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
                     })?;
 
-                // Const value for the key from the hash
-                let const_key = convert_literal_to_value(
-                    context,
-                    &Literal::B256(hashed_storage_slot.into()),
-                    span_md_idx,
-                );
-
-                // Convert the key pointer to a value using get_ptr
-                let key_ptr_ty = *key_ptr.get_type(context);
-                let key_ptr_val =
+                // Convert the local pointer created to a value using get_ptr
+                let value_ptr_val =
                     self.current_block
                         .ins(context)
-                        .get_ptr(key_ptr, key_ptr_ty, 0, span_md_idx);
+                        .get_ptr(value_ptr, *r#type, 0, span_md_idx);
 
-                // Store the const hash value to the key pointer value
-                self.current_block
-                    .ins(context)
-                    .store(key_ptr_val, const_key, span_md_idx);
-
-                match r#type {
-                    Type::Uint(_) | Type::Bool => {
-                        // These types fit in a word. use state_store_word/state_load_word
-                        match access_type {
-                            StateAccessType::Read => {
-                                // `state_load_word` always returns a `u64`. Cast the result back
-                                // to the right type before returning
-                                let load_val = self
-                                    .current_block
-                                    .ins(context)
-                                    .state_load_word(key_ptr_val, span_md_idx);
-                                self.current_block.ins(context).bitcast(
-                                    load_val,
-                                    *r#type,
-                                    span_md_idx,
-                                )
-                            }
-                            StateAccessType::Write => {
-                                // `state_store_word` requires a `u64`. Cast the value to store to
-                                // `u64` first before actually storing.
-                                let rhs_u64 = self.current_block.ins(context).bitcast(
-                                    rhs.expect("expecting a rhs for write"),
-                                    Type::Uint(64),
-                                    span_md_idx,
-                                );
-                                self.current_block.ins(context).state_store_word(
-                                    rhs_u64,
-                                    key_ptr_val,
-                                    span_md_idx,
-                                );
-                                rhs.expect("expecting a rhs for write")
-                            }
-                        }
+                match access_type {
+                    StateAccessType::Read => {
+                        self.current_block.ins(context).state_load_quad_word(
+                            value_ptr_val,
+                            key_ptr_val,
+                            span_md_idx,
+                        );
+                        value_ptr_val
                     }
-                    Type::B256 => {
-                        // B256 requires 4 words. Use state_load_quad_word/state_store_quad_word
-                        // First, create a name for the value to load from or store to
-                        let mut value_name = format!("{}{}", "val_for_", ix.to_usize());
-                        for ix in &indices {
-                            value_name = format!("{}_{}", value_name, ix);
-                        }
-                        let alias_value_name =
-                            self.lexical_map.insert(value_name.as_str().to_owned());
-
-                        // Local pointer to hold the B256
-                        let value_ptr = self
-                            .function
-                            .new_local_ptr(context, alias_value_name, *r#type, true, None)
-                            .map_err(|ir_error| {
-                                CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
-                            })?;
-
-                        // Convert the local pointer created to a value using get_ptr
-                        let value_ptr_val = self.current_block.ins(context).get_ptr(
-                            value_ptr,
-                            *r#type,
-                            0,
+                    StateAccessType::Write => {
+                        // Store the value to the local pointer created for rhs
+                        self.current_block.ins(context).store(
+                            value_ptr_val,
+                            rhs.expect("expecting a rhs for write"),
                             span_md_idx,
                         );
 
-                        match access_type {
-                            StateAccessType::Read => {
-                                self.current_block.ins(context).state_load_quad_word(
-                                    value_ptr_val,
-                                    key_ptr_val,
-                                    span_md_idx,
-                                );
-                                value_ptr_val
-                            }
-                            StateAccessType::Write => {
-                                // Store the value to the local pointer created for rhs
-                                self.current_block.ins(context).store(
-                                    value_ptr_val,
-                                    rhs.expect("expecting a rhs for write"),
-                                    span_md_idx,
-                                );
-
-                                // Finally, just call state_load_quad_word/state_store_quad_word
-                                self.current_block.ins(context).state_store_quad_word(
-                                    value_ptr_val,
-                                    key_ptr_val,
-                                    span_md_idx,
-                                );
-                                rhs.expect("expecting a rhs for write")
-                            }
-                        }
+                        // Finally, just call state_load_quad_word/state_store_quad_word
+                        self.current_block.ins(context).state_store_quad_word(
+                            value_ptr_val,
+                            key_ptr_val,
+                            span_md_idx,
+                        );
+                        rhs.expect("expecting a rhs for write")
                     }
-                    _ => unreachable!(),
                 }
             }
-            _ => unimplemented!("Other types are not yet supported in storage"),
         })
     }
+
+    #[allow(clippy::too_many_arguments)]
+    fn compile_storage_blob_read_or_write(
+        &mut self,
+        context: &mut Context,
+        access_type: &StateAccessType,
+        ix: &StateIndex,
+        indices: Vec<u64>,
+        r#type: &Type,
+        rhs: &Option<Value>,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let size_in_bytes = ir_type_size_in_bytes(context, r#type);
+        let num_slots = (size_in_bytes + 31) / 32;
+
+        // The quad word state instructions always transfer a whole slot, so the value is wrapped
+        // in a struct which is padded out to a multiple of 32 bytes.
+        let padding_in_words = (num_slots * 32 - size_in_bytes) / 8;
+        let mut wrapper_field_types = vec![*r#type];
+        if padding_in_words > 0 {
+            let padding = Aggregate::new_array(context, Type::Uint(64), padding_in_words);
+            wrapper_field_types.push(Type::Array(padding));
+        }
+        let wrapper_aggregate = Aggregate::new_struct(context, wrapper_field_types);
+        let wrapper_type = Type::Struct(wrapper_aggregate);
+
+        // Local pointer to hold the wrapper.
+        let mut value_name = format!("{}{}", "val_for_", ix.to_usize());
+        for ix in &indices {
+            value_name = format!("{}_{}", value_name, ix);
+        }
+        let alias_value_name = self.lexical_map.insert(value_name.as_str().to_owned());
+        let value_ptr = self
+            .function
+            .new_local_ptr(context, alias_value_name, wrapper_type, true, None)
+            .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
+        let value_ptr_val =
+            self.current_block
+                .ins(context)
+                .get_ptr(value_ptr, wrapper_type, 0, span_md_idx);
+
+        if let StateAccessType::Write = access_type {
+            let wrapper_val = Constant::get_undef(context, wrapper_type, span_md_idx);
+            let wrapper_val = self.current_block.ins(context).insert_value(
+                wrapper_val,
+                wrapper_aggregate,
+                rhs.expect("expecting a rhs for write"),
+                vec![0],
+                span_md_idx,
+            );
+            self.current_block
+                .ins(context)
+                .store(value_ptr_val, wrapper_val, span_md_idx);
+        }
+
+        // Each 32 byte chunk of the wrapper gets its own slot, indexed as though the chunks were
+        // fields.
+        for slot_idx in 0..num_slots {
+            let mut new_indices = indices.clone();
+            new_indices.push(slot_idx);
+            let key_ptr_val = self.compile_storage_key(context, ix, &new_indices, span_md_idx)?;
+
+            let chunk_ptr_val = self.current_block.ins(context).get_ptr(
+                value_ptr,
+                Type::B256,
+                slot_idx,
+                span_md_idx,
+            );
+            match access_type {
+                StateAccessType::Read => {
+                    self.current_block.ins(context).state_load_quad_word(
+                        chunk_ptr_val,
+                        key_ptr_val,
+                        span_md_idx,
+                    );
+                }
+                StateAccessType::Write => {
+                    self.current_block.ins(context).state_store_quad_word(
+                        chunk_ptr_val,
+                        key_ptr_val,
+                        span_md_idx,
+                    );
+                }
+            }
+        }
+
+        Ok(match access_type {
+            StateAccessType::Read => {
+                let wrapper_val = self
+                    .current_block
+                    .ins(context)
+                    .load(value_ptr_val, span_md_idx);
+                self.current_block.ins(context).extract_value(
+                    wrapper_val,
+                    wrapper_aggregate,
+                    vec![0],
+                    span_md_idx,
+                )
+            }
+            StateAccessType::Write => rhs.expect("expecting a rhs for write"),
+        })
+    }
+
+    fn compile_storage_key(
+        &mut self,
+        context: &mut Context,
+        ix: &StateIndex,
+        indices: &[u64],
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        // Calculate the storage location hash for the given field
+        let hashed_storage_slot = get_storage_key(ix, indices);
+
+        // New name for the key
+        let mut key_name = format!("{}{}", "key_for_", ix.to_usize());
+        for ix in indices {
+            key_name = format!("{}_{}", key_name, ix);
+        }
+        let alias_key_name = self.lexical_map.insert(key_name.as_str().to_owned());
+
+        // Local pointer for the key
+        let key_ptr = self
+            .function
+            .new_local_ptr(context, alias_key_name, Type::B256, true, None)
+            .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
+
+        // Const value for the key from the hash
+        let const_key = convert_literal_to_value(
+            context,
+            &Literal::B256(hashed_storage_slot.into()),
+            span_md_idx,
+        );
+
+        // Convert the key pointer to a value using get_ptr
+        let key_ptr_ty = *key_ptr.get_type(context);
+        let key_ptr_val =
+            self.current_block
+                .ins(context)
+                .get_ptr(key_ptr, key_ptr_ty, 0, span_md_idx);
+
+        // Store the const hash value to the key pointer value
+        self.current_block
+            .ins(context)
+            .store(key_ptr_val, const_key, span_md_idx);
+
+        Ok(key_ptr_val)
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -2393,6 +3265,11 @@ fn convert_literal_to_value(
         Literal::U16(n) => Constant::get_uint(context, 64, *n as u64, span_id_idx),
         Literal::U32(n) => Constant::get_uint(context, 64, *n as u64, span_id_idx),
         Literal::U64(n) => Constant::get_uint(context, 64, *n, span_id_idx),
+        // Signed integers are sign-extended to the full word, in two's complement.
+        Literal::I8(n) => Constant::get_uint(context, 64, *n as i64 as u64, span_id_idx),
+        Literal::I16(n) => Constant::get_uint(context, 64, *n as i64 as u64, span_id_idx),
+        Literal::I32(n) => Constant::get_uint(context, 64, *n as i64 as u64, span_id_idx),
+        Literal::I64(n) => Constant::get_uint(context, 64, *n as u64, span_id_idx),
         Literal::Numeric(n) => Constant::get_uint(context, 64, *n, span_id_idx),
         Literal::String(s) => {
             Constant::get_string(context, s.as_str().as_bytes().to_vec(), span_id_idx)
@@ -2409,6 +3286,10 @@ fn convert_literal_to_constant(ast_literal: &Literal) -> Constant {
         Literal::U16(n) => Constant::new_uint(64, *n as u64),
         Literal::U32(n) => Constant::new_uint(64, *n as u64),
         Literal::U64(n) => Constant::new_uint(64, *n),
+        Literal::I8(n) => Constant::new_uint(64, *n as i64 as u64),
+        Literal::I16(n) => Constant::new_uint(64, *n as i64 as u64),
+        Literal::I32(n) => Constant::new_uint(64, *n as i64 as u64),
+        Literal::I64(n) => Constant::new_uint(64, *n as u64),
         Literal::Numeric(n) => Constant::new_uint(64, *n),
         Literal::String(s) => Constant::new_string(s.as_str().as_bytes().to_vec()),
         Literal::Boolean(b) => Constant::new_bool(*b),
@@ -2448,6 +3329,7 @@ fn convert_resolved_type(
     Ok(match ast_type {
         // All integers are `u64`, see comment in convert_literal_to_value() above.
         TypeInfo::UnsignedInteger(_) => Type::Uint(64),
+        TypeInfo::SignedInteger(_) => Type::Uint(64),
         TypeInfo::Numeric => Type::Uint(64),
         TypeInfo::Boolean => Type::Bool,
         TypeInfo::Byte => Type::Uint(64),
@@ -2537,6 +3419,24 @@ fn convert_resolved_type(
                 span.clone(),
             ))
         }
+        TypeInfo::Function { .. } => {
+            return Err(CompileError::Internal(
+                "Function type cannot be resolved in IR.",
+                span.clone(),
+            ))
+        }
+        TypeInfo::GenericArray(..) => {
+            return Err(CompileError::Internal(
+                "Array of generic length cannot be resolved in IR.",
+                span.clone(),
+            ))
+        }
+        TypeInfo::ConstGenericValue(..) => {
+            return Err(CompileError::Internal(
+                "Const generic value cannot be resolved in IR.",
+                span.clone(),
+            ))
+        }
     })
 }
 
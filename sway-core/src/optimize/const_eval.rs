@@ -0,0 +1,554 @@
+// Compile time evaluation of `const` initializers.
+//
+// The typed AST for an initializer is interpreted directly, producing an IR `Constant`.  Operators
+// on the primitive types are implemented in the core library as functions wrapping `asm` blocks,
+// so as well as literals and aggregates we evaluate calls to functions (by binding their arguments
+// and evaluating their bodies) and a small arithmetic subset of the VM instructions.  Anything
+// with a side effect, such as loops, reassignment or storage access, is rejected.
+
+use std::collections::HashMap;
+
+use crate::{
+    error::CompileError,
+    parse_tree::{AsmOp, AsmRegister, LazyOp},
+    semantic_analysis::{ast_node::*, *},
+    type_engine::*,
+};
+
+use sway_types::span::Span;
+
+use sway_ir::*;
+
+use super::{
+    convert_literal_to_constant, convert_resolved_typeid, get_struct_name_and_field_index,
+};
+
+/// Resolves the names of other constants referred to by a constant expression.
+pub(super) trait ConstantLookup {
+    fn lookup_constant(
+        &mut self,
+        context: &mut Context,
+        name: &str,
+    ) -> Result<Option<Constant>, CompileError>;
+}
+
+/// Evaluate `expr` at compile time, or fail with an error pointing at the offending part of it.
+pub(super) fn const_eval_expression<L: ConstantLookup>(
+    context: &mut Context,
+    lookup: &mut L,
+    expr: &TypedExpression,
+) -> Result<Constant, CompileError> {
+    let mut evaluator = ConstEvaluator {
+        lookup,
+        frames: vec![Vec::new()],
+        call_spans: Vec::new(),
+    };
+    match evaluator.eval(context, expr) {
+        Ok(constant) => Ok(constant),
+        Err(Interrupt::Error(err)) => Err(err),
+        Err(Interrupt::Return(_)) => Err(CompileError::NonConstantExpression {
+            span: expr.span.clone(),
+        }),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// The module level constants.  These may refer to each other in any order, so each is evaluated
+// on demand when first referred to and then added to the module globals.
+
+pub(super) struct ModuleConstants<'a> {
+    module: Module,
+    pending: HashMap<String, &'a TypedExpression>,
+}
+
+impl<'a> ModuleConstants<'a> {
+    pub(super) fn new(module: Module, pending: HashMap<String, &'a TypedExpression>) -> Self {
+        ModuleConstants { module, pending }
+    }
+}
+
+impl ConstantLookup for ModuleConstants<'_> {
+    fn lookup_constant(
+        &mut self,
+        context: &mut Context,
+        name: &str,
+    ) -> Result<Option<Constant>, CompileError> {
+        if let Some(value) = self.module.get_global_constant(context, name) {
+            return Ok(match &context.values[value.0].value {
+                ValueDatum::Constant(constant) => Some(constant.clone()),
+                _otherwise => None,
+            });
+        }
+
+        // Removing the initializer before evaluating it means a cycle can't recurse forever, but
+        // these are rejected during type checking anyway.
+        match self.pending.remove(name) {
+            None => Ok(None),
+            Some(expr) => {
+                let constant = const_eval_expression(context, self, expr)?;
+                let span_md_idx = MetadataIndex::from_span(context, &expr.span);
+                let value = Value::new_constant(context, constant.clone(), span_md_idx);
+                self.module
+                    .add_global_constant(context, name.to_owned(), value);
+                Ok(Some(constant))
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Evaluation is cut short either by an error or by a `return` from the function being called.
+enum Interrupt {
+    Return(Constant),
+    Error(CompileError),
+}
+
+impl From<CompileError> for Interrupt {
+    fn from(err: CompileError) -> Self {
+        Interrupt::Error(err)
+    }
+}
+
+struct ConstEvaluator<'l, L> {
+    lookup: &'l mut L,
+    // The bindings visible within each of the function calls being evaluated, innermost last.
+    // Later bindings shadow earlier ones, and each block truncates its frame when it ends.
+    frames: Vec<Vec<(String, Constant)>>,
+    // The call sites of the functions being evaluated, used to report errors from within `asm`
+    // blocks at the expression which used them rather than in the library which defined them.
+    call_spans: Vec<Span>,
+}
+
+impl<L: ConstantLookup> ConstEvaluator<'_, L> {
+    fn eval(
+        &mut self,
+        context: &mut Context,
+        expr: &TypedExpression,
+    ) -> Result<Constant, Interrupt> {
+        let non_constant = || {
+            Interrupt::Error(CompileError::NonConstantExpression {
+                span: expr.span.clone(),
+            })
+        };
+
+        match &expr.expression {
+            TypedExpressionVariant::Literal(literal) => Ok(convert_literal_to_constant(literal)),
+            TypedExpressionVariant::VariableExpression { name } => {
+                let local = self
+                    .frames
+                    .last()
+                    .and_then(|frame| frame.iter().rev().find(|(local, _)| local == name.as_str()))
+                    .map(|(_, constant)| constant.clone());
+                match local {
+                    Some(constant) => Ok(constant),
+                    None => self
+                        .lookup
+                        .lookup_constant(context, name.as_str())?
+                        .ok_or_else(non_constant),
+                }
+            }
+            TypedExpressionVariant::Tuple { fields } if fields.is_empty() => {
+                Ok(Constant::new_unit())
+            }
+            TypedExpressionVariant::Tuple { fields } => {
+                let fields = self.eval_all(context, fields.iter())?;
+                self.new_aggregate(context, expr, fields)
+            }
+            TypedExpressionVariant::Array { contents } => {
+                let elems = self.eval_all(context, contents.iter())?;
+                self.new_aggregate(context, expr, elems)
+            }
+            // The fields of a struct expression are already in declaration order.
+            TypedExpressionVariant::StructExpression { fields, .. } => {
+                let fields = self.eval_all(context, fields.iter().map(|field| &field.value))?;
+                self.new_aggregate(context, expr, fields)
+            }
+            TypedExpressionVariant::EnumInstantiation { tag, contents, .. } => {
+                let contents = match contents {
+                    Some(contents) => self.eval(context, contents)?,
+                    None => Constant::new_unit(),
+                };
+                Ok(Constant {
+                    ty: convert_resolved_typeid(context, &expr.return_type, &expr.span)?,
+                    value: ConstantValue::Struct(vec![
+                        Constant::new_uint(64, *tag as u64),
+                        contents,
+                    ]),
+                })
+            }
+            TypedExpressionVariant::StructFieldAccess {
+                prefix,
+                field_to_access,
+                resolved_type_of_parent,
+            } => {
+                let field_idx = get_struct_name_and_field_index(
+                    *resolved_type_of_parent,
+                    &field_to_access.name,
+                )
+                .and_then(|(_, field_idx)| field_idx)
+                .ok_or_else(|| {
+                    CompileError::Internal(
+                        "Unknown struct field in constant field expression.",
+                        field_to_access.span.clone(),
+                    )
+                })?;
+                let prefix = self.eval(context, prefix)?;
+                self.extract_field(prefix, field_idx as usize, &expr.span)
+            }
+            TypedExpressionVariant::TupleElemAccess {
+                prefix,
+                elem_to_access_num,
+                ..
+            } => {
+                let prefix = self.eval(context, prefix)?;
+                self.extract_field(prefix, *elem_to_access_num, &expr.span)
+            }
+            TypedExpressionVariant::ArrayIndex { prefix, index } => {
+                let array = self.eval(context, prefix)?;
+                let index_val = self.eval(context, index)?;
+                match (array.value, index_val.value) {
+                    (ConstantValue::Array(mut elems), ConstantValue::Uint(index_num)) => {
+                        let count = elems.len() as u64;
+                        if index_num >= count {
+                            Err(CompileError::ArrayOutOfBounds {
+                                index: index_num,
+                                count,
+                                span: index.span.clone(),
+                            }
+                            .into())
+                        } else {
+                            Ok(elems.swap_remove(index_num as usize))
+                        }
+                    }
+                    _otherwise => Err(CompileError::Internal(
+                        "Unexpected constant array index expression.",
+                        expr.span.clone(),
+                    )
+                    .into()),
+                }
+            }
+            TypedExpressionVariant::CodeBlock(block) => self.eval_block(context, block),
+            TypedExpressionVariant::IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                if self.eval_bool(context, condition)? {
+                    self.eval(context, then)
+                } else {
+                    match r#else {
+                        Some(r#else) => self.eval(context, r#else),
+                        None => Ok(Constant::new_unit()),
+                    }
+                }
+            }
+            TypedExpressionVariant::LazyOperator { op, lhs, rhs } => {
+                let lhs = self.eval_bool(context, lhs)?;
+                match (op, lhs) {
+                    (LazyOp::And, false) | (LazyOp::Or, true) => Ok(Constant::new_bool(lhs)),
+                    _otherwise => Ok(Constant::new_bool(self.eval_bool(context, rhs)?)),
+                }
+            }
+            // Contract calls and methods on trait constrained type parameters can't be evaluated,
+            // but otherwise the callee body is available to be evaluated in a new frame.
+            TypedExpressionVariant::FunctionApplication {
+                arguments,
+                function_body,
+                selector: None,
+                trait_constraint_method: None,
+                ..
+            } => {
+                let mut frame = Vec::with_capacity(arguments.len());
+                for (name, arg) in arguments {
+                    frame.push((name.as_str().to_owned(), self.eval(context, arg)?));
+                }
+
+                self.frames.push(frame);
+                self.call_spans.push(expr.span.clone());
+                let result = self.eval_block(context, function_body);
+                self.call_spans.pop();
+                self.frames.pop();
+
+                match result {
+                    Err(Interrupt::Return(constant)) => Ok(constant),
+                    otherwise => otherwise,
+                }
+            }
+            TypedExpressionVariant::AsmExpression {
+                registers,
+                body,
+                returns,
+                whole_block_span,
+            } => {
+                let span = self
+                    .call_spans
+                    .last()
+                    .cloned()
+                    .unwrap_or_else(|| whole_block_span.clone());
+                self.eval_asm(context, registers, body, returns, expr.return_type, span)
+            }
+            _otherwise => Err(non_constant()),
+        }
+    }
+
+    fn eval_all<'e>(
+        &mut self,
+        context: &mut Context,
+        exprs: impl Iterator<Item = &'e TypedExpression>,
+    ) -> Result<Vec<Constant>, Interrupt> {
+        exprs.map(|expr| self.eval(context, expr)).collect()
+    }
+
+    fn eval_bool(
+        &mut self,
+        context: &mut Context,
+        expr: &TypedExpression,
+    ) -> Result<bool, Interrupt> {
+        match self.eval(context, expr)?.value {
+            ConstantValue::Bool(b) => Ok(b),
+            _otherwise => Err(CompileError::Internal(
+                "Expecting a boolean constant expression.",
+                expr.span.clone(),
+            )
+            .into()),
+        }
+    }
+
+    fn new_aggregate(
+        &mut self,
+        context: &mut Context,
+        expr: &TypedExpression,
+        contents: Vec<Constant>,
+    ) -> Result<Constant, Interrupt> {
+        let ty = convert_resolved_typeid(context, &expr.return_type, &expr.span)?;
+        let value = match ty {
+            Type::Array(_) => ConstantValue::Array(contents),
+            _otherwise => ConstantValue::Struct(contents),
+        };
+        Ok(Constant { ty, value })
+    }
+
+    fn extract_field(
+        &self,
+        aggregate: Constant,
+        idx: usize,
+        span: &Span,
+    ) -> Result<Constant, Interrupt> {
+        match aggregate.value {
+            ConstantValue::Struct(mut fields) if idx < fields.len() => Ok(fields.swap_remove(idx)),
+            _otherwise => Err(CompileError::Internal(
+                "Unexpected constant aggregate field access.",
+                span.clone(),
+            )
+            .into()),
+        }
+    }
+
+    fn eval_block(
+        &mut self,
+        context: &mut Context,
+        block: &TypedCodeBlock,
+    ) -> Result<Constant, Interrupt> {
+        let scope_start = self.frames.last().map(Vec::len).unwrap_or(0);
+        let mut value = Constant::new_unit();
+        for node in &block.contents {
+            value = match &node.content {
+                TypedAstNodeContent::ReturnStatement(TypedReturnStatement { expr }) => {
+                    return Err(Interrupt::Return(self.eval(context, expr)?));
+                }
+                TypedAstNodeContent::Declaration(TypedDeclaration::VariableDeclaration(
+                    TypedVariableDeclaration { name, body, .. },
+                )) => {
+                    let constant = self.eval(context, body)?;
+                    self.bind(name.as_str(), constant);
+                    Constant::new_unit()
+                }
+                TypedAstNodeContent::Declaration(TypedDeclaration::ConstantDeclaration(
+                    TypedConstantDeclaration { name, value, .. },
+                )) => {
+                    let constant = self.eval(context, value)?;
+                    self.bind(name.as_str(), constant);
+                    Constant::new_unit()
+                }
+                TypedAstNodeContent::Declaration(
+                    TypedDeclaration::Reassignment(_) | TypedDeclaration::StorageReassignment(_),
+                )
+                | TypedAstNodeContent::WhileLoop(_)
+                | TypedAstNodeContent::Break
+                | TypedAstNodeContent::Continue => {
+                    return Err(CompileError::NonConstantExpression {
+                        span: node.span.clone(),
+                    }
+                    .into());
+                }
+                TypedAstNodeContent::Declaration(_) | TypedAstNodeContent::SideEffect => {
+                    Constant::new_unit()
+                }
+                TypedAstNodeContent::Expression(expr)
+                | TypedAstNodeContent::ImplicitReturnExpression(expr) => {
+                    self.eval(context, expr)?
+                }
+            };
+        }
+        if let Some(frame) = self.frames.last_mut() {
+            frame.truncate(scope_start);
+        }
+        Ok(value)
+    }
+
+    fn bind(&mut self, name: &str, constant: Constant) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.push((name.to_owned(), constant));
+        }
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Only the arithmetic, comparison and bitwise instructions over words are supported, which is
+    // everything the core library operators use for the integer and boolean types.
+
+    fn eval_asm(
+        &mut self,
+        context: &mut Context,
+        registers: &[TypedAsmRegisterDeclaration],
+        body: &[AsmOp],
+        returns: &Option<(AsmRegister, Span)>,
+        return_type: TypeId,
+        span: Span,
+    ) -> Result<Constant, Interrupt> {
+        let non_constant =
+            || Interrupt::Error(CompileError::NonConstantExpression { span: span.clone() });
+
+        let mut regs: HashMap<String, u64> = HashMap::new();
+        for TypedAsmRegisterDeclaration { name, initializer } in registers {
+            let word = match initializer {
+                Some(initializer) => match self.eval(context, initializer)?.value {
+                    ConstantValue::Uint(n) => n,
+                    ConstantValue::Bool(b) => b as u64,
+                    ConstantValue::Unit => 0,
+                    // Anything else would be passed by reference.
+                    _otherwise => return Err(non_constant()),
+                },
+                None => 0,
+            };
+            regs.insert(name.as_str().to_owned(), word);
+        }
+
+        for op in body {
+            // The first argument is always the destination register.
+            let dest = match op.op_args.first().map(|arg| arg.as_str()) {
+                Some(dest) if dest != "zero" && dest != "one" => dest,
+                _otherwise => return Err(non_constant()),
+            };
+            let srcs = op.op_args[1..]
+                .iter()
+                .map(|arg| read_reg(&regs, arg.as_str()))
+                .collect::<Option<Vec<u64>>>()
+                .ok_or_else(non_constant)?;
+            let imm = match &op.immediate {
+                Some(imm) => Some(
+                    imm.as_str()
+                        .trim_start_matches('i')
+                        .parse::<u64>()
+                        .map_err(|_| non_constant())?,
+                ),
+                None => None,
+            };
+
+            // The immediate variants are named for their register variants with an `i` suffix.
+            let (op_name, a, b) = match (op.op_name.as_str(), srcs.as_slice(), imm) {
+                (op_name @ ("move" | "not"), [a], None) => (op_name, *a, 0),
+                (op_name, [a, b], None) => (op_name, *a, *b),
+                (op_name, [a], Some(imm)) if op_name.ends_with('i') => {
+                    (&op_name[..op_name.len() - 1], *a, imm)
+                }
+                _otherwise => return Err(non_constant()),
+            };
+
+            let overflow =
+                || Interrupt::Error(CompileError::ConstantOverflow { span: span.clone() });
+            let div_by_zero =
+                || Interrupt::Error(CompileError::ConstantDivisionByZero { span: span.clone() });
+
+            let result = match op_name {
+                "move" => a,
+                "not" => !a,
+                "add" => a.checked_add(b).ok_or_else(overflow)?,
+                "sub" => a.checked_sub(b).ok_or_else(overflow)?,
+                "mul" => a.checked_mul(b).ok_or_else(overflow)?,
+                "div" => a.checked_div(b).ok_or_else(div_by_zero)?,
+                "mod" => a.checked_rem(b).ok_or_else(div_by_zero)?,
+                "exp" => u32::try_from(b)
+                    .ok()
+                    .and_then(|b| a.checked_pow(b))
+                    .ok_or_else(overflow)?,
+                "and" => a & b,
+                "or" => a | b,
+                "xor" => a ^ b,
+                // Shifting out every bit leaves zero rather than being an error.
+                "sll" => u32::try_from(b)
+                    .ok()
+                    .and_then(|b| a.checked_shl(b))
+                    .unwrap_or(0),
+                "srl" => u32::try_from(b)
+                    .ok()
+                    .and_then(|b| a.checked_shr(b))
+                    .unwrap_or(0),
+                "eq" => (a == b) as u64,
+                "gt" => (a > b) as u64,
+                "lt" => (a < b) as u64,
+                _otherwise => return Err(non_constant()),
+            };
+
+            regs.insert(dest.to_owned(), result);
+        }
+
+        let word = match returns {
+            Some((reg, _)) => read_reg(&regs, &reg.name).ok_or_else(non_constant)?,
+            None => return Ok(Constant::new_unit()),
+        };
+        match resolve_type(return_type, &span)
+            .map_err(|ty_err| CompileError::InternalOwned(format!("{:?}", ty_err), span.clone()))?
+        {
+            TypeInfo::Boolean => Ok(Constant::new_bool(word != 0)),
+            TypeInfo::Unit => Ok(Constant::new_unit()),
+            TypeInfo::UnsignedInteger(bits) => {
+                let max = match bits {
+                    IntegerBits::Eight => u8::MAX as u64,
+                    IntegerBits::Sixteen => u16::MAX as u64,
+                    IntegerBits::ThirtyTwo => u32::MAX as u64,
+                    IntegerBits::SixtyFour => u64::MAX,
+                };
+                if word > max {
+                    Err(CompileError::ConstantOverflow { span }.into())
+                } else {
+                    Ok(Constant::new_uint(64, word))
+                }
+            }
+            TypeInfo::SignedInteger(bits) => {
+                // Signed words are sign-extended, so must fall within the range of the type
+                // when read back as an `i64`.
+                let (min, max) = match bits {
+                    IntegerBits::Eight => (i8::MIN as i64, i8::MAX as i64),
+                    IntegerBits::Sixteen => (i16::MIN as i64, i16::MAX as i64),
+                    IntegerBits::ThirtyTwo => (i32::MIN as i64, i32::MAX as i64),
+                    IntegerBits::SixtyFour => (i64::MIN, i64::MAX),
+                };
+                if (min..=max).contains(&(word as i64)) {
+                    Ok(Constant::new_uint(64, word))
+                } else {
+                    Err(CompileError::ConstantOverflow { span }.into())
+                }
+            }
+            _otherwise => Err(non_constant()),
+        }
+    }
+}
+
+fn read_reg(regs: &HashMap<String, u64>, name: &str) -> Option<u64> {
+    match name {
+        "zero" => Some(0),
+        "one" => Some(1),
+        _otherwise => regs.get(name).copied(),
+    }
+}
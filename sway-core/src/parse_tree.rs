@@ -6,6 +6,7 @@ mod expression;
 pub mod ident;
 mod include_statement;
 mod literal;
+mod module_declaration;
 mod return_statement;
 mod use_statement;
 mod visibility;
@@ -17,6 +18,7 @@ pub use declaration::*;
 pub use expression::*;
 pub(crate) use include_statement::IncludeStatement;
 pub use literal::Literal;
+pub(crate) use module_declaration::{ModuleDeclaration, ModuleDeclarationKind};
 pub use return_statement::*;
 pub use use_statement::{ImportType, UseStatement};
 pub use visibility::Visibility;
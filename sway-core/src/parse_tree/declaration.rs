@@ -7,6 +7,7 @@ mod reassignment;
 mod storage;
 mod r#struct;
 mod r#trait;
+mod type_alias;
 mod type_argument;
 mod type_parameter;
 mod variable;
@@ -20,6 +21,7 @@ pub use r#struct::*;
 pub use r#trait::*;
 pub(crate) use reassignment::*;
 pub use storage::*;
+pub use type_alias::*;
 pub(crate) use type_argument::*;
 pub(crate) use type_parameter::*;
 pub use variable::*;
@@ -37,4 +39,5 @@ pub enum Declaration {
     AbiDeclaration(AbiDeclaration),
     ConstantDeclaration(ConstantDeclaration),
     StorageDeclaration(StorageDeclaration),
+    TypeAliasDeclaration(TypeAliasDeclaration),
 }
@@ -1,4 +1,4 @@
-use super::{FunctionDeclaration, TraitFn};
+use super::{FunctionDeclaration, TraitFn, TypeParameter};
 
 use sway_types::{ident::Ident, span::Span};
 
@@ -8,6 +8,8 @@ use sway_types::{ident::Ident, span::Span};
 pub struct AbiDeclaration {
     /// The name of the abi trait (also known as a "contract trait")
     pub name: Ident,
+    /// The type parameters of the abi, e.g. `T` in `abi Vault<T>`
+    pub(crate) type_parameters: Vec<TypeParameter>,
     /// The methods a contract is required to implement in order opt in to this interface
     pub interface_surface: Vec<TraitFn>,
     /// The methods provided to a contract "for free" upon opting in to this interface
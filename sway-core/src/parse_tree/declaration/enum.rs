@@ -3,7 +3,7 @@ use crate::{
     parse_tree::{declaration::TypeParameter, Visibility},
     semantic_analysis::{
         ast_node::{TypedEnumDeclaration, TypedEnumVariant},
-        insert_type_parameters,
+        canonicalize_trait_constraints, insert_type_parameters,
         namespace::Namespace,
     },
     type_engine::*,
@@ -51,7 +51,7 @@ impl EnumDeclaration {
         }
         TypedEnumDeclaration {
             name: self.name.clone(),
-            type_parameters: self.type_parameters.clone(),
+            type_parameters: canonicalize_trait_constraints(namespace, &self.type_parameters),
             variants: variants_buf,
             span: self.span.clone(),
             visibility: self.visibility,
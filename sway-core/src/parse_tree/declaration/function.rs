@@ -51,4 +51,6 @@ pub struct FunctionParameter {
     pub name: Ident,
     pub(crate) type_id: TypeId,
     pub(crate) type_span: Span,
+    /// Whether the parameter is declared `&mut T` (or `&mut self`).
+    pub(crate) is_reference: bool,
 }
@@ -1,19 +1,30 @@
-use super::{FunctionDeclaration, TypeParameter};
+use super::{ConstantDeclaration, FunctionDeclaration, TypeArgument, TypeParameter};
 use crate::{parse_tree::CallPath, type_engine::TypeInfo};
 
-use sway_types::span::Span;
+use sway_types::{ident::Ident, span::Span};
 
 #[derive(Debug, Clone)]
 pub struct ImplTrait {
     pub trait_name: CallPath,
+    // the type arguments of a generic trait, e.g. `b256` in `impl From<b256> for Address`
+    pub(crate) trait_type_arguments: Vec<TypeArgument>,
     pub(crate) type_implementing_for: TypeInfo,
     pub(crate) type_implementing_for_span: Span,
     pub(crate) type_arguments: Vec<TypeParameter>,
     pub functions: Vec<FunctionDeclaration>,
+    pub(crate) constants: Vec<ConstantDeclaration>,
+    pub(crate) associated_types: Vec<AssociatedType>,
     // the span of the whole impl trait and block
     pub(crate) block_span: Span,
 }
 
+/// The type given to an associated type of a trait, like `type Item = u64;`
+#[derive(Debug, Clone)]
+pub struct AssociatedType {
+    pub name: Ident,
+    pub(crate) type_argument: TypeArgument,
+}
+
 /// An impl of methods without a trait
 /// like `impl MyType { fn foo { .. } }`
 #[derive(Debug, Clone)]
@@ -22,6 +33,7 @@ pub struct ImplSelf {
     pub(crate) type_implementing_for_span: Span,
     pub(crate) type_parameters: Vec<TypeParameter>,
     pub functions: Vec<FunctionDeclaration>,
+    pub(crate) constants: Vec<ConstantDeclaration>,
     // the span of the whole impl trait and block
     pub(crate) block_span: Span,
 }
@@ -1,4 +1,4 @@
-use crate::type_engine::*;
+use crate::{parse_tree::Expression, type_engine::*};
 
 use sway_types::{ident::Ident, span::Span};
 
@@ -11,11 +11,11 @@ pub struct StorageDeclaration {
 }
 
 /// An individual field in a storage declaration.
-/// A type annotation _and_ initializer value must be provided. The initializer value must be a
-/// constant expression. For now, that basically means just a literal, but as constant folding
-/// improves, we can update that.
+/// A type annotation must be provided, and an initializer value may be. The initializer value must
+/// be a constant expression: literals, and tuples, arrays, structs and enums built from them.
 #[derive(Debug, Clone)]
 pub struct StorageField {
     pub name: Ident,
     pub r#type: TypeInfo,
+    pub initializer: Option<Expression>,
 }
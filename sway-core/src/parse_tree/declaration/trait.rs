@@ -1,4 +1,4 @@
-use super::{FunctionDeclaration, FunctionParameter};
+use super::{ConstantDeclaration, FunctionDeclaration, FunctionParameter, TypeParameter};
 
 use crate::{
     function::Purity,
@@ -11,8 +11,15 @@ use sway_types::{ident::Ident, span::Span};
 #[derive(Debug, Clone)]
 pub struct TraitDeclaration {
     pub name: Ident,
+    pub(crate) type_parameters: Vec<TypeParameter>,
+    // the associated types of the trait, e.g. `Item` in `type Item;`, which are treated like
+    // type parameters until they are given by an implementation
+    pub(crate) associated_types: Vec<TypeParameter>,
+    // associated constants are part of the interface surface as functions without parameters
     pub(crate) interface_surface: Vec<TraitFn>,
     pub methods: Vec<FunctionDeclaration>,
+    // the default values of associated constants, e.g. `const DECIMALS: u8 = 9;`
+    pub(crate) constants: Vec<ConstantDeclaration>,
     pub(crate) supertraits: Vec<Supertrait>,
     pub visibility: Visibility,
 }
@@ -0,0 +1,17 @@
+use crate::{
+    parse_tree::{declaration::TypeParameter, Visibility},
+    type_engine::TypeInfo,
+};
+
+use sway_types::{ident::Ident, span::Span};
+
+/// A `type Name<T> = ...;` declaration, which introduces a new name for an existing type.
+#[derive(Debug, Clone)]
+pub struct TypeAliasDeclaration {
+    pub name: Ident,
+    pub(crate) type_parameters: Vec<TypeParameter>,
+    pub(crate) ty: TypeInfo,
+    pub(crate) ty_span: Span,
+    pub visibility: Visibility,
+    pub(crate) span: Span,
+}
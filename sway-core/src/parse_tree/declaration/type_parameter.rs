@@ -16,6 +16,9 @@ pub struct TypeParameter {
     pub(crate) type_id: TypeId,
     pub(crate) name_ident: Ident,
     pub(crate) trait_constraints: Vec<TraitConstraint>,
+    /// Whether this is a const parameter, e.g. `const N: u64`, which stands for a `u64` value
+    /// rather than a type.
+    pub(crate) is_const: bool,
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -26,6 +29,7 @@ impl Hash for TypeParameter {
         look_up_type_id(self.type_id).hash(state);
         self.name_ident.hash(state);
         self.trait_constraints.hash(state);
+        self.is_const.hash(state);
     }
 }
 
@@ -37,13 +41,20 @@ impl PartialEq for TypeParameter {
         look_up_type_id(self.type_id) == look_up_type_id(other.type_id)
             && self.name_ident == other.name_ident
             && self.trait_constraints == other.trait_constraints
+            && self.is_const == other.is_const
     }
 }
 
 impl From<&TypeParameter> for TypedDeclaration {
     fn from(n: &TypeParameter) -> Self {
-        TypedDeclaration::GenericTypeForFunctionScope {
-            name: n.name_ident.clone(),
+        if n.is_const {
+            TypedDeclaration::ConstGenericForFunctionScope {
+                name: n.name_ident.clone(),
+            }
+        } else {
+            TypedDeclaration::GenericTypeForFunctionScope {
+                name: n.name_ident.clone(),
+            }
         }
     }
 }
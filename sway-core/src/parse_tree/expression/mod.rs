@@ -3,7 +3,8 @@ use crate::{
     error::*,
     parse_tree::{ident, CallPath, Literal},
     type_engine::TypeInfo,
-    AstNode, AstNodeContent, CodeBlock, Declaration, TypeArgument, VariableDeclaration,
+    AstNode, AstNodeContent, CodeBlock, Declaration, FunctionParameter, TypeArgument,
+    VariableDeclaration,
 };
 use sway_types::{ident::Ident, Span};
 
@@ -131,6 +132,7 @@ pub enum Expression {
     /// A cast of a hash to an ABI for calling a contract.
     AbiCast {
         abi_name: CallPath,
+        type_arguments: Vec<TypeArgument>,
         address: Box<Expression>,
         span: Span,
     },
@@ -170,12 +172,36 @@ pub enum Expression {
         type_span: Span,
         span: Span,
     },
+    /// The storage slot hash of the `storage` field a method is being called on. Only meaningful
+    /// inside the body of a method whose `self` is a storage field, e.g. `StorageMap::insert`.
+    BuiltinGetStorageKey {
+        span: Span,
+    },
+    /// A non-capturing closure, e.g. `|x: u64| x + 1`. If no return type is written it is
+    /// inferred from the body.
+    Closure {
+        parameters: Vec<FunctionParameter>,
+        return_type: TypeInfo,
+        return_type_span: Span,
+        body: Box<Expression>,
+        span: Span,
+    },
+    /// A mutable borrow, e.g. `&mut x`. Only valid as an argument for a `&mut` parameter.
+    MutRef {
+        expr: Box<Expression>,
+        span: Span,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum BuiltinProperty {
     SizeOfType,
     IsRefType,
+    /// The length in bytes of a string type, not counting its padding.
+    StrLen,
+    /// The value of the const generic parameter whose type id is given. This is never written
+    /// in source code; it is what a const generic parameter used as a value type checks to.
+    ConstGenericValue,
 }
 
 #[derive(Debug, Clone)]
@@ -183,6 +209,7 @@ pub enum DelayedResolutionVariant {
     StructField(DelayedStructFieldResolution),
     EnumVariant(DelayedEnumVariantResolution),
     TupleVariant(DelayedTupleVariantResolution),
+    ArrayLength(DelayedArrayLengthResolution),
 }
 
 /// During type checking, this gets replaced with struct field access.
@@ -209,6 +236,13 @@ pub struct DelayedTupleVariantResolution {
     pub elem_num: usize,
 }
 
+/// During type checking, this gets replaced with the length of the array, which is known at
+/// compile time.  Used when desugaring `for` loops over arrays.
+#[derive(Debug, Clone)]
+pub struct DelayedArrayLengthResolution {
+    pub exp: Box<Expression>,
+}
+
 #[derive(Clone, Debug, PartialEq, Hash)]
 pub enum LazyOp {
     And,
@@ -273,6 +307,9 @@ impl Expression {
             IfLet { span, .. } => span,
             SizeOfVal { span, .. } => span,
             BuiltinGetTypeProperty { span, .. } => span,
+            BuiltinGetStorageKey { span } => span,
+            Closure { span, .. } => span,
+            MutRef { span, .. } => span,
         })
         .clone()
     }
@@ -13,6 +13,10 @@ pub enum Literal {
     U16(u16),
     U32(u32),
     U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
     String(span::Span),
     Numeric(u64),
     Boolean(bool),
@@ -63,6 +67,22 @@ impl Hash for Literal {
                 state.write_u8(9);
                 x.hash(state);
             }
+            I8(x) => {
+                state.write_u8(10);
+                x.hash(state);
+            }
+            I16(x) => {
+                state.write_u8(11);
+                x.hash(state);
+            }
+            I32(x) => {
+                state.write_u8(12);
+                x.hash(state);
+            }
+            I64(x) => {
+                state.write_u8(13);
+                x.hash(state);
+            }
         }
     }
 }
@@ -77,6 +97,10 @@ impl PartialEq for Literal {
             (Self::U16(l0), Self::U16(r0)) => l0 == r0,
             (Self::U32(l0), Self::U32(r0)) => l0 == r0,
             (Self::U64(l0), Self::U64(r0)) => l0 == r0,
+            (Self::I8(l0), Self::I8(r0)) => l0 == r0,
+            (Self::I16(l0), Self::I16(r0)) => l0 == r0,
+            (Self::I32(l0), Self::I32(r0)) => l0 == r0,
+            (Self::I64(l0), Self::I64(r0)) => l0 == r0,
             (Self::String(l0), Self::String(r0)) => *l0.as_str() == *r0.as_str(),
             (Self::Numeric(l0), Self::Numeric(r0)) => l0 == r0,
             (Self::Boolean(l0), Self::Boolean(r0)) => l0 == r0,
@@ -96,6 +120,10 @@ impl Literal {
             U16(_) => ResolvedType::UnsignedInteger(IntegerBits::Sixteen),
             U32(_) => ResolvedType::UnsignedInteger(IntegerBits::ThirtyTwo),
             U64(_) => ResolvedType::UnsignedInteger(IntegerBits::SixtyFour),
+            I8(_) => ResolvedType::SignedInteger(IntegerBits::Eight),
+            I16(_) => ResolvedType::SignedInteger(IntegerBits::Sixteen),
+            I32(_) => ResolvedType::SignedInteger(IntegerBits::ThirtyTwo),
+            I64(_) => ResolvedType::SignedInteger(IntegerBits::SixtyFour),
             Numeric(_) => ResolvedType::UnsignedInteger(IntegerBits::SixtyFour),
             String(inner) => ResolvedType::Str(inner.as_str().len() as u64),
             Boolean(_) => ResolvedType::Boolean,
@@ -117,6 +145,11 @@ impl Literal {
                 vec![0, 0, 0, 0, bytes[0], bytes[1], bytes[2], bytes[3]]
             }
             U64(val) => val.to_be_bytes().to_vec(),
+            // Signed values are sign-extended to a full word, in two's complement.
+            I8(val) => (*val as i64).to_be_bytes().to_vec(),
+            I16(val) => (*val as i64).to_be_bytes().to_vec(),
+            I32(val) => (*val as i64).to_be_bytes().to_vec(),
+            I64(val) => val.to_be_bytes().to_vec(),
             Numeric(val) => val.to_be_bytes().to_vec(),
             Boolean(b) => {
                 vec![
@@ -0,0 +1,20 @@
+use crate::{parse_tree::Visibility, AstNode};
+
+use sway_types::{ident::Ident, span::Span};
+
+/// A nested module declared with either `mod foo { ... }` or `mod foo;`.
+#[derive(Clone, Debug)]
+pub struct ModuleDeclaration {
+    pub(crate) name: Ident,
+    pub(crate) visibility: Visibility,
+    pub(crate) kind: ModuleDeclarationKind,
+    pub(crate) span: Span,
+}
+
+#[derive(Clone, Debug)]
+pub enum ModuleDeclarationKind {
+    /// The module's items are declared inline within braces.
+    Inline(Vec<AstNode>),
+    /// The module's items live in a separate `foo.sw` file alongside the declaring file.
+    File,
+}
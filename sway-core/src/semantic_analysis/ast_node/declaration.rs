@@ -3,12 +3,14 @@ mod r#enum;
 mod function;
 mod storage;
 mod r#struct;
+mod type_alias;
 mod variable;
 pub(crate) use create_type_id::*;
 pub use function::*;
 pub use r#enum::*;
 pub use r#struct::*;
 pub use storage::*;
+pub use type_alias::*;
 pub use variable::*;
 
 use super::{
@@ -42,9 +44,14 @@ pub enum TypedDeclaration {
     GenericTypeForFunctionScope {
         name: Ident,
     },
+    // Likewise for const generic parameters, e.g. `N` in `fn sum<const N: u64>(a: [u64; N])`.
+    ConstGenericForFunctionScope {
+        name: Ident,
+    },
     ErrorRecovery,
     StorageDeclaration(TypedStorageDeclaration),
     StorageReassignment(TypeCheckedStorageReassignment),
+    TypeAliasDeclaration(TypedTypeAliasDeclaration),
 }
 
 impl CopyTypes for TypedDeclaration {
@@ -69,7 +76,11 @@ impl CopyTypes for TypedDeclaration {
             AbiDeclaration(..) => (),
             StorageDeclaration(..) => (),
             StorageReassignment(..) => (),
-            GenericTypeForFunctionScope { .. } | ErrorRecovery => (),
+            // the aliased type is only resolved when the alias is used
+            TypeAliasDeclaration(..) => (),
+            GenericTypeForFunctionScope { .. }
+            | ConstGenericForFunctionScope { .. }
+            | ErrorRecovery => (),
         }
     }
 }
@@ -233,9 +244,11 @@ impl TypedDeclaration {
             ImplTrait { .. } => "impl trait",
             AbiDeclaration(..) => "abi",
             GenericTypeForFunctionScope { .. } => "generic type parameter",
+            ConstGenericForFunctionScope { .. } => "const generic parameter",
             ErrorRecovery => "error",
             StorageDeclaration(_) => "contract storage declaration",
             StorageReassignment(_) => "contract storage reassignment",
+            TypeAliasDeclaration(_) => "type alias",
         }
     }
 
@@ -258,7 +271,8 @@ impl TypedDeclaration {
             TypedDeclaration::StorageDeclaration(decl) => insert_type(TypeInfo::Storage {
                 fields: decl.fields_as_typed_struct_fields(),
             }),
-            TypedDeclaration::GenericTypeForFunctionScope { name } => {
+            TypedDeclaration::GenericTypeForFunctionScope { name }
+            | TypedDeclaration::ConstGenericForFunctionScope { name } => {
                 insert_type(TypeInfo::UnknownGeneric { name: name.clone() })
             }
             decl => {
@@ -291,7 +305,10 @@ impl TypedDeclaration {
             ImplTrait { span, .. } => span.clone(),
             StorageDeclaration(decl) => decl.span(),
             StorageReassignment(decl) => decl.span(),
-            ErrorRecovery | GenericTypeForFunctionScope { .. } => {
+            TypeAliasDeclaration(TypedTypeAliasDeclaration { span, .. }) => span.clone(),
+            ErrorRecovery
+            | GenericTypeForFunctionScope { .. }
+            | ConstGenericForFunctionScope { .. } => {
                 unreachable!("No span exists for these ast node types")
             }
         }
@@ -335,6 +352,9 @@ impl TypedDeclaration {
                     name.as_str().into(),
                 TypedDeclaration::EnumDeclaration(TypedEnumDeclaration { name, .. }) =>
                     name.as_str().into(),
+                TypedDeclaration::TypeAliasDeclaration(TypedTypeAliasDeclaration {
+                    name, ..
+                }) => name.as_str().into(),
                 TypedDeclaration::Reassignment(TypedReassignment { lhs, .. }) => lhs
                     .iter()
                     .map(|x| x.name.as_str())
@@ -349,6 +369,7 @@ impl TypedDeclaration {
         use TypedDeclaration::*;
         match self {
             GenericTypeForFunctionScope { .. }
+            | ConstGenericForFunctionScope { .. }
             | Reassignment(..)
             | ImplTrait { .. }
             | StorageDeclaration { .. }
@@ -362,7 +383,8 @@ impl TypedDeclaration {
             | ConstantDeclaration(TypedConstantDeclaration { visibility, .. })
             | FunctionDeclaration(TypedFunctionDeclaration { visibility, .. })
             | TraitDeclaration(TypedTraitDeclaration { visibility, .. })
-            | StructDeclaration(TypedStructDeclaration { visibility, .. }) => *visibility,
+            | StructDeclaration(TypedStructDeclaration { visibility, .. })
+            | TypeAliasDeclaration(TypedTypeAliasDeclaration { visibility, .. }) => *visibility,
         }
     }
 }
@@ -373,6 +395,8 @@ impl TypedDeclaration {
 pub struct TypedAbiDeclaration {
     /// The name of the abi trait (also known as a "contract trait")
     pub(crate) name: Ident,
+    /// The type parameters of the abi, which are substituted when the abi is implemented or cast
+    pub(crate) type_parameters: Vec<TypeParameter>,
     /// The methods a contract is required to implement in order opt in to this interface
     pub(crate) interface_surface: Vec<TypedTraitFn>,
     /// The methods provided to a contract "for free" upon opting in to this interface
@@ -412,6 +436,8 @@ impl CopyTypes for TypedConstantDeclaration {
 #[derivative(PartialEq, Eq)]
 pub struct TypedTraitDeclaration {
     pub(crate) name: Ident,
+    pub(crate) type_parameters: Vec<TypeParameter>,
+    pub(crate) associated_types: Vec<TypeParameter>,
     pub(crate) interface_surface: Vec<TypedTraitFn>,
     // NOTE: deriving partialeq and hash on this element may be important in the
     // future, but I am not sure. For now, adding this would 2x the amount of
@@ -419,6 +445,9 @@ pub struct TypedTraitDeclaration {
     #[derivative(PartialEq = "ignore")]
     #[derivative(Eq(bound = ""))]
     pub(crate) methods: Vec<FunctionDeclaration>,
+    #[derivative(PartialEq = "ignore")]
+    #[derivative(Eq(bound = ""))]
+    pub(crate) constants: Vec<ConstantDeclaration>,
     pub(crate) supertraits: Vec<Supertrait>,
     pub(crate) visibility: Visibility,
 }
@@ -446,6 +475,9 @@ pub struct TypedTraitFn {
 
 impl CopyTypes for TypedTraitFn {
     fn copy_types(&mut self, type_mapping: &TypeMapping) {
+        self.parameters
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping));
         self.return_type = if let Some(matching_id) =
             look_up_type_id(self.return_type).matches_type_parameter(type_mapping)
         {
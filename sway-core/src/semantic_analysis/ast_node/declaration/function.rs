@@ -3,8 +3,9 @@ use crate::{
     parse_tree::*,
     semantic_analysis::{
         ast_node::{
-            copy_types::insert_type_parameters, IsConstant, Mode, TypedCodeBlock, TypedDeclaration,
-            TypedExpression, TypedExpressionVariant, TypedReturnStatement,
+            canonicalize_trait_constraints, copy_types::insert_type_parameters,
+            error_recovery_expr, insert_trait_constraints, IsConstant, Mode, TypedCodeBlock,
+            TypedDeclaration, TypedExpression, TypedExpressionVariant, TypedReturnStatement,
             TypedVariableDeclaration, VariableMutability,
         },
         CopyTypes, TypeCheckArguments, TypeMapping, TypedAstNode, TypedAstNodeContent,
@@ -115,8 +116,11 @@ impl TypedFunctionDeclaration {
         } = fn_decl;
         is_snake_case(&name).ok(&mut warnings, &mut errors);
         opts.purity = purity;
+        // A `break` or `continue` in this function cannot refer to a loop outside of it.
+        opts.in_loop = false;
 
         // insert type parameters as Unknown types
+        let type_parameters = canonicalize_trait_constraints(namespace, &type_parameters);
         let type_mapping = insert_type_parameters(&type_parameters);
 
         // insert parameters and generic type declarations into namespace
@@ -133,6 +137,14 @@ impl TypedFunctionDeclaration {
             );
         }
 
+        // make the methods of the traits constraining the type parameters available
+        check!(
+            insert_trait_constraints(&mut fn_namespace, &type_parameters),
+            (),
+            warnings,
+            errors
+        );
+
         parameters.iter_mut().for_each(|parameter| {
             parameter.type_id =
                 match look_up_type_id(parameter.type_id).matches_type_parameter(&type_mapping) {
@@ -151,7 +163,13 @@ impl TypedFunctionDeclaration {
                 };
         });
 
-        for FunctionParameter { name, type_id, .. } in parameters.clone() {
+        for FunctionParameter {
+            name,
+            type_id,
+            is_reference,
+            ..
+        } in parameters.clone()
+        {
             fn_namespace.insert_symbol(
                 name.clone(),
                 TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
@@ -162,7 +180,11 @@ impl TypedFunctionDeclaration {
                         is_constant: IsConstant::No,
                         span: name.span().clone(),
                     },
-                    is_mutable: VariableMutability::Immutable,
+                    is_mutable: if is_reference {
+                        VariableMutability::Mutable
+                    } else {
+                        VariableMutability::Immutable
+                    },
                     const_decl_origin: false,
                     type_ascription: type_id,
                 }),
@@ -218,10 +240,12 @@ impl TypedFunctionDeclaration {
                      name,
                      type_id: r#type,
                      type_span,
+                     is_reference,
                  }| TypedFunctionParameter {
                     name,
                     r#type,
                     type_span,
+                    is_reference,
                 },
             )
             .collect::<Vec<_>>();
@@ -263,6 +287,80 @@ impl TypedFunctionDeclaration {
         )
     }
 
+    /// Type checks an associated constant of a trait or impl, e.g. `const DECIMALS: u8 = 9;`, as
+    /// a function without parameters which returns its value. This lets `Self::DECIMALS` and
+    /// `T::DECIMALS` be resolved through the trait map like any other method.
+    pub(crate) fn type_check_associated_const(
+        arguments: TypeCheckArguments<'_, ConstantDeclaration>,
+    ) -> CompileResult<TypedFunctionDeclaration> {
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+        let TypeCheckArguments {
+            checkee: const_decl,
+            namespace,
+            self_type,
+            build_config,
+            dead_code_graph,
+            mut opts,
+            ..
+        } = arguments;
+        let ConstantDeclaration {
+            name,
+            type_ascription,
+            value,
+            visibility,
+        } = const_decl;
+        is_screaming_snake_case(&name).ok(&mut warnings, &mut errors);
+        opts.purity = Purity::Pure;
+
+        let span = value.span();
+        let return_type = check!(
+            namespace.resolve_type_with_self(type_ascription, self_type, name.span().clone(), true),
+            insert_type(TypeInfo::ErrorRecovery),
+            warnings,
+            errors,
+        );
+        let value = check!(
+            TypedExpression::type_check(TypeCheckArguments {
+                checkee: value,
+                namespace,
+                return_type_annotation: return_type,
+                help_text: "This declaration's type annotation does not match up with the \
+                    assigned expression's type.",
+                self_type,
+                build_config,
+                dead_code_graph,
+                mode: Mode::NonAbi,
+                opts,
+            }),
+            error_recovery_expr(span.clone()),
+            warnings,
+            errors
+        );
+        ok(
+            TypedFunctionDeclaration {
+                name: name.clone(),
+                body: TypedCodeBlock {
+                    contents: vec![TypedAstNode {
+                        content: TypedAstNodeContent::ImplicitReturnExpression(value),
+                        span: span.clone(),
+                    }],
+                    whole_block_span: span.clone(),
+                },
+                parameters: vec![],
+                span,
+                return_type,
+                type_parameters: vec![],
+                return_type_span: name.span().clone(),
+                visibility,
+                is_contract_call: false,
+                purity: Purity::Pure,
+            },
+            warnings,
+            errors,
+        )
+    }
+
     /// Given a typed function declaration with type parameters, make a copy of it and update the
     /// type ids which refer to generic types to be fresh copies, maintaining their referential
     /// relationship. This is used so when this function is resolved, the types don't clobber the
@@ -471,11 +569,13 @@ fn test_function_selector_behavior() {
                 name: Ident::new_no_span("foo"),
                 r#type: crate::type_engine::insert_type(TypeInfo::Str(5)),
                 type_span: Span::dummy(),
+                is_reference: false,
             },
             TypedFunctionParameter {
                 name: Ident::new_no_span("baz"),
                 r#type: insert_type(TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)),
                 type_span: Span::dummy(),
+                is_reference: false,
             },
         ],
         span: Span::dummy(),
@@ -11,6 +11,8 @@ pub struct TypedFunctionParameter {
     pub(crate) name: Ident,
     pub(crate) r#type: TypeId,
     pub(crate) type_span: Span,
+    /// Whether the parameter is passed by mutable reference rather than by value.
+    pub(crate) is_reference: bool,
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -18,7 +20,9 @@ pub struct TypedFunctionParameter {
 // https://doc.rust-lang.org/std/collections/struct.HashMap.html
 impl PartialEq for TypedFunctionParameter {
     fn eq(&self, other: &Self) -> bool {
-        self.name == other.name && look_up_type_id(self.r#type) == look_up_type_id(other.r#type)
+        self.name == other.name
+            && look_up_type_id(self.r#type) == look_up_type_id(other.r#type)
+            && self.is_reference == other.is_reference
     }
 }
 
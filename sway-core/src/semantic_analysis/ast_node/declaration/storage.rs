@@ -1,5 +1,6 @@
 use crate::semantic_analysis::{
-    TypeCheckedStorageAccess, TypeCheckedStorageAccessDescriptor, TypedStructField,
+    namespace, TypeCheckedStorageAccess, TypeCheckedStorageAccessDescriptor, TypedExpression,
+    TypedStructField,
 };
 use crate::type_engine::look_up_type_id;
 use crate::{
@@ -10,6 +11,7 @@ use crate::{
 use sway_types::{state::StateIndex, Span};
 
 use derivative::Derivative;
+use fuel_tx::StorageSlot;
 
 #[derive(Clone, Debug, Derivative)]
 #[derivative(PartialEq, Eq)]
@@ -114,6 +116,19 @@ impl TypedStorageDeclaration {
         )
     }
 
+    /// Lay out the values of the fields which have an initializer, giving the storage slots to be
+    /// set when the contract is deployed.  The initializers may refer to the constants in
+    /// `module_ns`.
+    pub fn get_initialized_storage_slots(
+        &self,
+        module_ns: &namespace::Module,
+    ) -> CompileResult<Vec<StorageSlot>> {
+        match crate::optimize::compile_storage_slots(&self.fields, module_ns) {
+            Ok(slots) => ok(slots, vec![], vec![]),
+            Err(e) => err(vec![], vec![e]),
+        }
+    }
+
     pub fn span(&self) -> Span {
         self.span.clone()
     }
@@ -126,6 +141,7 @@ impl TypedStorageDeclaration {
                      ref name,
                      ref r#type,
                      ref span,
+                     ..
                  }| TypedStructField {
                     name: name.clone(),
                     r#type: *r#type,
@@ -140,6 +156,7 @@ impl TypedStorageDeclaration {
 pub struct TypedStorageField {
     pub(crate) name: Ident,
     pub(crate) r#type: TypeId,
+    pub(crate) initializer: Option<TypedExpression>,
     pub(crate) span: Span,
 }
 
@@ -148,12 +165,65 @@ pub struct TypedStorageField {
 // https://doc.rust-lang.org/std/collections/struct.HashMap.html
 impl PartialEq for TypedStorageField {
     fn eq(&self, other: &Self) -> bool {
-        self.name == other.name && look_up_type_id(self.r#type) == look_up_type_id(other.r#type)
+        self.name == other.name
+            && look_up_type_id(self.r#type) == look_up_type_id(other.r#type)
+            && self.initializer == other.initializer
     }
 }
 
 impl TypedStorageField {
-    pub fn new(name: Ident, r#type: TypeId, span: Span) -> Self {
-        TypedStorageField { name, r#type, span }
+    pub fn new(
+        name: Ident,
+        r#type: TypeId,
+        initializer: Option<TypedExpression>,
+        span: Span,
+    ) -> Self {
+        TypedStorageField {
+            name,
+            r#type,
+            initializer,
+            span,
+        }
+    }
+
+    /// Storage fields are spread across slots according to their type, which works for any
+    /// combination of primitives, strings, arrays, tuples, structs and enums.  Anything else has
+    /// no representation in storage and is rejected here.
+    pub(crate) fn check_type_is_storable(&self) -> CompileResult<()> {
+        fn find_unstorable_type(type_id: TypeId) -> Option<TypeInfo> {
+            match look_up_type_id(type_id) {
+                TypeInfo::UnsignedInteger(_)
+                | TypeInfo::SignedInteger(_)
+                | TypeInfo::Numeric
+                | TypeInfo::Boolean
+                | TypeInfo::Byte
+                | TypeInfo::B256
+                | TypeInfo::Str(_)
+                | TypeInfo::ErrorRecovery => None,
+                TypeInfo::Struct { fields, .. } => fields
+                    .iter()
+                    .find_map(|field| find_unstorable_type(field.r#type)),
+                TypeInfo::Enum { variant_types, .. } => variant_types
+                    .iter()
+                    .find_map(|variant| find_unstorable_type(variant.r#type)),
+                TypeInfo::Tuple(fields) => fields
+                    .iter()
+                    .find_map(|field| find_unstorable_type(field.type_id)),
+                TypeInfo::Array(elem_type, _) => find_unstorable_type(elem_type),
+                ty => Some(ty),
+            }
+        }
+
+        match find_unstorable_type(self.r#type) {
+            None => ok((), vec![], vec![]),
+            Some(ty) => err(
+                vec![],
+                vec![CompileError::StorageFieldTypeNotSupported {
+                    field_name: self.name.clone(),
+                    ty: ty.friendly_type_str(),
+                    span: self.name.span().clone(),
+                }],
+            ),
+        }
     }
 }
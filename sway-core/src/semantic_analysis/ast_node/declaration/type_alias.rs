@@ -0,0 +1,128 @@
+use crate::{
+    error::*,
+    parse_tree::*,
+    semantic_analysis::{ast_node::copy_types::TypeMapping, namespace::Namespace},
+    type_engine::*,
+    Ident,
+};
+use sway_types::Span;
+
+/// A type-checked `type Name<T> = ...;` declaration.
+///
+/// The aliased type is kept unresolved, so that it can be resolved afresh, in the module the
+/// alias is declared in, each time the alias is instantiated with type arguments.
+#[derive(Clone, Debug, Eq)]
+pub struct TypedTypeAliasDeclaration {
+    pub(crate) name: Ident,
+    pub(crate) type_parameters: Vec<TypeParameter>,
+    pub(crate) ty: TypeInfo,
+    pub(crate) visibility: Visibility,
+    pub(crate) span: Span,
+}
+
+impl PartialEq for TypedTypeAliasDeclaration {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.type_parameters == other.type_parameters
+            && self.ty == other.ty
+            && self.visibility == other.visibility
+    }
+}
+
+impl TypedTypeAliasDeclaration {
+    pub(crate) fn type_check(
+        decl: TypeAliasDeclaration,
+        namespace: &mut Namespace,
+        self_type: TypeId,
+    ) -> CompileResult<Self> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let TypeAliasDeclaration {
+            name,
+            type_parameters,
+            ty,
+            ty_span,
+            visibility,
+            span,
+        } = decl;
+
+        // Resolve the aliased type once, with the type parameters in scope, so that any errors
+        // are reported at the declaration rather than at each use.
+        let mut alias_namespace = namespace.clone();
+        for type_parameter in type_parameters.iter() {
+            alias_namespace.insert_symbol(type_parameter.name_ident.clone(), type_parameter.into());
+        }
+        check!(
+            alias_namespace.resolve_type_with_self(ty.clone(), self_type, ty_span, true),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        let decl = TypedTypeAliasDeclaration {
+            name,
+            type_parameters,
+            ty,
+            visibility,
+            span,
+        };
+        ok(decl, warnings, errors)
+    }
+
+    /// Substitute `type_arguments` for the type parameters of the alias, returning the aliased
+    /// type ready to be resolved in the alias's module. `name` is the alias as written at the use
+    /// site, for error reporting.
+    pub(crate) fn instantiate(
+        &self,
+        name: &Ident,
+        type_arguments: &[TypeArgument],
+        enforce_type_args: bool,
+    ) -> CompileResult<TypeInfo> {
+        let warnings = vec![];
+        let mut errors = vec![];
+        let type_ids = if type_arguments.is_empty() && !self.type_parameters.is_empty() {
+            if enforce_type_args {
+                errors.push(CompileError::NeedsTypeArguments {
+                    name: name.clone(),
+                    span: name.span().clone(),
+                });
+                return err(warnings, errors);
+            }
+            // Leave the arguments to be inferred, as for a struct or enum without type
+            // arguments.
+            self.type_parameters
+                .iter()
+                .map(|_| insert_type(TypeInfo::Unknown))
+                .collect::<Vec<_>>()
+        } else if self.type_parameters.is_empty() && !type_arguments.is_empty() {
+            errors.push(CompileError::DoesNotTakeTypeArguments {
+                name: name.clone(),
+                span: name.span().clone(),
+            });
+            return err(warnings, errors);
+        } else if type_arguments.len() != self.type_parameters.len() {
+            errors.push(CompileError::IncorrectNumberOfTypeArguments {
+                given: type_arguments.len(),
+                expected: self.type_parameters.len(),
+                span: name.span().clone(),
+            });
+            return err(warnings, errors);
+        } else {
+            type_arguments
+                .iter()
+                .map(|type_argument| type_argument.type_id)
+                .collect::<Vec<_>>()
+        };
+        let type_mapping = self
+            .type_parameters
+            .iter()
+            .cloned()
+            .zip(type_ids)
+            .collect::<TypeMapping>();
+        ok(
+            self.ty.substitute_type_parameters(&type_mapping),
+            warnings,
+            errors,
+        )
+    }
+}
@@ -8,6 +8,8 @@ use crate::type_engine::{look_up_type_id, TypeId};
 
 /// Given an enum declaration and the instantiation expression/type arguments, construct a valid
 /// [TypedExpression].
+///
+/// The `enum_module_path` is the absolute path of the module in which the enum is declared.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn instantiate_enum(
     enum_module_path: &[Ident],
@@ -40,6 +42,13 @@ pub(crate) fn instantiate_enum(
         );
     }
 
+    let type_parameters = enum_decl.type_parameters.clone();
+    let generic_variant_types = enum_decl
+        .variants
+        .iter()
+        .map(|variant| variant.r#type)
+        .collect::<Vec<_>>();
+
     // if this is a generic enum, i.e. it has some type
     // parameters, monomorphize it before unifying the
     // types
@@ -58,7 +67,7 @@ pub(crate) fn instantiate_enum(
         }
         (false, false) => {
             let module = check!(
-                namespace.check_submodule_mut(enum_module_path),
+                namespace.root.check_submodule_mut(enum_module_path),
                 return err(warnings, errors),
                 warnings,
                 errors,
@@ -133,6 +142,24 @@ pub(crate) fn instantiate_enum(
             // we now know that the instantiator type matches the declared type, via the above tpe
             // check
 
+            // which also means that the trait constraints of any type parameter it was the type
+            // of can be checked
+            let type_mapping = instantiated_type_parameters(
+                &type_parameters,
+                &generic_variant_types,
+                &new_decl
+                    .variants
+                    .iter()
+                    .map(|variant| variant.r#type)
+                    .collect::<Vec<_>>(),
+            );
+            check!(
+                check_trait_constraints(namespace, &type_mapping, &typed_expr.span),
+                (),
+                warnings,
+                errors
+            );
+
             ok(
                 TypedExpression {
                     return_type: new_decl.create_type_id(),
@@ -64,9 +64,10 @@ pub(crate) fn instantiate_function_application(
     let TypedFunctionDeclaration {
         parameters,
         return_type,
-        body,
+        mut body,
         span,
         purity,
+        type_parameters,
         ..
     } = typed_function_decl;
 
@@ -133,7 +134,13 @@ pub(crate) fn instantiate_function_application(
                 opts,
             };
             let exp = check!(
-                TypedExpression::type_check(args),
+                TypedExpression::type_check_call_argument(args),
+                error_recovery_expr(arg.span()),
+                warnings,
+                errors
+            );
+            let exp = check!(
+                exp.check_mut_ref_argument(param, false, namespace),
                 error_recovery_expr(arg.span()),
                 warnings,
                 errors
@@ -142,12 +149,30 @@ pub(crate) fn instantiate_function_application(
         })
         .collect();
 
+    // now that the arguments have been unified with the parameters, the type parameters are
+    // known and any trait constraints on them can be checked and resolved
+    if !type_parameters.is_empty() {
+        let type_mapping = type_parameters
+            .iter()
+            .map(|type_parameter| (type_parameter.clone(), type_parameter.type_id))
+            .collect::<TypeMapping>();
+        check!(
+            check_trait_constraints(namespace, &type_mapping, &name.span()),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        errors.append(&mut body.resolve_trait_constraint_methods(namespace, self_type));
+    }
+
     let expression = TypedExpressionVariant::FunctionApplication {
         arguments: typed_call_arguments,
         contract_call_params: HashMap::new(),
         name,
         function_body: body,
         selector: None, // regular functions cannot be in a contract call; only methods
+        self_state_idx: None,
+        trait_constraint_method: None,
     };
 
     ok(
@@ -86,7 +86,9 @@ impl TypedExpression {
             | Literal(_)
             | StorageAccess { .. }
             | TypeProperty { .. }
+            | GetStorageKey
             | VariableExpression { .. }
+            | MutRef { .. }
             | FunctionParameter
             | TupleElemAccess { .. } => false,
             ArrayIndex { prefix, index } => {
@@ -133,6 +135,15 @@ impl TypedExpression {
                             .unwrap_or(false))
             }
             AbiName(_) => false,
+            // The body only runs when the closure is called.
+            Closure { .. } => false,
+            ClosureCall { closure, arguments } => {
+                arguments.iter().any(|x| x.deterministically_aborts())
+                    || match &closure.expression {
+                        Closure { body, .. } => body.deterministically_aborts(),
+                        _ => false,
+                    }
+            }
         }
     }
     /// recurse into `self` and get any return statements -- used to validate that all returns
@@ -187,10 +198,15 @@ impl TypedExpression {
             | TypedExpressionVariant::AbiCast { .. }
             | TypedExpressionVariant::SizeOfValue { .. }
             | TypedExpressionVariant::TypeProperty { .. }
+            | TypedExpressionVariant::GetStorageKey
             | TypedExpressionVariant::StructExpression { .. }
             | TypedExpressionVariant::VariableExpression { .. }
             | TypedExpressionVariant::AbiName(_)
             | TypedExpressionVariant::StorageAccess { .. }
+            // a `return` within a closure returns from the closure, not the enclosing function
+            | TypedExpressionVariant::Closure { .. }
+            | TypedExpressionVariant::ClosureCall { .. }
+            | TypedExpressionVariant::MutRef { .. }
             | TypedExpressionVariant::FunctionApplication { .. } => vec![],
         }
     }
@@ -398,10 +414,12 @@ impl TypedExpression {
             ),
             Expression::AbiCast {
                 abi_name,
+                type_arguments,
                 address,
                 span,
             } => Self::type_check_abi_cast(
                 abi_name,
+                type_arguments,
                 address,
                 span,
                 namespace,
@@ -516,6 +534,42 @@ impl TypedExpression {
                 },
                 span,
             ),
+            Expression::BuiltinGetStorageKey { span } => ok(
+                TypedExpression {
+                    expression: TypedExpressionVariant::GetStorageKey,
+                    return_type: insert_type(TypeInfo::B256),
+                    is_constant: IsConstant::No,
+                    span,
+                },
+                vec![],
+                vec![],
+            ),
+            Expression::Closure {
+                parameters,
+                return_type,
+                return_type_span,
+                body,
+                span,
+            } => Self::type_check_closure(
+                TypeCheckArguments {
+                    checkee: (parameters, return_type, return_type_span, *body),
+                    namespace,
+                    return_type_annotation: insert_type(TypeInfo::Unknown),
+                    help_text: Default::default(),
+                    self_type,
+                    build_config,
+                    dead_code_graph,
+                    mode: Mode::NonAbi,
+                    opts,
+                },
+                span,
+            ),
+            // `&mut` is only meaningful as a call argument, which is handled by
+            // `type_check_call_argument`.
+            Expression::MutRef { span, .. } => {
+                let errors = vec![CompileError::MutRefNotAllowedHere { span: span.clone() }];
+                ok(error_recovery_expr(span), vec![], errors)
+            }
         };
         let mut typed_expression = match res.value {
             Some(r) => r,
@@ -552,11 +606,13 @@ impl TypedExpression {
         );
 
         // Literals of type Numeric can now be resolved if typed_expression.return_type is
-        // an UnsignedInteger or a Numeric
+        // an UnsignedInteger, a SignedInteger or a Numeric
         if let TypedExpressionVariant::Literal(lit) = typed_expression.clone().expression {
             if let Literal::Numeric(_) = lit {
                 match look_up_type_id(typed_expression.return_type) {
-                    TypeInfo::UnsignedInteger(_) | TypeInfo::Numeric => {
+                    TypeInfo::UnsignedInteger(_)
+                    | TypeInfo::SignedInteger(_)
+                    | TypeInfo::Numeric => {
                         typed_expression = check!(
                             Self::resolve_numeric_literal(
                                 lit,
@@ -584,6 +640,10 @@ impl TypedExpression {
             Literal::U16(_) => TypeInfo::UnsignedInteger(IntegerBits::Sixteen),
             Literal::U32(_) => TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo),
             Literal::U64(_) => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+            Literal::I8(_) => TypeInfo::SignedInteger(IntegerBits::Eight),
+            Literal::I16(_) => TypeInfo::SignedInteger(IntegerBits::Sixteen),
+            Literal::I32(_) => TypeInfo::SignedInteger(IntegerBits::ThirtyTwo),
+            Literal::I64(_) => TypeInfo::SignedInteger(IntegerBits::SixtyFour),
             Literal::Boolean(_) => TypeInfo::Boolean,
             Literal::Byte(_) => TypeInfo::Byte,
             Literal::B256(_) => TypeInfo::B256,
@@ -623,6 +683,16 @@ impl TypedExpression {
                 expression: TypedExpressionVariant::VariableExpression { name: name.clone() },
                 span,
             },
+            Some(TypedDeclaration::ConstGenericForFunctionScope { name }) => TypedExpression {
+                return_type: insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+                is_constant: IsConstant::No,
+                expression: TypedExpressionVariant::TypeProperty {
+                    property: BuiltinProperty::ConstGenericValue,
+                    type_id: insert_type(TypeInfo::UnknownGeneric { name: name.clone() }),
+                    span: span.clone(),
+                },
+                span,
+            },
             Some(TypedDeclaration::AbiDeclaration(decl)) => TypedExpression {
                 return_type: decl.as_type(),
                 is_constant: IsConstant::Yes,
@@ -648,10 +718,127 @@ impl TypedExpression {
         ok(exp, vec![], errors)
     }
 
+    /// Type checks an argument of a function or method call. Unlike any other expression, an
+    /// argument may be a `&mut` borrow of a variable; whether it is allowed for the particular
+    /// parameter is checked by `check_mut_ref_argument` once the callee is known.
+    pub(crate) fn type_check_call_argument(
+        arguments: TypeCheckArguments<'_, Expression>,
+    ) -> CompileResult<TypedExpression> {
+        let (expr, span) = match arguments.checkee {
+            Expression::MutRef { expr, span } => (*expr, span),
+            checkee => {
+                return Self::type_check(TypeCheckArguments {
+                    checkee,
+                    ..arguments
+                })
+            }
+        };
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let name = match &expr {
+            Expression::VariableExpression { name, .. } => name.clone(),
+            _ => {
+                errors.push(CompileError::MutRefNotAVariable { span: span.clone() });
+                return ok(error_recovery_expr(span), warnings, errors);
+            }
+        };
+        let TypeCheckArguments {
+            namespace,
+            return_type_annotation,
+            help_text,
+            self_type,
+            build_config,
+            dead_code_graph,
+            mode,
+            opts,
+            ..
+        } = arguments;
+        let variable = check!(
+            Self::type_check(TypeCheckArguments {
+                checkee: expr,
+                namespace,
+                return_type_annotation,
+                help_text,
+                self_type,
+                build_config,
+                dead_code_graph,
+                mode,
+                opts,
+            }),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        let borrowed = check!(
+            Self::borrow_variable(name, variable.return_type, span, namespace),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        ok(borrowed, warnings, errors)
+    }
+
+    /// Checks that a call argument is passed by `&mut` exactly when its parameter is a `&mut`
+    /// reference. A variable used as the receiver of a `&mut self` method is borrowed implicitly.
+    pub(crate) fn check_mut_ref_argument(
+        self,
+        param: &TypedFunctionParameter,
+        is_receiver: bool,
+        namespace: &Namespace,
+    ) -> CompileResult<TypedExpression> {
+        match (&self.expression, param.is_reference) {
+            (TypedExpressionVariant::MutRef { .. }, true) => ok(self, vec![], vec![]),
+            (TypedExpressionVariant::MutRef { .. }, false) => {
+                let errors = vec![CompileError::MutRefNotAllowedHere {
+                    span: self.span.clone(),
+                }];
+                ok(error_recovery_expr(self.span), vec![], errors)
+            }
+            (TypedExpressionVariant::VariableExpression { name }, true) if is_receiver => {
+                Self::borrow_variable(name.clone(), self.return_type, self.span, namespace)
+            }
+            (_, true) => {
+                let errors = vec![CompileError::ExpectedMutRefArgument {
+                    param: param.name.clone(),
+                    span: self.span.clone(),
+                }];
+                ok(error_recovery_expr(self.span), vec![], errors)
+            }
+            (_, false) => ok(self, vec![], vec![]),
+        }
+    }
+
+    fn borrow_variable(
+        name: Ident,
+        return_type: TypeId,
+        span: Span,
+        namespace: &Namespace,
+    ) -> CompileResult<TypedExpression> {
+        match namespace.resolve_symbol(&name).value {
+            Some(TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                is_mutable: VariableMutability::Mutable,
+                ..
+            })) => ok(
+                TypedExpression {
+                    expression: TypedExpressionVariant::MutRef { name },
+                    return_type,
+                    is_constant: IsConstant::No,
+                    span,
+                },
+                vec![],
+                vec![],
+            ),
+            _ => {
+                let errors = vec![CompileError::MutRefOfImmutable { name }];
+                ok(error_recovery_expr(span), vec![], errors)
+            }
+        }
+    }
+
     #[allow(clippy::type_complexity)]
     fn type_check_function_application(
         arguments: TypeCheckArguments<'_, (CallPath, Vec<Expression>, Vec<TypeArgument>)>,
-        _span: Span,
+        span: Span,
     ) -> CompileResult<TypedExpression> {
         let mut warnings = vec![];
         let mut errors = vec![];
@@ -664,6 +851,33 @@ impl TypedExpression {
             opts,
             ..
         } = arguments;
+
+        // a call through a variable holding a closure, e.g. `f(x)` where `f: fn(u64) -> u64`
+        if name.prefixes.is_empty() && type_arguments.is_empty() {
+            if let Some(TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                body,
+                ..
+            })) = namespace.resolve_symbol(&name.suffix).value.cloned()
+            {
+                if let TypeInfo::Function { .. } = look_up_type_id(body.return_type) {
+                    return Self::type_check_closure_call(
+                        TypeCheckArguments {
+                            checkee: (name.suffix, arguments),
+                            namespace,
+                            return_type_annotation: body.return_type,
+                            help_text: Default::default(),
+                            self_type,
+                            build_config,
+                            dead_code_graph,
+                            mode: Mode::NonAbi,
+                            opts,
+                        },
+                        span,
+                    );
+                }
+            }
+        }
+
         let unknown_decl = check!(
             namespace.resolve_call_path(&name).cloned(),
             return err(warnings, errors),
@@ -689,6 +903,260 @@ impl TypedExpression {
         )
     }
 
+    #[allow(clippy::type_complexity)]
+    fn type_check_closure(
+        arguments: TypeCheckArguments<'_, (Vec<FunctionParameter>, TypeInfo, Span, Expression)>,
+        span: Span,
+    ) -> CompileResult<TypedExpression> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let TypeCheckArguments {
+            checkee: (parameters, return_type, return_type_span, body),
+            namespace,
+            self_type,
+            build_config,
+            dead_code_graph,
+            mut opts,
+            ..
+        } = arguments;
+        // A `break` or `continue` in this closure cannot refer to a loop outside of it.
+        opts.in_loop = false;
+
+        // Closures do not capture their environment, so the local variables of the enclosing
+        // function are removed from the closure's namespace.
+        let mut closure_namespace = namespace.clone();
+        let captured_names = namespace
+            .symbols()
+            .iter()
+            .filter_map(|(name, decl)| match decl {
+                TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                    const_decl_origin: false,
+                    ..
+                }) => Some(name.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for name in captured_names.iter() {
+            closure_namespace.symbols.remove(name);
+        }
+
+        let mut typed_parameters = vec![];
+        for FunctionParameter {
+            name,
+            type_id,
+            type_span,
+            ..
+        } in parameters
+        {
+            let r#type = check!(
+                closure_namespace.resolve_type_with_self(
+                    look_up_type_id(type_id),
+                    self_type,
+                    type_span.clone(),
+                    true
+                ),
+                insert_type(TypeInfo::ErrorRecovery),
+                warnings,
+                errors,
+            );
+            closure_namespace.insert_symbol(
+                name.clone(),
+                TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                    name: name.clone(),
+                    body: TypedExpression {
+                        expression: TypedExpressionVariant::FunctionParameter,
+                        return_type: r#type,
+                        is_constant: IsConstant::No,
+                        span: name.span().clone(),
+                    },
+                    is_mutable: VariableMutability::Immutable,
+                    const_decl_origin: false,
+                    type_ascription: r#type,
+                }),
+            );
+            typed_parameters.push(TypedFunctionParameter {
+                name,
+                r#type,
+                type_span,
+                is_reference: false,
+            });
+        }
+
+        let return_type = check!(
+            closure_namespace.resolve_type_with_self(
+                return_type,
+                self_type,
+                return_type_span,
+                true
+            ),
+            insert_type(TypeInfo::ErrorRecovery),
+            warnings,
+            errors,
+        );
+
+        let typed_body = check!(
+            TypedExpression::type_check(TypeCheckArguments {
+                checkee: body.clone(),
+                namespace: &mut closure_namespace,
+                return_type_annotation: return_type,
+                help_text: "Closure body's return type does not match up with its return type \
+                    annotation.",
+                self_type,
+                build_config,
+                dead_code_graph,
+                mode: Mode::NonAbi,
+                opts,
+            }),
+            error_recovery_expr(body.span()),
+            warnings,
+            errors
+        );
+
+        // any variable left unresolved which belongs to the enclosing function was captured
+        errors = errors
+            .into_iter()
+            .map(|error| match error {
+                CompileError::UnknownVariable { var_name }
+                    if captured_names.contains(&var_name) =>
+                {
+                    CompileError::ClosureCapturesVariable {
+                        span: var_name.span().clone(),
+                        name: var_name,
+                    }
+                }
+                error => error,
+            })
+            .collect();
+
+        // handle the return statement(s)
+        for stmt in typed_body.gather_return_statements() {
+            let (mut new_warnings, new_errors) = unify_with_self(
+                stmt.expr.return_type,
+                return_type,
+                self_type,
+                &stmt.expr.span,
+                "Return statement must return the closure's return type.",
+            );
+            warnings.append(&mut new_warnings);
+            errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
+        }
+
+        let closure_type = insert_type(TypeInfo::Function {
+            parameters: typed_parameters
+                .iter()
+                .map(|param| TypeArgument {
+                    type_id: param.r#type,
+                    span: param.type_span.clone(),
+                })
+                .collect(),
+            return_type,
+        });
+        ok(
+            TypedExpression {
+                expression: TypedExpressionVariant::Closure {
+                    parameters: typed_parameters,
+                    body: Box::new(typed_body),
+                },
+                return_type: closure_type,
+                is_constant: IsConstant::No,
+                span,
+            },
+            warnings,
+            errors,
+        )
+    }
+
+    fn type_check_closure_call(
+        arguments: TypeCheckArguments<'_, (Ident, Vec<Expression>)>,
+        span: Span,
+    ) -> CompileResult<TypedExpression> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let TypeCheckArguments {
+            checkee: (name, arguments),
+            namespace,
+            return_type_annotation: closure_type,
+            self_type,
+            build_config,
+            dead_code_graph,
+            opts,
+            ..
+        } = arguments;
+        let (parameters, return_type) = match look_up_type_id(closure_type) {
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => (parameters, return_type),
+            _ => unreachable!("closure calls are only made through values of function type"),
+        };
+
+        if arguments.len() != parameters.len() {
+            let arguments_span = arguments
+                .iter()
+                .fold(name.span().clone(), |acc, arg| Span::join(acc, arg.span()));
+            if arguments.len() > parameters.len() {
+                errors.push(CompileError::TooManyArgumentsForFunction {
+                    span: arguments_span,
+                    method_name: name.clone(),
+                    expected: parameters.len(),
+                    received: arguments.len(),
+                });
+            } else {
+                errors.push(CompileError::TooFewArgumentsForFunction {
+                    span: arguments_span,
+                    method_name: name.clone(),
+                    expected: parameters.len(),
+                    received: arguments.len(),
+                });
+            }
+        }
+
+        let typed_arguments = arguments
+            .into_iter()
+            .zip(parameters.iter())
+            .map(|(arg, param)| {
+                check!(
+                    TypedExpression::type_check(TypeCheckArguments {
+                        checkee: arg.clone(),
+                        namespace,
+                        return_type_annotation: param.type_id,
+                        help_text: "The argument that has been provided to this closure does not \
+                            match the type of the closure's parameter.",
+                        self_type,
+                        build_config,
+                        dead_code_graph,
+                        mode: Mode::NonAbi,
+                        opts,
+                    }),
+                    error_recovery_expr(arg.span()),
+                    warnings,
+                    errors
+                )
+            })
+            .collect();
+
+        let closure = check!(
+            Self::type_check_variable_expression(name.clone(), name.span().clone(), namespace),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        ok(
+            TypedExpression {
+                expression: TypedExpressionVariant::ClosureCall {
+                    closure: Box::new(closure),
+                    arguments: typed_arguments,
+                },
+                return_type,
+                is_constant: IsConstant::No,
+                span,
+            },
+            warnings,
+            errors,
+        )
+    }
+
     fn type_check_lazy_operator(
         arguments: TypeCheckArguments<'_, (LazyOp, Expression, Expression)>,
         span: Span,
@@ -1297,6 +1765,14 @@ impl TypedExpression {
             warnings,
             errors
         );
+        check!(
+            namespace
+                .root()
+                .check_module_privacy(&module_path, namespace.mod_path()),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
         let unknown_decl = check!(
             namespace
                 .root()
@@ -1313,6 +1789,12 @@ impl TypedExpression {
             errors
         )
         .clone();
+        let type_parameters = struct_decl.type_parameters.clone();
+        let generic_field_types = struct_decl
+            .fields
+            .iter()
+            .map(|field| field.r#type)
+            .collect::<Vec<_>>();
 
         // if this is a generic struct, i.e. it has some type
         // parameters, monomorphize it before unifying the
@@ -1352,7 +1834,7 @@ impl TypedExpression {
                 // perform the monomorphization
                 check!(
                     struct_decl.monomorphize(
-                        &mut namespace[&call_path.prefixes],
+                        &mut namespace.root[&module_path],
                         &type_arguments,
                         Some(self_type)
                     ),
@@ -1422,6 +1904,24 @@ impl TypedExpression {
                 });
             }
         }
+
+        // the type parameters are now known, so their trait constraints can be checked
+        let type_mapping = instantiated_type_parameters(
+            &type_parameters,
+            &generic_field_types,
+            &new_decl
+                .fields
+                .iter()
+                .map(|field| field.r#type)
+                .collect::<Vec<_>>(),
+        );
+        check!(
+            check_trait_constraints(namespace, &type_mapping, &span),
+            (),
+            warnings,
+            errors
+        );
+
         let expression = TypedExpressionVariant::StructExpression {
             struct_name: new_decl.name.clone(),
             fields: typed_fields_buf,
@@ -1703,8 +2203,10 @@ impl TypedExpression {
 
         // First, check if this could be a module. We check first so that we can check for
         // ambiguity in the following enum check.
+        let abs_mod_path: Vec<_> = namespace.find_module_path(&call_path.prefixes);
         let is_module = namespace
-            .check_submodule(&call_path.prefixes)
+            .root()
+            .check_submodule(&abs_mod_path)
             .ok(&mut probe_warnings, &mut probe_errors)
             .is_some();
 
@@ -1712,7 +2214,8 @@ impl TypedExpression {
         let (enum_name, enum_mod_path) = call_path.prefixes.split_last().expect("empty call path");
         let abs_enum_mod_path: Vec<_> = namespace.find_module_path(enum_mod_path);
         let exp = if let Some(enum_decl) = namespace
-            .check_submodule_mut(enum_mod_path)
+            .root()
+            .check_submodule(&abs_enum_mod_path)
             .ok(&mut warnings, &mut errors)
             .map(|_| ())
             .and_then(|_| {
@@ -1722,15 +2225,30 @@ impl TypedExpression {
                     .value
             })
             .and_then(|decl| decl.as_enum().cloned())
-        {
+            // An enum without a variant of this name may have an associated constant of it.
+            .filter(|enum_decl| {
+                enum_decl
+                    .variants
+                    .iter()
+                    .any(|variant| variant.name.as_str() == call_path.suffix.as_str())
+                    || associated_const_type_name_opt(&call_path, namespace).is_none()
+            }) {
             // Check for ambiguity between this enum name and a module name.
             if is_module {
                 errors.push(CompileError::AmbiguousPath { span });
                 return err(warnings, errors);
             }
+            check!(
+                namespace
+                    .root()
+                    .check_module_privacy(&abs_enum_mod_path, namespace.mod_path()),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
             check!(
                 instantiate_enum(
-                    enum_mod_path,
+                    &abs_enum_mod_path,
                     enum_decl,
                     call_path.suffix,
                     args,
@@ -1747,11 +2265,7 @@ impl TypedExpression {
             )
 
         // Otherwise, our prefix should point to some module ending with an enum or function.
-        } else if namespace
-            .check_submodule_mut(&call_path.prefixes)
-            .ok(&mut probe_warnings, &mut probe_errors)
-            .is_some()
-        {
+        } else if is_module {
             let decl = check!(
                 namespace.resolve_call_path(&call_path).cloned(),
                 return err(warnings, errors),
@@ -1762,7 +2276,7 @@ impl TypedExpression {
                 TypedDeclaration::EnumDeclaration(enum_decl) => {
                     check!(
                         instantiate_enum(
-                            &call_path.prefixes,
+                            &abs_mod_path,
                             enum_decl,
                             call_path.suffix,
                             args,
@@ -1807,6 +2321,35 @@ impl TypedExpression {
                 }
             }
 
+        // Otherwise, the prefix may be a type with an associated constant, e.g. `T::DECIMALS`.
+        } else if let Some(type_name) = associated_const_type_name_opt(&call_path, namespace) {
+            let type_name_span = call_path.prefixes[0].span().clone();
+            check!(
+                type_check_method_application(
+                    MethodName::FromType {
+                        call_path: CallPath {
+                            prefixes: vec![],
+                            suffix: call_path.suffix,
+                            is_absolute: false,
+                        },
+                        type_name: Some(type_name),
+                        type_name_span: Some(type_name_span),
+                    },
+                    vec![],
+                    args,
+                    type_arguments,
+                    span,
+                    namespace,
+                    self_type,
+                    build_config,
+                    dead_code_graph,
+                    opts,
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            )
+
         // If prefix is neither a module or enum, there's nothing to be found.
         } else {
             errors.push(CompileError::SymbolNotFound {
@@ -1821,6 +2364,7 @@ impl TypedExpression {
     #[allow(clippy::too_many_arguments)]
     fn type_check_abi_cast(
         abi_name: CallPath,
+        type_arguments: Vec<TypeArgument>,
         address: Box<Expression>,
         span: Span,
         namespace: &mut Namespace,
@@ -1922,19 +2466,36 @@ impl TypedExpression {
             address: Some(Box::new(address_expr.clone())),
         });
 
+        // substitute the type arguments of the cast, e.g. `u64` in `abi(Vault<u64>, address)`,
+        // for the type parameters of the abi
+        let abi_type_mapping = check!(
+            trait_type_mapping(&abi_name, &abi.type_parameters, &type_arguments, namespace),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
         let mut functions_buf = abi
             .interface_surface
             .iter()
-            .map(|x| x.to_dummy_func(Mode::ImplAbiFn))
+            .map(|x| {
+                let mut x = x.clone();
+                x.copy_types(&abi_type_mapping);
+                x.to_dummy_func(Mode::ImplAbiFn)
+            })
             .collect::<Vec<_>>();
+        // A temporary namespace with the type parameters of the abi in scope.
+        let mut abi_namespace = namespace.clone();
+        for (type_parameter, _) in abi_type_mapping.iter() {
+            abi_namespace.insert_symbol(type_parameter.name_ident.clone(), type_parameter.into());
+        }
         // calls of ABI methods do not result in any codegen of the ABI method block
         // they instead just use the CALL opcode and the return type
         let mut type_checked_fn_buf = Vec::with_capacity(abi.methods.len());
         for method in &abi.methods {
-            type_checked_fn_buf.push(check!(
+            let mut method = check!(
                 TypedFunctionDeclaration::type_check(TypeCheckArguments {
                     checkee: method.clone(),
-                    namespace,
+                    namespace: &mut abi_namespace,
                     return_type_annotation: insert_type(TypeInfo::Unknown),
                     help_text: Default::default(),
                     self_type: insert_type(TypeInfo::Contract),
@@ -1946,7 +2507,9 @@ impl TypedExpression {
                 return err(warnings, errors),
                 warnings,
                 errors
-            ));
+            );
+            method.copy_types(&abi_type_mapping);
+            type_checked_fn_buf.push(method);
         }
 
         functions_buf.append(&mut type_checked_fn_buf);
@@ -2087,7 +2650,9 @@ impl TypedExpression {
         );
 
         // If the return type is a static array then create a TypedArrayIndex.
-        if let TypeInfo::Array(elem_type_id, _) = look_up_type_id(prefix_te.return_type) {
+        if let TypeInfo::Array(elem_type_id, _) | TypeInfo::GenericArray(elem_type_id, _) =
+            look_up_type_id(prefix_te.return_type)
+        {
             let index_te = check!(
                 TypedExpression::type_check(TypeCheckArguments {
                     checkee: index,
@@ -2310,6 +2875,57 @@ impl TypedExpression {
                 };
                 ok(exp, warnings, errors)
             }
+            DelayedResolutionVariant::ArrayLength(DelayedArrayLengthResolution { exp }) => {
+                let args = TypeCheckArguments {
+                    checkee: *exp,
+                    namespace,
+                    return_type_annotation: insert_type(TypeInfo::Unknown),
+                    help_text: Default::default(),
+                    self_type,
+                    build_config,
+                    dead_code_graph,
+                    mode: Mode::NonAbi,
+                    opts,
+                };
+                let parent = check!(
+                    TypedExpression::type_check(args),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                let (expression, is_constant) = match look_up_type_id(parent.return_type) {
+                    TypeInfo::Array(_, count) => (
+                        TypedExpressionVariant::Literal(Literal::U64(count as u64)),
+                        IsConstant::Yes,
+                    ),
+                    // The length of an array of generic length is only known once the enclosing
+                    // function has been monomorphized.
+                    TypeInfo::GenericArray(_, length) => (
+                        TypedExpressionVariant::TypeProperty {
+                            property: BuiltinProperty::ConstGenericValue,
+                            type_id: length,
+                            span: span.clone(),
+                        },
+                        IsConstant::No,
+                    ),
+                    TypeInfo::ErrorRecovery => return err(warnings, errors),
+                    other => {
+                        errors.push(CompileError::ForLoopNotIterable {
+                            ty: other.friendly_type_str(),
+                            span: parent.span,
+                        });
+                        let exp = error_recovery_expr(span);
+                        return ok(exp, warnings, errors);
+                    }
+                };
+                let exp = TypedExpression {
+                    expression,
+                    return_type: insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+                    is_constant,
+                    span,
+                };
+                ok(exp, warnings, errors)
+            }
         }
     }
 
@@ -2352,7 +2968,7 @@ impl TypedExpression {
             ..
         } = arguments;
         let type_id = check!(
-            namespace.resolve_type_with_self(type_name, self_type, type_span, true),
+            namespace.resolve_type_with_self(type_name, self_type, type_span.clone(), true),
             insert_type(TypeInfo::ErrorRecovery),
             warnings,
             errors,
@@ -2362,6 +2978,23 @@ impl TypedExpression {
                 insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour))
             }
             BuiltinProperty::IsRefType => insert_type(TypeInfo::Boolean),
+            BuiltinProperty::StrLen => {
+                // A generic type can only be checked once it has been monomorphized, which
+                // happens when the expression is lowered.
+                match look_up_type_id(type_id) {
+                    TypeInfo::Str(_)
+                    | TypeInfo::UnknownGeneric { .. }
+                    | TypeInfo::ErrorRecovery => {}
+                    other => errors.push(CompileError::StrLenNotStr {
+                        ty: other.friendly_type_str(),
+                        span: type_span,
+                    }),
+                }
+                insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour))
+            }
+            BuiltinProperty::ConstGenericValue => {
+                unreachable!("const generic values are not written as builtins")
+            }
         };
         let exp = TypedExpression {
             expression: TypedExpressionVariant::TypeProperty {
@@ -2428,6 +3061,48 @@ impl TypedExpression {
                         new_type,
                     ),
                 },
+                TypeInfo::SignedInteger(n) => match n {
+                    IntegerBits::Eight => (
+                        num.to_string().parse().map(Literal::I8).map_err(|e| {
+                            Literal::handle_parse_int_error(
+                                e,
+                                TypeInfo::SignedInteger(IntegerBits::Eight),
+                                span.clone(),
+                            )
+                        }),
+                        new_type,
+                    ),
+                    IntegerBits::Sixteen => (
+                        num.to_string().parse().map(Literal::I16).map_err(|e| {
+                            Literal::handle_parse_int_error(
+                                e,
+                                TypeInfo::SignedInteger(IntegerBits::Sixteen),
+                                span.clone(),
+                            )
+                        }),
+                        new_type,
+                    ),
+                    IntegerBits::ThirtyTwo => (
+                        num.to_string().parse().map(Literal::I32).map_err(|e| {
+                            Literal::handle_parse_int_error(
+                                e,
+                                TypeInfo::SignedInteger(IntegerBits::ThirtyTwo),
+                                span.clone(),
+                            )
+                        }),
+                        new_type,
+                    ),
+                    IntegerBits::SixtyFour => (
+                        num.to_string().parse().map(Literal::I64).map_err(|e| {
+                            Literal::handle_parse_int_error(
+                                e,
+                                TypeInfo::SignedInteger(IntegerBits::SixtyFour),
+                                span.clone(),
+                            )
+                        }),
+                        new_type,
+                    ),
+                },
                 TypeInfo::Numeric => (
                     num.to_string().parse().map(Literal::U64).map_err(|e| {
                         Literal::handle_parse_int_error(
@@ -2470,6 +3145,24 @@ impl TypedExpression {
     }
 }
 
+/// A path whose only prefix names a type parameter, a struct or an enum, e.g. `T::DECIMALS`, may
+/// refer to an associated constant of that type.  Built-in types such as `u64::MAX` are handled
+/// when converting the parse tree.
+fn associated_const_type_name_opt(call_path: &CallPath, namespace: &Namespace) -> Option<TypeInfo> {
+    match &call_path.prefixes[..] {
+        [type_name] if !call_path.is_absolute => match namespace.resolve_symbol(type_name).value {
+            Some(TypedDeclaration::GenericTypeForFunctionScope { .. })
+            | Some(TypedDeclaration::StructDeclaration(_))
+            | Some(TypedDeclaration::EnumDeclaration(_)) => Some(TypeInfo::Custom {
+                name: type_name.clone(),
+                type_arguments: vec![],
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn check_scrutinee_type(
     scrutinee: &Scrutinee,
     namespace: &mut Namespace,
@@ -23,9 +23,19 @@ pub(crate) fn type_check_method_application(
     let mut errors = vec![];
     let mut args_buf = VecDeque::new();
     let mut contract_call_params_map = HashMap::new();
+    let numeric_literals = arguments
+        .iter()
+        .map(|arg| match arg {
+            Expression::Literal {
+                value: Literal::Numeric(_),
+                ..
+            } => Some(arg.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
     for arg in arguments {
         args_buf.push_back(check!(
-            TypedExpression::type_check(TypeCheckArguments {
+            TypedExpression::type_check_call_argument(TypeCheckArguments {
                 checkee: arg,
                 namespace,
                 return_type_annotation: insert_type(TypeInfo::Unknown),
@@ -42,7 +52,7 @@ pub(crate) fn type_check_method_application(
         ));
     }
 
-    let method = match method_name {
+    let (method, receiver_type, method_path) = match method_name {
         MethodName::FromType {
             ref call_path,
             ref type_name,
@@ -96,12 +106,19 @@ pub(crate) fn type_check_method_application(
             } else {
                 namespace.find_module_path(call_path.full_path())
             };
-            check!(
+            // This is only used to tell whether the method is called on a type parameter, so
+            // it's fine to fall back to the unresolved type.
+            let receiver_type = namespace
+                .resolve_type_with_self(ty.clone(), self_type, type_name_span, false)
+                .value
+                .unwrap_or_else(|| insert_type(ty.clone()));
+            let method = check!(
                 namespace.find_method_for_type(insert_type(ty), &abs_path, self_type, &args_buf),
                 return err(warnings, errors),
                 warnings,
                 errors
-            )
+            );
+            (method, receiver_type, abs_path)
         }
         MethodName::FromModule { ref method_name } => {
             let ty = args_buf
@@ -109,14 +126,29 @@ pub(crate) fn type_check_method_application(
                 .map(|x| x.return_type)
                 .unwrap_or_else(|| insert_type(TypeInfo::Unknown));
             let abs_path: Vec<_> = namespace.find_module_path(Some(method_name));
-            check!(
+            let method = check!(
                 namespace.find_method_for_type(ty, &abs_path, self_type, &args_buf),
                 return err(warnings, errors),
                 warnings,
                 errors
-            )
+            );
+            (method, ty, abs_path)
         }
     };
+
+    // A method called on a type parameter must come from one of its trait constraints, and only
+    // has a placeholder body until the type parameter is monomorphized.  Any such calls within
+    // the method itself can be resolved now, if their types are known.
+    let trait_constraint_method = match look_up_type_id(receiver_type) {
+        TypeInfo::UnknownGeneric { .. } => Some(TraitConstraintMethod {
+            receiver_type,
+            method_path,
+        }),
+        _ => None,
+    };
+    let mut function_body = method.body.clone();
+    errors.append(&mut function_body.resolve_trait_constraint_methods(namespace, self_type));
+
     let contract_caller = if method.is_contract_call {
         args_buf.pop_front()
     } else {
@@ -199,6 +231,56 @@ pub(crate) fn type_check_method_application(
         }
     }
 
+    // An unsuffixed integer literal argument is typed as a `u64` before the method is known, which
+    // can be cast to an unsigned parameter type but not to a signed one, so check it again against
+    // a signed parameter type now that it is known.
+    for ((arg, literal), param) in args_buf
+        .iter_mut()
+        .zip(numeric_literals)
+        .zip(method.parameters.iter())
+    {
+        if let (Some(literal), TypeInfo::SignedInteger(_)) =
+            (literal, look_up_type_id(param.r#type))
+        {
+            *arg = check!(
+                TypedExpression::type_check(TypeCheckArguments {
+                    checkee: literal,
+                    namespace,
+                    return_type_annotation: param.r#type,
+                    help_text: Default::default(),
+                    self_type,
+                    build_config,
+                    dead_code_graph,
+                    mode: Mode::NonAbi,
+                    opts,
+                }),
+                error_recovery_expr(arg.span.clone()),
+                warnings,
+                errors
+            );
+        }
+    }
+
+    // check that `&mut` is used exactly for the `&mut` parameters, implicitly borrowing the
+    // receiver of a `&mut self` method called as `a.b(c)`
+    let has_receiver = matches!(method_name, MethodName::FromModule { .. });
+    let args_buf = args_buf
+        .into_iter()
+        .enumerate()
+        .map(|(i, arg)| match method.parameters.get(i) {
+            Some(param) => {
+                let span = arg.span.clone();
+                check!(
+                    arg.check_mut_ref_argument(param, has_receiver && i == 0, namespace),
+                    error_recovery_expr(span),
+                    warnings,
+                    errors
+                )
+            }
+            None => arg,
+        })
+        .collect::<VecDeque<_>>();
+
     // type check all of the arguments against the parameters in the method declaration
     for (arg, param) in args_buf.iter().zip(method.parameters.iter()) {
         // if the return type cannot be cast into the annotation type then it is a type error
@@ -219,6 +301,16 @@ pub(crate) fn type_check_method_application(
         }
         // The annotation may result in a cast, which is handled in the type engine.
     }
+
+    // A method called directly on a `storage` field, e.g. `storage.balances.insert(..)`, gets to
+    // know which slot `self` lives in, so that `__get_storage_key()` can refer to it.
+    let self_state_idx = match args_buf.get(0).map(|arg| &arg.expression) {
+        Some(TypedExpressionVariant::StorageAccess(access)) if access.fields.len() == 1 => {
+            Some(access.ix)
+        }
+        _ => None,
+    };
+
     let exp = match method_name {
         // something like a.b(c)
         MethodName::FromModule { method_name } => {
@@ -285,8 +377,10 @@ pub(crate) fn type_check_method_application(
                 },
                 contract_call_params: contract_call_params_map,
                 arguments: args_and_names,
-                function_body: method.body.clone(),
+                function_body,
                 selector,
+                self_state_idx,
+                trait_constraint_method,
             };
 
             TypedExpression {
@@ -358,8 +452,10 @@ pub(crate) fn type_check_method_application(
                 name: call_path.clone(),
                 contract_call_params: contract_call_params_map,
                 arguments: args_and_names,
-                function_body: method.body.clone(),
+                function_body,
                 selector,
+                self_state_idx,
+                trait_constraint_method,
             };
 
             TypedExpression {
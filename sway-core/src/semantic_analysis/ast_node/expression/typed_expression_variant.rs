@@ -12,6 +12,15 @@ pub(crate) struct ContractCallMetadata {
     pub(crate) contract_address: Box<TypedExpression>,
 }
 
+/// A call to a method of a trait which constrains a type parameter, e.g. `a.gt(b)` where
+/// `T: Ord`.  The method is looked up again via `method_path` once the type of the receiver is
+/// known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TraitConstraintMethod {
+    pub(crate) receiver_type: TypeId,
+    pub(crate) method_path: Vec<Ident>,
+}
+
 #[derive(Clone, Debug, Derivative)]
 #[derivative(Eq)]
 pub(crate) enum TypedExpressionVariant {
@@ -26,6 +35,12 @@ pub(crate) enum TypedExpressionVariant {
         /// there is no selector.
         #[derivative(Eq(bound = ""))]
         selector: Option<ContractCallMetadata>,
+        /// If this is a method called on a `storage` field then this is the index of that field,
+        /// used to resolve `__get_storage_key()` within the method body.
+        self_state_idx: Option<StateIndex>,
+        /// If this is `Some(method)` then `function_body` is only a placeholder until the type
+        /// parameter the method is called on has been monomorphized.
+        trait_constraint_method: Option<TraitConstraintMethod>,
     },
     LazyOperator {
         #[derivative(Eq(bound = ""))]
@@ -113,8 +128,25 @@ pub(crate) enum TypedExpressionVariant {
     SizeOfValue {
         expr: Box<TypedExpression>,
     },
+    /// The storage slot hash of the `storage` field whose method is being called.
+    GetStorageKey,
     /// a zero-sized type-system-only compile-time thing that is used for constructing ABI casts.
     AbiName(AbiName),
+    /// A non-capturing closure. Closures are never materialized at runtime: IR generation
+    /// compiles `body` afresh at every call made through the closure.
+    Closure {
+        parameters: Vec<TypedFunctionParameter>,
+        body: Box<TypedExpression>,
+    },
+    /// A call through a value of function type, e.g. `f(x)` where `f: fn(u64) -> u64`.
+    ClosureCall {
+        closure: Box<TypedExpression>,
+        arguments: Vec<TypedExpression>,
+    },
+    /// A `&mut name` argument, passing the mutable binding `name` to a `&mut` parameter.
+    MutRef {
+        name: Ident,
+    },
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -343,6 +375,28 @@ impl PartialEq for TypedExpressionVariant {
             (Self::SizeOfValue { expr: l_expr }, Self::SizeOfValue { expr: r_expr }) => {
                 l_expr == r_expr
             }
+            (Self::GetStorageKey, Self::GetStorageKey) => true,
+            (
+                Self::Closure {
+                    parameters: l_parameters,
+                    body: l_body,
+                },
+                Self::Closure {
+                    parameters: r_parameters,
+                    body: r_body,
+                },
+            ) => l_parameters == r_parameters && (**l_body) == (**r_body),
+            (
+                Self::ClosureCall {
+                    closure: l_closure,
+                    arguments: l_arguments,
+                },
+                Self::ClosureCall {
+                    closure: r_closure,
+                    arguments: r_arguments,
+                },
+            ) => (**l_closure) == (**r_closure) && l_arguments == r_arguments,
+            (Self::MutRef { name: l_name }, Self::MutRef { name: r_name }) => l_name == r_name,
             _ => false,
         }
     }
@@ -356,12 +410,25 @@ impl CopyTypes for TypedExpressionVariant {
             FunctionApplication {
                 arguments,
                 function_body,
+                trait_constraint_method,
                 ..
             } => {
                 arguments
                     .iter_mut()
                     .for_each(|(_ident, expr)| expr.copy_types(type_mapping));
                 function_body.copy_types(type_mapping);
+                if let Some(TraitConstraintMethod {
+                    ref mut receiver_type,
+                    ..
+                }) = trait_constraint_method
+                {
+                    *receiver_type = match look_up_type_id(*receiver_type)
+                        .matches_type_parameter(type_mapping)
+                    {
+                        Some(matching_id) => insert_type(TypeInfo::Ref(matching_id)),
+                        None => insert_type(look_up_type_id_raw(*receiver_type)),
+                    };
+                }
             }
             LazyOperator { lhs, rhs, .. } => {
                 (*lhs).copy_types(type_mapping);
@@ -457,6 +524,7 @@ impl CopyTypes for TypedExpressionVariant {
                 };
             }
             SizeOfValue { expr } => expr.copy_types(type_mapping),
+            GetStorageKey => (),
             IfLet {
                 ref mut variant,
                 ref mut enum_type,
@@ -472,6 +540,19 @@ impl CopyTypes for TypedExpressionVariant {
                 variant.copy_types(type_mapping);
             }
             AbiName(_) => (),
+            Closure { parameters, body } => {
+                parameters
+                    .iter_mut()
+                    .for_each(|x| x.copy_types(type_mapping));
+                body.copy_types(type_mapping);
+            }
+            ClosureCall { closure, arguments } => {
+                closure.copy_types(type_mapping);
+                arguments
+                    .iter_mut()
+                    .for_each(|x| x.copy_types(type_mapping));
+            }
+            MutRef { .. } => (),
         }
     }
 }
@@ -542,6 +623,10 @@ impl TypedExpressionVariant {
                     Literal::U16(content) => content.to_string(),
                     Literal::U32(content) => content.to_string(),
                     Literal::U64(content) => content.to_string(),
+                    Literal::I8(content) => content.to_string(),
+                    Literal::I16(content) => content.to_string(),
+                    Literal::I32(content) => content.to_string(),
+                    Literal::I64(content) => content.to_string(),
                     Literal::Numeric(content) => content.to_string(),
                     Literal::String(content) => content.as_str().to_string(),
                     Literal::Boolean(content) => content.to_string(),
@@ -637,12 +722,20 @@ impl TypedExpressionVariant {
                 match property {
                     BuiltinProperty::SizeOfType => format!("size_of({type_str:?})"),
                     BuiltinProperty::IsRefType => format!("is_ref_type({type_str:?})"),
+                    BuiltinProperty::StrLen => format!("str_len({type_str:?})"),
+                    BuiltinProperty::ConstGenericValue => type_str,
                 }
             }
             TypedExpressionVariant::SizeOfValue { expr } => {
                 format!("size_of_val({:?})", expr.pretty_print())
             }
+            TypedExpressionVariant::GetStorageKey => "get_storage_key".into(),
             TypedExpressionVariant::AbiName(n) => format!("ABI name {}", n),
+            TypedExpressionVariant::Closure { .. } => "closure".into(),
+            TypedExpressionVariant::ClosureCall { closure, .. } => {
+                format!("{} call", closure.pretty_print())
+            }
+            TypedExpressionVariant::MutRef { name } => format!("&mut {}", name.as_str()),
         }
     }
 }
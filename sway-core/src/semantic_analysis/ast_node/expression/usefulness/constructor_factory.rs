@@ -181,6 +181,118 @@ impl ConstructorFactory {
                     errors
                 )
             }
+            Pattern::I8(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I8(range) => ranges.push(range),
+                        _ => {
+                            errors.push(CompileError::Internal("type mismatch", span.clone()));
+                            return err(warnings, errors);
+                        }
+                    }
+                }
+                let unincluded: PatStack = check!(
+                    Range::find_exclusionary_ranges(ranges, Range::i8(), span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+                .into_iter()
+                .map(Pattern::I8)
+                .collect::<Vec<_>>()
+                .into();
+                check!(
+                    Pattern::from_pat_stack(unincluded, span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+            }
+            Pattern::I16(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I16(range) => ranges.push(range),
+                        _ => {
+                            errors.push(CompileError::Internal("type mismatch", span.clone()));
+                            return err(warnings, errors);
+                        }
+                    }
+                }
+                let unincluded: PatStack = check!(
+                    Range::find_exclusionary_ranges(ranges, Range::i16(), span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+                .into_iter()
+                .map(Pattern::I16)
+                .collect::<Vec<_>>()
+                .into();
+                check!(
+                    Pattern::from_pat_stack(unincluded, span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+            }
+            Pattern::I32(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I32(range) => ranges.push(range),
+                        _ => {
+                            errors.push(CompileError::Internal("type mismatch", span.clone()));
+                            return err(warnings, errors);
+                        }
+                    }
+                }
+                let unincluded: PatStack = check!(
+                    Range::find_exclusionary_ranges(ranges, Range::i32(), span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+                .into_iter()
+                .map(Pattern::I32)
+                .collect::<Vec<_>>()
+                .into();
+                check!(
+                    Pattern::from_pat_stack(unincluded, span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+            }
+            Pattern::I64(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I64(range) => ranges.push(range),
+                        _ => {
+                            errors.push(CompileError::Internal("type mismatch", span.clone()));
+                            return err(warnings, errors);
+                        }
+                    }
+                }
+                let unincluded: PatStack = check!(
+                    Range::find_exclusionary_ranges(ranges, Range::i64(), span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+                .into_iter()
+                .map(Pattern::I64)
+                .collect::<Vec<_>>()
+                .into();
+                check!(
+                    Pattern::from_pat_stack(unincluded, span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+            }
             Pattern::Numeric(range) => {
                 let mut ranges = vec![range];
                 for pat in rest.into_iter() {
@@ -274,6 +274,58 @@ impl PatStack {
                 }
                 Range::do_ranges_equal_range(ranges, Range::u64(), span)
             }
+            Pattern::I8(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I8(range) => ranges.push(range),
+                        _ => {
+                            errors.push(CompileError::Internal("type mismatch", span.clone()));
+                            return err(warnings, errors);
+                        }
+                    }
+                }
+                Range::do_ranges_equal_range(ranges, Range::i8(), span)
+            }
+            Pattern::I16(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I16(range) => ranges.push(range),
+                        _ => {
+                            errors.push(CompileError::Internal("type mismatch", span.clone()));
+                            return err(warnings, errors);
+                        }
+                    }
+                }
+                Range::do_ranges_equal_range(ranges, Range::i16(), span)
+            }
+            Pattern::I32(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I32(range) => ranges.push(range),
+                        _ => {
+                            errors.push(CompileError::Internal("type mismatch", span.clone()));
+                            return err(warnings, errors);
+                        }
+                    }
+                }
+                Range::do_ranges_equal_range(ranges, Range::i32(), span)
+            }
+            Pattern::I64(range) => {
+                let mut ranges = vec![range];
+                for pat in rest.into_iter() {
+                    match pat {
+                        Pattern::I64(range) => ranges.push(range),
+                        _ => {
+                            errors.push(CompileError::Internal("type mismatch", span.clone()));
+                            return err(warnings, errors);
+                        }
+                    }
+                }
+                Range::do_ranges_equal_range(ranges, Range::i64(), span)
+            }
             Pattern::Byte(range) => {
                 let mut ranges = vec![range];
                 for pat in rest.into_iter() {
@@ -101,6 +101,10 @@ pub(crate) enum Pattern {
     U16(Range<u16>),
     U32(Range<u32>),
     U64(Range<u64>),
+    I8(Range<i8>),
+    I16(Range<i16>),
+    I32(Range<i32>),
+    I64(Range<i64>),
     B256([u8; 32]),
     Boolean(bool),
     Byte(Range<u8>),
@@ -131,6 +135,10 @@ impl Pattern {
                 Literal::U16(x) => ok(Pattern::U16(Range::from_single(x)), warnings, errors),
                 Literal::U32(x) => ok(Pattern::U32(Range::from_single(x)), warnings, errors),
                 Literal::U64(x) => ok(Pattern::U64(Range::from_single(x)), warnings, errors),
+                Literal::I8(x) => ok(Pattern::I8(Range::from_single(x)), warnings, errors),
+                Literal::I16(x) => ok(Pattern::I16(Range::from_single(x)), warnings, errors),
+                Literal::I32(x) => ok(Pattern::I32(Range::from_single(x)), warnings, errors),
+                Literal::I64(x) => ok(Pattern::I64(Range::from_single(x)), warnings, errors),
                 Literal::B256(x) => ok(Pattern::B256(x), warnings, errors),
                 Literal::Boolean(b) => ok(Pattern::Boolean(b), warnings, errors),
                 Literal::Byte(x) => ok(Pattern::Byte(Range::from_single(x)), warnings, errors),
@@ -357,6 +365,46 @@ impl Pattern {
                 }
                 Pattern::U64(range.clone())
             }
+            Pattern::I8(range) => {
+                if !args.is_empty() {
+                    errors.push(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    ));
+                    return err(warnings, errors);
+                }
+                Pattern::I8(range.clone())
+            }
+            Pattern::I16(range) => {
+                if !args.is_empty() {
+                    errors.push(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    ));
+                    return err(warnings, errors);
+                }
+                Pattern::I16(range.clone())
+            }
+            Pattern::I32(range) => {
+                if !args.is_empty() {
+                    errors.push(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    ));
+                    return err(warnings, errors);
+                }
+                Pattern::I32(range.clone())
+            }
+            Pattern::I64(range) => {
+                if !args.is_empty() {
+                    errors.push(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    ));
+                    return err(warnings, errors);
+                }
+                Pattern::I64(range.clone())
+            }
             Pattern::B256(b) => {
                 if !args.is_empty() {
                     errors.push(CompileError::Internal(
@@ -486,6 +534,10 @@ impl Pattern {
             Pattern::U16(_) => 0,
             Pattern::U32(_) => 0,
             Pattern::U64(_) => 0,
+            Pattern::I8(_) => 0,
+            Pattern::I16(_) => 0,
+            Pattern::I32(_) => 0,
+            Pattern::I64(_) => 0,
             Pattern::B256(_) => 0,
             Pattern::Boolean(_) => 0,
             Pattern::Byte(_) => 0,
@@ -533,6 +585,10 @@ impl Pattern {
             (Pattern::U16(a), Pattern::U16(b)) => a == b,
             (Pattern::U32(a), Pattern::U32(b)) => a == b,
             (Pattern::U64(a), Pattern::U64(b)) => a == b,
+            (Pattern::I8(a), Pattern::I8(b)) => a == b,
+            (Pattern::I16(a), Pattern::I16(b)) => a == b,
+            (Pattern::I32(a), Pattern::I32(b)) => a == b,
+            (Pattern::I64(a), Pattern::I64(b)) => a == b,
             (Pattern::B256(x), Pattern::B256(y)) => x == y,
             (Pattern::Boolean(x), Pattern::Boolean(y)) => x == y,
             (Pattern::Byte(a), Pattern::Byte(b)) => a == b,
@@ -620,6 +676,10 @@ impl fmt::Display for Pattern {
             Pattern::U16(range) => format!("{}", range),
             Pattern::U32(range) => format!("{}", range),
             Pattern::U64(range) => format!("{}", range),
+            Pattern::I8(range) => format!("{}", range),
+            Pattern::I16(range) => format!("{}", range),
+            Pattern::I32(range) => format!("{}", range),
+            Pattern::I64(range) => format!("{}", range),
             Pattern::Numeric(range) => format!("{}", range),
             Pattern::B256(n) => format!("{:#?}", n),
             Pattern::Boolean(b) => format!("{}", b),
@@ -1,7 +1,4 @@
-use std::{
-    fmt::{self, Debug, Write},
-    ops::Sub,
-};
+use std::fmt::{self, Debug, Write};
 
 use crate::{
     error::{err, ok},
@@ -82,6 +79,70 @@ impl MyMath<u64> for u64 {
     }
 }
 
+impl MyMath<i8> for i8 {
+    fn global_max() -> i8 {
+        std::i8::MAX
+    }
+    fn global_min() -> i8 {
+        std::i8::MIN
+    }
+
+    fn incr(&self) -> i8 {
+        self + 1
+    }
+    fn decr(&self) -> i8 {
+        self - 1
+    }
+}
+
+impl MyMath<i16> for i16 {
+    fn global_max() -> i16 {
+        std::i16::MAX
+    }
+    fn global_min() -> i16 {
+        std::i16::MIN
+    }
+
+    fn incr(&self) -> i16 {
+        self + 1
+    }
+    fn decr(&self) -> i16 {
+        self - 1
+    }
+}
+
+impl MyMath<i32> for i32 {
+    fn global_max() -> i32 {
+        std::i32::MAX
+    }
+    fn global_min() -> i32 {
+        std::i32::MIN
+    }
+
+    fn incr(&self) -> i32 {
+        self + 1
+    }
+    fn decr(&self) -> i32 {
+        self - 1
+    }
+}
+
+impl MyMath<i64> for i64 {
+    fn global_max() -> i64 {
+        std::i64::MAX
+    }
+    fn global_min() -> i64 {
+        std::i64::MIN
+    }
+
+    fn incr(&self) -> i64 {
+        self + 1
+    }
+    fn decr(&self) -> i64 {
+        self - 1
+    }
+}
+
 /// A `Range<T>` is a range of values of type T. Given this range:
 ///
 /// ```ignore
@@ -102,16 +163,7 @@ impl MyMath<u64> for u64 {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) struct Range<T>
 where
-    T: Debug
-        + fmt::Display
-        + Eq
-        + Ord
-        + PartialEq
-        + PartialOrd
-        + Clone
-        + MyMath<T>
-        + Sub<Output = T>
-        + Into<u64>,
+    T: Debug + fmt::Display + Eq + Ord + PartialEq + PartialOrd + Clone + MyMath<T>,
 {
     first: T,
     last: T,
@@ -153,18 +205,45 @@ impl Range<u64> {
     }
 }
 
+impl Range<i8> {
+    pub(crate) fn i8() -> Range<i8> {
+        Range {
+            first: std::i8::MIN,
+            last: std::i8::MAX,
+        }
+    }
+}
+
+impl Range<i16> {
+    pub(crate) fn i16() -> Range<i16> {
+        Range {
+            first: std::i16::MIN,
+            last: std::i16::MAX,
+        }
+    }
+}
+
+impl Range<i32> {
+    pub(crate) fn i32() -> Range<i32> {
+        Range {
+            first: std::i32::MIN,
+            last: std::i32::MAX,
+        }
+    }
+}
+
+impl Range<i64> {
+    pub(crate) fn i64() -> Range<i64> {
+        Range {
+            first: std::i64::MIN,
+            last: std::i64::MAX,
+        }
+    }
+}
+
 impl<T> Range<T>
 where
-    T: Debug
-        + fmt::Display
-        + Eq
-        + Ord
-        + PartialEq
-        + PartialOrd
-        + Clone
-        + MyMath<T>
-        + Sub<Output = T>
-        + Into<u64>,
+    T: Debug + fmt::Display + Eq + Ord + PartialEq + PartialOrd + Clone + MyMath<T>,
 {
     /// Creates a `Range<T>` from a single value of type `T`, where the value is used
     /// both as the lower inclusive contains and the upper inclusive contains.
@@ -528,24 +607,14 @@ where
     /// ```
     fn within_one(&self, other: &Range<T>) -> bool {
         !self.overlaps(other)
-            && (other.first > self.last && (other.first.clone() - self.last.clone()).into() == 1u64
-                || self.first > other.last
-                    && (self.first.clone() - other.last.clone()).into() == 1u64)
+            && (other.first > self.last && other.first == self.last.incr()
+                || self.first > other.last && self.first == other.last.incr())
     }
 }
 
 impl<T> fmt::Display for Range<T>
 where
-    T: Debug
-        + fmt::Display
-        + Eq
-        + Ord
-        + PartialEq
-        + PartialOrd
-        + Clone
-        + MyMath<T>
-        + Sub<Output = T>
-        + Into<u64>,
+    T: Debug + fmt::Display + Eq + Ord + PartialEq + PartialOrd + Clone + MyMath<T>,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut builder = String::new();
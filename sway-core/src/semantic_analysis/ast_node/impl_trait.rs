@@ -4,7 +4,10 @@ use crate::{
     build_config::BuildConfig,
     control_flow_analysis::ControlFlowGraph,
     error::*,
-    parse_tree::{FunctionDeclaration, ImplTrait, Purity},
+    parse_tree::{
+        AssociatedType, ConstantDeclaration, FunctionDeclaration, ImplTrait, Purity, TypeArgument,
+        TypeParameter,
+    },
     semantic_analysis::*,
     type_engine::*,
     CallPath, Ident,
@@ -23,42 +26,91 @@ pub(crate) fn implementation_of_trait(
     let mut warnings = vec![];
     let ImplTrait {
         trait_name,
+        trait_type_arguments,
         type_arguments,
         functions,
+        constants,
+        associated_types,
         type_implementing_for,
         type_implementing_for_span,
         block_span,
         ..
     } = impl_trait;
+
+    // A temporary namespace with the type parameters of the impl, and the methods of the traits
+    // constraining them, in scope.
+    let mut impl_namespace = namespace.clone();
+    for type_argument in type_arguments.iter() {
+        impl_namespace.insert_symbol(type_argument.name_ident.clone(), type_argument.into());
+    }
+    check!(
+        insert_trait_constraints(&mut impl_namespace, &type_arguments),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
     let type_implementing_for = check!(
-        namespace.resolve_type_without_self(&type_implementing_for),
+        impl_namespace.resolve_type_without_self(&type_implementing_for),
         return err(warnings, errors),
         warnings,
         errors
     );
     let type_implementing_for = look_up_type_id(type_implementing_for);
     let type_implementing_for_id = insert_type(type_implementing_for.clone());
-    for type_argument in type_arguments.iter() {
-        if !type_argument.trait_constraints.is_empty() {
-            errors.push(CompileError::WhereClauseNotYetSupported {
-                span: type_argument.name_ident.span().clone(),
-            });
-            break;
-        }
-    }
     match namespace
         .resolve_call_path(&trait_name)
         .ok(&mut warnings, &mut errors)
         .cloned()
     {
         Some(TypedDeclaration::TraitDeclaration(tr)) => {
+            // substitute the type arguments of the impl, e.g. `b256` in
+            // `impl From<b256> for Address`, for the type parameters of the trait
+            let trait_type_mapping = check!(
+                trait_type_mapping(
+                    &trait_name,
+                    &tr.type_parameters,
+                    &trait_type_arguments,
+                    &mut impl_namespace
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            // the associated types are substituted the same way, and refer to the types given by
+            // the impl within it, e.g. `Self::Item` to `u64` for `type Item = u64;`
+            let associated_type_mapping = check!(
+                associated_type_mapping(
+                    &trait_name,
+                    &tr.associated_types,
+                    &associated_types,
+                    &mut impl_namespace,
+                    &block_span
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            for (type_parameter, _) in associated_type_mapping.iter() {
+                impl_namespace
+                    .insert_symbol(type_parameter.name_ident.clone(), type_parameter.into());
+            }
+            let trait_type_mapping = [trait_type_mapping, associated_type_mapping.clone()].concat();
+            let mut interface_surface = tr.interface_surface.clone();
+            interface_surface
+                .iter_mut()
+                .for_each(|x| x.copy_types(&trait_type_mapping));
             let functions_buf = check!(
                 type_check_trait_implementation(
-                    &tr.interface_surface,
+                    &interface_surface,
                     &functions,
+                    &constants,
                     &tr.methods,
+                    &tr.constants,
+                    &trait_type_mapping,
+                    &associated_type_mapping,
                     &trait_name,
-                    namespace,
+                    &mut impl_namespace,
                     type_implementing_for_id,
                     build_config,
                     dead_code_graph,
@@ -99,9 +151,10 @@ pub(crate) fn implementation_of_trait(
         }
         Some(TypedDeclaration::AbiDeclaration(abi)) => {
             // if you are comparing this with the `impl_trait` branch above, note that
-            // there are no type arguments here because we don't support generic types
-            // in contract ABIs yet (or ever?) due to the complexity of communicating
-            // the ABI layout in the descriptor file.
+            // the type arguments of the impl itself are not in scope here because we
+            // don't support generic types in contract ABI implementations yet (or ever?)
+            // due to the complexity of communicating the ABI layout in the descriptor
+            // file. The type parameters of the ABI must be given concrete types.
             if type_implementing_for != TypeInfo::Contract {
                 errors.push(CompileError::ImplAbiForNonContract {
                     span: type_implementing_for_span.clone(),
@@ -109,11 +162,43 @@ pub(crate) fn implementation_of_trait(
                 });
             }
 
+            let abi_type_mapping = check!(
+                trait_type_mapping(
+                    &trait_name,
+                    &abi.type_parameters,
+                    &trait_type_arguments,
+                    namespace
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            // an abi has no associated types, so this only reports those given by the impl
+            check!(
+                associated_type_mapping(
+                    &trait_name,
+                    &[],
+                    &associated_types,
+                    namespace,
+                    &block_span
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            let mut interface_surface = abi.interface_surface.clone();
+            interface_surface
+                .iter_mut()
+                .for_each(|x| x.copy_types(&abi_type_mapping));
             let functions_buf = check!(
                 type_check_trait_implementation(
-                    &abi.interface_surface,
+                    &interface_surface,
                     &functions,
+                    &constants,
                     &abi.methods,
+                    &[],
+                    &abi_type_mapping,
+                    &TypeMapping::new(),
                     &trait_name,
                     namespace,
                     type_implementing_for_id,
@@ -158,6 +243,108 @@ pub(crate) fn implementation_of_trait(
     }
 }
 
+/// Pairs the type parameters of a generic trait or abi with the type arguments it is used with,
+/// e.g. `T` with `b256` in `impl From<b256> for Address`.
+pub(crate) fn trait_type_mapping(
+    trait_name: &CallPath,
+    type_parameters: &[TypeParameter],
+    type_arguments: &[TypeArgument],
+    namespace: &mut Namespace,
+) -> CompileResult<TypeMapping> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    if type_parameters.is_empty() && !type_arguments.is_empty() {
+        errors.push(CompileError::DoesNotTakeTypeArguments {
+            name: trait_name.suffix.clone(),
+            span: trait_name.span(),
+        });
+        return err(warnings, errors);
+    }
+    if !type_parameters.is_empty() && type_arguments.is_empty() {
+        errors.push(CompileError::NeedsTypeArguments {
+            name: trait_name.suffix.clone(),
+            span: trait_name.span(),
+        });
+        return err(warnings, errors);
+    }
+    if type_parameters.len() != type_arguments.len() {
+        errors.push(CompileError::IncorrectNumberOfTypeArguments {
+            given: type_arguments.len(),
+            expected: type_parameters.len(),
+            span: trait_name.span(),
+        });
+        return err(warnings, errors);
+    }
+    let mut type_mapping = vec![];
+    for (type_parameter, type_argument) in type_parameters.iter().zip(type_arguments.iter()) {
+        let type_id = check!(
+            namespace.resolve_type_without_self(&look_up_type_id(type_argument.type_id)),
+            insert_type(TypeInfo::ErrorRecovery),
+            warnings,
+            errors
+        );
+        type_mapping.push((type_parameter.clone(), type_id));
+    }
+    ok(type_mapping, warnings, errors)
+}
+
+/// Pairs the associated types of a trait with the types given to them by an implementation, e.g.
+/// `Item` with `u64` for `type Item = u64;`.
+fn associated_type_mapping(
+    trait_name: &CallPath,
+    trait_associated_types: &[TypeParameter],
+    associated_types: &[AssociatedType],
+    namespace: &mut Namespace,
+    block_span: &Span,
+) -> CompileResult<TypeMapping> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    for associated_type in associated_types.iter() {
+        if !trait_associated_types
+            .iter()
+            .any(|type_parameter| type_parameter.name_ident == associated_type.name)
+        {
+            errors.push(CompileError::AssociatedTypeNotAPartOfTrait {
+                name: associated_type.name.clone(),
+                trait_name: trait_name.suffix.clone(),
+                span: associated_type.name.span().clone(),
+            });
+        }
+    }
+    let mut type_mapping = vec![];
+    let mut missing_types = vec![];
+    for type_parameter in trait_associated_types.iter() {
+        match associated_types
+            .iter()
+            .find(|associated_type| associated_type.name == type_parameter.name_ident)
+        {
+            Some(associated_type) => {
+                let type_id = check!(
+                    namespace.resolve_type_without_self(&look_up_type_id(
+                        associated_type.type_argument.type_id
+                    )),
+                    insert_type(TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                type_mapping.push((type_parameter.clone(), type_id));
+            }
+            None => missing_types.push(type_parameter.name_ident.as_str().to_string()),
+        }
+    }
+    if !missing_types.is_empty() {
+        errors.push(CompileError::MissingAssociatedTypes {
+            missing_types: missing_types.join(", "),
+            span: block_span.clone(),
+        });
+    }
+    if errors.is_empty() {
+        ok(type_mapping, warnings, errors)
+    } else {
+        err(warnings, errors)
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
     ImplAbiFn,
@@ -174,7 +361,11 @@ impl Default for Mode {
 fn type_check_trait_implementation(
     interface_surface: &[TypedTraitFn],
     functions: &[FunctionDeclaration],
+    constants: &[ConstantDeclaration],
     methods: &[FunctionDeclaration],
+    default_constants: &[ConstantDeclaration],
+    trait_type_mapping: &TypeMapping,
+    associated_type_mapping: &TypeMapping,
     trait_name: &CallPath,
     namespace: &mut Namespace,
     _self_type: TypeId,
@@ -197,15 +388,107 @@ fn type_check_trait_implementation(
         .iter()
         .map(|decl| (&decl.name, decl))
         .collect();
+
+    // This name space is temporary! It is used only so that the below methods
+    // can reference functions from the interface
+    let mut impl_trait_namespace = namespace.clone();
+
+    // A trait impl needs access to everything that the trait methods have access to, which is
+    // basically everything in the path where the trait is declared.
+    // First, get the path to where the trait is declared. This is a combination of the path stored
+    // in the symbols map and the path stored in the CallPath.
+    let trait_path = [
+        &trait_name.prefixes[..],
+        impl_trait_namespace.get_canonical_path(&trait_name.suffix),
+    ]
+    .concat();
+    impl_trait_namespace.star_import(&trait_path);
+
+    // The methods of a generic trait refer to its type parameters, which are substituted once the
+    // methods are type checked.
+    for (type_parameter, _) in trait_type_mapping.iter() {
+        impl_trait_namespace
+            .insert_symbol(type_parameter.name_ident.clone(), type_parameter.into());
+    }
+
+    // The associated constants are type checked first, so that the functions of the impl can
+    // refer to them. Those which aren't given a value by the impl take their default value from
+    // the trait, which is type checked like the methods of the trait below.
+    let mut fn_decls = vec![];
+    for const_decl in constants {
+        let mut const_decl = check!(
+            TypedFunctionDeclaration::type_check_associated_const(TypeCheckArguments {
+                checkee: const_decl.clone(),
+                namespace,
+                return_type_annotation: insert_type(TypeInfo::Unknown),
+                help_text: Default::default(),
+                self_type: type_implementing_for,
+                build_config,
+                dead_code_graph,
+                mode,
+                opts,
+            }),
+            continue,
+            warnings,
+            errors
+        );
+        const_decl.copy_types(associated_type_mapping);
+        fn_decls.push(const_decl.replace_self_types(self_type_id));
+    }
+    let mut const_namespace = impl_trait_namespace.clone();
+    const_namespace.insert_trait_implementation(
+        CallPath {
+            prefixes: vec![],
+            suffix: trait_name.suffix.clone(),
+            is_absolute: false,
+        },
+        look_up_type_id(type_implementing_for),
+        fn_decls.clone(),
+    );
+    for const_decl in default_constants.iter().filter(|default| {
+        !constants
+            .iter()
+            .any(|const_decl| const_decl.name == default.name)
+    }) {
+        let mut const_decl = check!(
+            TypedFunctionDeclaration::type_check_associated_const(TypeCheckArguments {
+                checkee: const_decl.clone(),
+                namespace: &mut const_namespace,
+                return_type_annotation: insert_type(TypeInfo::Unknown),
+                help_text: Default::default(),
+                self_type: type_implementing_for,
+                build_config,
+                dead_code_graph,
+                mode,
+                opts,
+            }),
+            continue,
+            warnings,
+            errors
+        );
+        const_decl.copy_types(trait_type_mapping);
+        fn_decls.push(const_decl.replace_self_types(self_type_id));
+    }
+
+    let mut fn_namespace = namespace.clone();
+    fn_namespace.insert_trait_implementation(
+        CallPath {
+            prefixes: vec![],
+            suffix: trait_name.suffix.clone(),
+            is_absolute: false,
+        },
+        look_up_type_id(type_implementing_for),
+        fn_decls.clone(),
+    );
     for fn_decl in functions {
         // replace SelfType with type of implementor
         // i.e. fn add(self, other: u64) -> Self becomes fn
         // add(self: u64, other: u64) -> u64
 
-        let fn_decl = check!(
+        let mut fn_decl = check!(
             TypedFunctionDeclaration::type_check(TypeCheckArguments {
                 checkee: fn_decl.clone(),
-                namespace,
+                namespace: &mut fn_namespace,
                 return_type_annotation: insert_type(TypeInfo::Unknown),
                 help_text: Default::default(),
                 self_type: type_implementing_for,
@@ -218,7 +501,10 @@ fn type_check_trait_implementation(
             warnings,
             errors
         );
-        let fn_decl = fn_decl.replace_self_types(self_type_id);
+        fn_decl.copy_types(associated_type_mapping);
+        fn_decls.push(fn_decl.replace_self_types(self_type_id));
+    }
+    for fn_decl in fn_decls {
         // remove this function from the "checklist"
         let trait_fn = match function_checklist.remove(&fn_decl.name) {
             Some(trait_fn) => trait_fn,
@@ -277,6 +563,23 @@ fn type_check_trait_implementation(
                 });
                 break;
             }
+            if fn_decl_param.is_reference != trait_param.is_reference {
+                let ref_str = |is_reference: bool| if is_reference { "&mut " } else { "" };
+                errors.push(CompileError::MismatchedTypeInTrait {
+                    span: fn_decl_param.type_span.clone(),
+                    given: format!(
+                        "{}{}",
+                        ref_str(fn_decl_param.is_reference),
+                        fn_decl_param_type.friendly_type_str()
+                    ),
+                    expected: format!(
+                        "{}{}",
+                        ref_str(trait_param.is_reference),
+                        trait_param_type.friendly_type_str()
+                    ),
+                });
+                break;
+            }
         }
 
         if fn_decl.purity != *purity {
@@ -318,21 +621,6 @@ fn type_check_trait_implementation(
         functions_buf.push(fn_decl);
     }
 
-    // This name space is temporary! It is used only so that the below methods
-    // can reference functions from the interface
-    let mut impl_trait_namespace = namespace.clone();
-
-    // A trait impl needs access to everything that the trait methods have access to, which is
-    // basically everything in the path where the trait is declared.
-    // First, get the path to where the trait is declared. This is a combination of the path stored
-    // in the symbols map and the path stored in the CallPath.
-    let trait_path = [
-        &trait_name.prefixes[..],
-        impl_trait_namespace.get_canonical_path(&trait_name.suffix),
-    ]
-    .concat();
-    impl_trait_namespace.star_import(&trait_path);
-
     impl_trait_namespace.insert_trait_implementation(
         CallPath {
             prefixes: vec![],
@@ -354,7 +642,7 @@ fn type_check_trait_implementation(
 
         // use a local namespace which has the above interface inserted
         // into it as a trait implementation for this
-        let method = check!(
+        let mut method = check!(
             TypedFunctionDeclaration::type_check(TypeCheckArguments {
                 checkee: method.clone(),
                 namespace: &mut impl_trait_namespace,
@@ -370,6 +658,7 @@ fn type_check_trait_implementation(
             warnings,
             errors
         );
+        method.copy_types(trait_type_mapping);
         let fn_decl = method.replace_self_types(self_type_id);
         functions_buf.push(fn_decl);
     }
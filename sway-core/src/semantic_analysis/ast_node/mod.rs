@@ -2,7 +2,7 @@ use super::ERROR_RECOVERY_DECLARATION;
 
 use crate::{
     build_config::BuildConfig, control_flow_analysis::ControlFlowGraph, error::*, parse_tree::*,
-    semantic_analysis::*, style::*, type_engine::*, AstNode, AstNodeContent, Ident,
+    semantic_analysis::*, style::*, type_engine::*, AstNode, AstNodeContent, Ident, ParseTree,
     ReturnStatement,
 };
 
@@ -26,12 +26,12 @@ pub use declaration::{
 
 pub(crate) use declaration::{
     check_if_name_is_invalid, TypedReassignment, TypedStorageDeclaration, TypedTraitDeclaration,
-    TypedVariableDeclaration, VariableMutability,
+    TypedTypeAliasDeclaration, TypedVariableDeclaration, VariableMutability,
 };
 
 pub mod impl_trait;
-use impl_trait::implementation_of_trait;
 pub(crate) use impl_trait::Mode;
+use impl_trait::{implementation_of_trait, trait_type_mapping};
 
 mod code_block;
 pub(crate) use code_block::TypedCodeBlock;
@@ -48,6 +48,9 @@ pub(crate) use while_loop::TypedWhileLoop;
 mod copy_types;
 pub(crate) use copy_types::*;
 
+mod trait_constraints;
+pub(crate) use trait_constraints::*;
+
 /// whether or not something is constantly evaluatable (if the result is known at compile
 /// time)
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -63,6 +66,8 @@ pub(crate) enum TypedAstNodeContent {
     Expression(TypedExpression),
     ImplicitReturnExpression(TypedExpression),
     WhileLoop(TypedWhileLoop),
+    Break,
+    Continue,
     // a no-op node used for something that just issues a side effect, like an import statement.
     SideEffect,
 }
@@ -86,6 +91,8 @@ impl std::fmt::Display for TypedAstNode {
             Expression(exp) => exp.pretty_print(),
             ImplicitReturnExpression(exp) => format!("return {}", exp.pretty_print()),
             WhileLoop(w_loop) => w_loop.pretty_print(),
+            Break => "break".into(),
+            Continue => "continue".into(),
             SideEffect => "".into(),
         };
         f.write_str(&text)
@@ -110,7 +117,9 @@ impl CopyTypes for TypedAstNode {
                 condition.copy_types(type_mapping);
                 body.copy_types(type_mapping);
             }
-            TypedAstNodeContent::SideEffect => (),
+            TypedAstNodeContent::Break
+            | TypedAstNodeContent::Continue
+            | TypedAstNodeContent::SideEffect => (),
         }
     }
 }
@@ -124,11 +133,24 @@ impl TypedAstNode {
             ReturnStatement(_)
             | Expression(_)
             | WhileLoop(_)
+            | Break
+            | Continue
             | SideEffect
             | ImplicitReturnExpression(_) => false,
         }
     }
 
+    /// Returns `true` if this AST node is the declaration of a generic function, whose types can
+    /// only be known once it has been monomorphized at a call site.
+    pub(crate) fn is_generic_function(&self) -> bool {
+        matches!(
+            &self.content,
+            TypedAstNodeContent::Declaration(TypedDeclaration::FunctionDeclaration(
+                TypedFunctionDeclaration { type_parameters, .. },
+            )) if !type_parameters.is_empty()
+        )
+    }
+
     /// Naive check to see if this node is a function declaration of a function called `main` if
     /// the [TreeType] is Script or Predicate.
     pub(crate) fn is_main_function(&self, tree_type: TreeType) -> bool {
@@ -152,12 +174,12 @@ impl TypedAstNode {
     pub(crate) fn deterministically_aborts(&self) -> bool {
         use TypedAstNodeContent::*;
         match &self.content {
-            ReturnStatement(_) => true,
+            ReturnStatement(_) | Break | Continue => true,
             Declaration(_) => false,
             Expression(exp) | ImplicitReturnExpression(exp) => exp.deterministically_aborts(),
-            WhileLoop(TypedWhileLoop { condition, body }) => {
-                condition.deterministically_aborts() || body.deterministically_aborts()
-            }
+            // The body of a loop may never be entered, and any `break` or `continue` within it
+            // only aborts the current iteration, so only the condition is considered.
+            WhileLoop(TypedWhileLoop { condition, .. }) => condition.deterministically_aborts(),
             SideEffect => false,
         }
     }
@@ -191,7 +213,10 @@ impl TypedAstNode {
                 TypedReassignment { rhs, .. },
             )) => rhs.gather_return_statements(),
             TypedAstNodeContent::Expression(exp) => exp.gather_return_statements(),
-            TypedAstNodeContent::SideEffect | TypedAstNodeContent::Declaration(_) => vec![],
+            TypedAstNodeContent::Break
+            | TypedAstNodeContent::Continue
+            | TypedAstNodeContent::SideEffect
+            | TypedAstNodeContent::Declaration(_) => vec![],
         }
     }
 
@@ -206,7 +231,7 @@ impl TypedAstNode {
             ImplicitReturnExpression(TypedExpression { return_type, .. }) => {
                 crate::type_engine::look_up_type_id(*return_type)
             }
-            WhileLoop(_) | SideEffect => TypeInfo::Tuple(Vec::new()),
+            WhileLoop(_) | Break | Continue | SideEffect => TypeInfo::Tuple(Vec::new()),
         }
     }
 
@@ -283,6 +308,16 @@ impl TypedAstNode {
                     );
                     TypedAstNodeContent::SideEffect
                 }
+                AstNodeContent::ModuleDeclaration(ref a) => {
+                    // Type-check the module's items within a new submodule of the current module.
+                    check!(
+                        type_check_module_declaration(a, namespace, build_config, dead_code_graph),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    TypedAstNodeContent::SideEffect
+                }
                 AstNodeContent::Declaration(a) => {
                     TypedAstNodeContent::Declaration(match a {
                         Declaration::VariableDeclaration(VariableDeclaration {
@@ -376,15 +411,6 @@ impl TypedAstNode {
                             typed_const_decl
                         }
                         Declaration::EnumDeclaration(e) => {
-                            for type_parameter in e.type_parameters.iter() {
-                                if !type_parameter.trait_constraints.is_empty() {
-                                    errors.push(CompileError::WhereClauseNotYetSupported {
-                                        span: type_parameter.name_ident.span().clone(),
-                                    });
-                                    break;
-                                }
-                            }
-
                             is_upper_camel_case(&e.name).ok(&mut warnings, &mut errors);
                             let decl = TypedDeclaration::EnumDeclaration(
                                 e.to_typed_decl(namespace, self_type),
@@ -399,15 +425,6 @@ impl TypedAstNode {
                             decl
                         }
                         Declaration::FunctionDeclaration(fn_decl) => {
-                            for type_parameter in fn_decl.type_parameters.iter() {
-                                if !type_parameter.trait_constraints.is_empty() {
-                                    errors.push(CompileError::WhereClauseNotYetSupported {
-                                        span: type_parameter.name_ident.span().clone(),
-                                    });
-                                    break;
-                                }
-                            }
-
                             let decl = check!(
                                 TypedFunctionDeclaration::type_check(TypeCheckArguments {
                                     checkee: fn_decl.clone(),
@@ -487,20 +504,12 @@ impl TypedAstNode {
 
                         Declaration::ImplSelf(ImplSelf {
                             functions,
+                            constants,
                             type_implementing_for,
                             block_span,
                             type_parameters,
                             ..
                         }) => {
-                            for type_parameter in type_parameters.iter() {
-                                if !type_parameter.trait_constraints.is_empty() {
-                                    errors.push(CompileError::WhereClauseNotYetSupported {
-                                        span: type_parameter.name_ident.span().clone(),
-                                    });
-                                    break;
-                                }
-                            }
-
                             let mut impl_namespace = namespace.clone();
                             for type_parameter in type_parameters.iter() {
                                 impl_namespace.insert_symbol(
@@ -508,6 +517,12 @@ impl TypedAstNode {
                                     type_parameter.into(),
                                 );
                             }
+                            check!(
+                                insert_trait_constraints(&mut impl_namespace, &type_parameters),
+                                (),
+                                warnings,
+                                errors
+                            );
 
                             // Resolve the Self type as it's most likely still 'Custom' and use the
                             // resolved type for self instead.
@@ -518,7 +533,38 @@ impl TypedAstNode {
                                 errors
                             );
                             let type_implementing_for = look_up_type_id(implementing_for_type_id);
+                            let trait_name = CallPath {
+                                prefixes: vec![],
+                                suffix: Ident::new_with_override("r#Self", block_span.clone()),
+                                is_absolute: false,
+                            };
                             let mut functions_buf: Vec<TypedFunctionDeclaration> = vec![];
+                            // type check the associated constants first so that the functions can
+                            // refer to them
+                            for const_decl in constants.into_iter() {
+                                let args = TypeCheckArguments {
+                                    checkee: const_decl,
+                                    namespace: &mut impl_namespace,
+                                    return_type_annotation: insert_type(TypeInfo::Unknown),
+                                    help_text: "",
+                                    self_type: implementing_for_type_id,
+                                    build_config,
+                                    dead_code_graph,
+                                    mode: Mode::NonAbi,
+                                    opts,
+                                };
+                                functions_buf.push(check!(
+                                    TypedFunctionDeclaration::type_check_associated_const(args),
+                                    continue,
+                                    warnings,
+                                    errors
+                                ));
+                            }
+                            impl_namespace.insert_trait_implementation(
+                                trait_name.clone(),
+                                type_implementing_for.clone(),
+                                functions_buf.clone(),
+                            );
                             for mut fn_decl in functions.into_iter() {
                                 // ensure this fn decl's parameters and signature lines up with the
                                 // one in the trait
@@ -556,11 +602,6 @@ impl TypedAstNode {
                                     errors
                                 ));
                             }
-                            let trait_name = CallPath {
-                                prefixes: vec![],
-                                suffix: Ident::new_with_override("r#Self", block_span.clone()),
-                                is_absolute: false,
-                            };
                             namespace.insert_trait_implementation(
                                 trait_name.clone(),
                                 type_implementing_for.clone(),
@@ -574,15 +615,6 @@ impl TypedAstNode {
                             }
                         }
                         Declaration::StructDeclaration(decl) => {
-                            for type_parameter in decl.type_parameters.iter() {
-                                if !type_parameter.trait_constraints.is_empty() {
-                                    errors.push(CompileError::WhereClauseNotYetSupported {
-                                        span: type_parameter.name_ident.span().clone(),
-                                    });
-                                    break;
-                                }
-                            }
-
                             is_upper_camel_case(&decl.name).ok(&mut warnings, &mut errors);
                             // look up any generic or struct types in the namespace
                             // insert type parameters
@@ -616,7 +648,10 @@ impl TypedAstNode {
                                 .collect::<Vec<_>>();
                             let decl = TypedStructDeclaration {
                                 name: decl.name.clone(),
-                                type_parameters: decl.type_parameters.clone(),
+                                type_parameters: canonicalize_trait_constraints(
+                                    namespace,
+                                    &decl.type_parameters,
+                                ),
                                 fields,
                                 visibility: decl.visibility,
                                 span: decl.span,
@@ -637,27 +672,47 @@ impl TypedAstNode {
                         }
                         Declaration::AbiDeclaration(AbiDeclaration {
                             name,
+                            type_parameters,
                             interface_surface,
                             methods,
                             span,
                         }) => {
+                            // A temporary namespace with the type parameters of the abi in scope.
+                            let mut abi_namespace = namespace.clone();
+                            for type_parameter in type_parameters.iter() {
+                                abi_namespace.insert_symbol(
+                                    type_parameter.name_ident.clone(),
+                                    type_parameter.into(),
+                                );
+                            }
                             // type check the interface surface and methods
                             // We don't want the user to waste resources by contract calling
                             // themselves, and we don't want to do more work in the compiler,
                             // so we don't support the case of calling a contract's own interface
                             // from itself. This is by design.
                             let interface_surface = check!(
-                                type_check_interface_surface(interface_surface, namespace),
+                                type_check_interface_surface(interface_surface, &mut abi_namespace),
                                 return err(warnings, errors),
                                 warnings,
                                 errors
                             );
+                            // ABI methods are called across contract boundaries, where there is
+                            // no caller memory to borrow from.
+                            for param in interface_surface
+                                .iter()
+                                .flat_map(|method| method.parameters.iter())
+                                .filter(|param| param.is_reference)
+                            {
+                                errors.push(CompileError::AbiMethodMutRefParameter {
+                                    span: param.type_span.clone(),
+                                });
+                            }
                             // type check these for errors but don't actually use them yet -- the real
                             // ones will be type checked with proper symbols when the ABI is implemented
                             let _methods = check!(
                                 type_check_trait_methods(
                                     methods.clone(),
-                                    namespace,
+                                    &mut abi_namespace,
                                     self_type,
                                     build_config,
                                     dead_code_graph,
@@ -668,6 +723,7 @@ impl TypedAstNode {
                             );
 
                             let decl = TypedDeclaration::AbiDeclaration(TypedAbiDeclaration {
+                                type_parameters,
                                 interface_surface,
                                 methods,
                                 name: name.clone(),
@@ -678,14 +734,41 @@ impl TypedAstNode {
                         }
                         Declaration::StorageDeclaration(StorageDeclaration { span, fields }) => {
                             let mut fields_buf = Vec::with_capacity(fields.len());
-                            for StorageField { name, r#type } in fields {
+                            for StorageField {
+                                name,
+                                r#type,
+                                initializer,
+                            } in fields
+                            {
                                 let r#type = check!(
                                     namespace.resolve_type_without_self(&r#type),
                                     return err(warnings, errors),
                                     warnings,
                                     errors
                                 );
-                                fields_buf.push(TypedStorageField::new(name, r#type, span.clone()));
+                                let initializer = initializer.map(|initializer| {
+                                    check!(
+                                        TypedExpression::type_check(TypeCheckArguments {
+                                            checkee: initializer,
+                                            namespace,
+                                            return_type_annotation: r#type,
+                                            help_text: "This storage field's type annotation \
+                                                does not match up with its initializer's type.",
+                                            self_type,
+                                            build_config,
+                                            dead_code_graph,
+                                            mode: Mode::NonAbi,
+                                            opts,
+                                        }),
+                                        error_recovery_expr(name.span().clone()),
+                                        warnings,
+                                        errors
+                                    )
+                                });
+                                let field =
+                                    TypedStorageField::new(name, r#type, initializer, span.clone());
+                                check!(field.check_type_is_storable(), (), warnings, errors);
+                                fields_buf.push(field);
                             }
 
                             let decl = TypedStorageDeclaration::new(fields_buf, span);
@@ -701,6 +784,25 @@ impl TypedAstNode {
                             );
                             TypedDeclaration::StorageDeclaration(decl)
                         }
+                        Declaration::TypeAliasDeclaration(decl) => {
+                            is_upper_camel_case(&decl.name).ok(&mut warnings, &mut errors);
+                            let decl = check!(
+                                TypedTypeAliasDeclaration::type_check(decl, namespace, self_type),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
+                            check!(
+                                namespace.insert_symbol(
+                                    decl.name.clone(),
+                                    TypedDeclaration::TypeAliasDeclaration(decl.clone()),
+                                ),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
+                            TypedDeclaration::TypeAliasDeclaration(decl)
+                        }
                     })
                 }
                 AstNodeContent::Expression(a) => {
@@ -802,7 +904,10 @@ impl TypedAstNode {
                             build_config,
                             dead_code_graph,
                             mode: Mode::NonAbi,
-                            opts,
+                            opts: TCOpts {
+                                in_loop: true,
+                                ..opts
+                            },
                         }),
                         (
                             TypedCodeBlock {
@@ -819,6 +924,22 @@ impl TypedAstNode {
                         body: typed_body,
                     })
                 }
+                AstNodeContent::Break => {
+                    if !opts.in_loop {
+                        errors.push(CompileError::BreakOutsideLoop {
+                            span: node.span.clone(),
+                        });
+                    }
+                    TypedAstNodeContent::Break
+                }
+                AstNodeContent::Continue => {
+                    if !opts.in_loop {
+                        errors.push(CompileError::ContinueOutsideLoop {
+                            span: node.span.clone(),
+                        });
+                    }
+                    TypedAstNodeContent::Continue
+                }
             },
             span: node.span.clone(),
         };
@@ -893,6 +1014,83 @@ fn import_new_file(
     crate::compile_inner_dependency(file_str, alias, dep_config, namespace, dead_code_graph)
 }
 
+/// Type-checks the items of a nested module declaration into a submodule of the given
+/// [Namespace], reading them from `<name>.sw` alongside the current file for `mod name;`.
+///
+/// Any file modules declared within the module itself are looked up in a directory named after
+/// the module.
+fn type_check_module_declaration(
+    decl: &ModuleDeclaration,
+    namespace: &mut Namespace,
+    build_config: &BuildConfig,
+    dead_code_graph: &mut ControlFlowGraph,
+) -> CompileResult<()> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let mut module_config = build_config.clone();
+    module_config.dir_of_code = Arc::new(build_config.dir_of_code.join(decl.name.as_str()));
+
+    let tree = match &decl.kind {
+        ModuleDeclarationKind::Inline(nodes) => ParseTree {
+            root_nodes: nodes.clone(),
+            span: decl.span.clone(),
+        },
+        ModuleDeclarationKind::File => {
+            let canonical_path = build_config
+                .dir_of_code
+                .join(decl.name.as_str())
+                .with_extension(crate::constants::DEFAULT_FILE_EXTENSION);
+
+            let manifest_dir = build_config.manifest_path.parent().unwrap();
+            module_config.file_name = match canonical_path.strip_prefix(manifest_dir) {
+                Ok(file_name) => Arc::new(file_name.to_path_buf()),
+                Err(_) => return err(warnings, errors),
+            };
+
+            let file_str: Arc<str> = match std::fs::read_to_string(&canonical_path) {
+                Ok(s) => Arc::from(s),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    errors.push(CompileError::FileNotFound {
+                        file_path: canonical_path.to_string_lossy().to_string(),
+                        span: decl.name.span().clone(),
+                    });
+                    return err(warnings, errors);
+                }
+                Err(e) => {
+                    errors.push(CompileError::FileCouldNotBeRead {
+                        span: decl.name.span().clone(),
+                        file_path: canonical_path.to_string_lossy().to_string(),
+                        stringified_error: e.to_string(),
+                    });
+                    return err(warnings, errors);
+                }
+            };
+            check!(
+                crate::parse_module(file_str, &module_config),
+                return err(warnings, errors),
+                warnings,
+                errors
+            )
+        }
+    };
+
+    check!(
+        crate::compile_inner_module(
+            decl.name.clone(),
+            decl.visibility,
+            tree,
+            &module_config,
+            namespace,
+            dead_code_graph,
+        ),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    ok((), warnings, errors)
+}
+
 fn reassignment(
     arguments: TypeCheckArguments<'_, (ReassignmentTarget, Expression)>,
     span: Span,
@@ -1180,23 +1378,32 @@ fn type_check_trait_decl(
         help_text: _help_text,
         build_config,
         dead_code_graph,
+        opts,
         ..
     } = arguments;
 
     let mut warnings = Vec::new();
     let mut errors = Vec::new();
 
+    // A temporary namespace for checking within the trait's scope, with the type parameters and
+    // associated types of the trait in scope.
+    let mut trait_namespace = namespace.clone();
+    for type_parameter in trait_decl
+        .type_parameters
+        .iter()
+        .chain(trait_decl.associated_types.iter())
+    {
+        trait_namespace.insert_symbol(type_parameter.name_ident.clone(), type_parameter.into());
+    }
+
     // type check the interface surface
     let interface_surface = check!(
-        type_check_interface_surface(trait_decl.interface_surface.to_vec(), namespace),
+        type_check_interface_surface(trait_decl.interface_surface.to_vec(), &mut trait_namespace),
         return err(warnings, errors),
         warnings,
         errors
     );
 
-    // A temporary namespace for checking within the trait's scope.
-    let mut trait_namespace = namespace.clone();
-
     // Recursively handle supertraits: make their interfaces and methods available to this trait
     check!(
         handle_supertraits(&trait_decl.supertraits, &mut trait_namespace),
@@ -1232,10 +1439,32 @@ fn type_check_trait_decl(
         warnings,
         errors
     );
+    // likewise for the default values of the associated constants
+    for const_decl in trait_decl.constants.iter() {
+        check!(
+            TypedFunctionDeclaration::type_check_associated_const(TypeCheckArguments {
+                checkee: const_decl.clone(),
+                namespace: &mut trait_namespace,
+                return_type_annotation: insert_type(TypeInfo::Unknown),
+                help_text: Default::default(),
+                self_type: insert_type(TypeInfo::SelfType),
+                build_config,
+                dead_code_graph,
+                mode: Mode::NonAbi,
+                opts,
+            }),
+            continue,
+            warnings,
+            errors
+        );
+    }
     let typed_trait_decl = TypedDeclaration::TraitDeclaration(TypedTraitDeclaration {
         name: trait_decl.name.clone(),
+        type_parameters: trait_decl.type_parameters.to_vec(),
+        associated_types: trait_decl.associated_types.to_vec(),
         interface_surface,
         methods: trait_decl.methods.to_vec(),
+        constants: trait_decl.constants.to_vec(),
         supertraits: trait_decl.supertraits.to_vec(),
         visibility: trait_decl.visibility,
     });
@@ -1269,6 +1498,7 @@ fn type_check_interface_surface(
                              name,
                              type_id,
                              type_span,
+                             is_reference,
                          }| TypedFunctionParameter {
                             name,
                             r#type: check!(
@@ -1283,6 +1513,7 @@ fn type_check_interface_surface(
                                 errors,
                             ),
                             type_span,
+                            is_reference,
                         },
                     )
                     .collect(),
@@ -1329,6 +1560,7 @@ fn type_check_trait_methods(
             |FunctionParameter {
                  name,
                  type_id: ref r#type,
+                 is_reference,
                  ..
              }| {
                 let r#type = check!(
@@ -1352,8 +1584,11 @@ fn type_check_trait_methods(
                             is_constant: IsConstant::No,
                             span: name.span().clone(),
                         },
-                        // TODO allow mutable function params?
-                        is_mutable: VariableMutability::Immutable,
+                        is_mutable: if *is_reference {
+                            VariableMutability::Mutable
+                        } else {
+                            VariableMutability::Immutable
+                        },
                         const_decl_origin: false,
                         type_ascription: r#type,
                     }),
@@ -1406,6 +1641,7 @@ fn type_check_trait_methods(
                      name,
                      type_id,
                      type_span,
+                     is_reference,
                  }| {
                     TypedFunctionParameter {
                         name,
@@ -1421,6 +1657,7 @@ fn type_check_trait_methods(
                             errors,
                         ),
                         type_span,
+                        is_reference,
                     }
                 },
             )
@@ -1449,7 +1686,10 @@ fn type_check_trait_methods(
                 build_config,
                 dead_code_graph,
                 mode: Mode::NonAbi,
-                opts: TCOpts { purity }
+                opts: TCOpts {
+                    purity,
+                    in_loop: false
+                }
             }),
             continue,
             warnings,
@@ -1505,6 +1745,7 @@ fn convert_trait_methods_to_dummy_funcs(
                              name,
                              type_id,
                              type_span,
+                             is_reference,
                          }| TypedFunctionParameter {
                             name: name.clone(),
                             r#type: check!(
@@ -1519,6 +1760,7 @@ fn convert_trait_methods_to_dummy_funcs(
                                 errors,
                             ),
                             type_span: type_span.clone(),
+                            is_reference: *is_reference,
                         },
                     )
                     .collect(),
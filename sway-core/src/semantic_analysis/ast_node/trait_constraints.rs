@@ -0,0 +1,448 @@
+//! This module handles trait constraints on type parameters, e.g. the `T: Ord` in
+//! `fn max<T>(a: T, b: T) -> T where T: Ord`.
+//!
+//! Within the scope of a constrained type parameter, placeholder implementations of the
+//! constraining traits are inserted for it, so that their methods may be called on values of
+//! that type. The bodies of those calls can't be known until the type parameter has been
+//! monomorphized, at which point the constraints are checked against the concrete type and the
+//! placeholder calls are resolved to the real implementations.
+
+use super::{convert_trait_methods_to_dummy_funcs, Mode};
+
+use crate::{
+    error::*, semantic_analysis::*, type_engine::*, CallPath, TraitConstraint, TypeParameter,
+};
+
+use sway_types::span::Span;
+
+use std::collections::VecDeque;
+
+/// Insert placeholder implementations of the traits constraining each of `type_parameters`,
+/// and of their supertraits, into `namespace`.
+pub(crate) fn insert_trait_constraints(
+    namespace: &mut Namespace,
+    type_parameters: &[TypeParameter],
+) -> CompileResult<()> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    for type_parameter in type_parameters.iter() {
+        let type_id = insert_type(TypeInfo::UnknownGeneric {
+            name: type_parameter.name_ident.clone(),
+        });
+        for constraint in type_parameter.trait_constraints.iter() {
+            check!(
+                insert_trait_placeholders(namespace, &constraint.call_path, type_id),
+                continue,
+                warnings,
+                errors
+            );
+        }
+    }
+    ok((), warnings, errors)
+}
+
+/// Replace the call paths of the trait constraints on `type_parameters` with the canonical paths
+/// of the traits they refer to, so that they can be checked from any module.
+pub(crate) fn canonicalize_trait_constraints(
+    namespace: &Namespace,
+    type_parameters: &[TypeParameter],
+) -> Vec<TypeParameter> {
+    type_parameters
+        .iter()
+        .map(|type_parameter| TypeParameter {
+            trait_constraints: type_parameter
+                .trait_constraints
+                .iter()
+                .map(|constraint| TraitConstraint {
+                    call_path: namespace.canonicalize_call_path(&constraint.call_path),
+                })
+                .collect(),
+            ..type_parameter.clone()
+        })
+        .collect()
+}
+
+fn insert_trait_placeholders(
+    namespace: &mut Namespace,
+    trait_name: &CallPath,
+    type_id: TypeId,
+) -> CompileResult<()> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    match namespace
+        .resolve_call_path(trait_name)
+        .ok(&mut warnings, &mut errors)
+        .cloned()
+    {
+        Some(TypedDeclaration::TraitDeclaration(TypedTraitDeclaration {
+            interface_surface,
+            methods,
+            supertraits,
+            ..
+        })) => {
+            let mut placeholders = interface_surface
+                .iter()
+                .map(|x| x.to_dummy_func(Mode::NonAbi))
+                .collect::<Vec<_>>();
+            placeholders.append(&mut check!(
+                convert_trait_methods_to_dummy_funcs(&methods, namespace),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ));
+            namespace.insert_trait_implementation(
+                trait_name.clone(),
+                look_up_type_id(type_id),
+                placeholders
+                    .into_iter()
+                    .map(|x| x.replace_self_types(type_id))
+                    .collect(),
+            );
+            for supertrait in supertraits.iter() {
+                check!(
+                    insert_trait_placeholders(namespace, &supertrait.name, type_id),
+                    continue,
+                    warnings,
+                    errors
+                );
+            }
+        }
+        _ => errors.push(CompileError::TraitNotFound {
+            name: trait_name.clone(),
+        }),
+    }
+    ok((), warnings, errors)
+}
+
+/// Check that the type each type parameter in `type_mapping` has been instantiated with
+/// implements the traits constraining it.
+pub(crate) fn check_trait_constraints(
+    namespace: &Namespace,
+    type_mapping: &TypeMapping,
+    span: &Span,
+) -> CompileResult<()> {
+    let mut errors = vec![];
+    for (type_parameter, type_id) in type_mapping.iter() {
+        let ty = match look_up_type_id(*type_id) {
+            // Nothing to check until the type has been inferred; if it never is then that is
+            // reported separately.
+            TypeInfo::UnknownGeneric { name } if name == type_parameter.name_ident => continue,
+            TypeInfo::Unknown | TypeInfo::ErrorRecovery => continue,
+            TypeInfo::Numeric => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+            ty => ty,
+        };
+        let implemented_traits = namespace
+            .implemented_traits
+            .get_call_path_and_type_info(ty.clone());
+        for constraint in type_parameter.trait_constraints.iter() {
+            if !implemented_traits
+                .iter()
+                .any(|((trait_name, _), _)| is_same_trait(trait_name, &constraint.call_path))
+            {
+                errors.push(CompileError::TraitConstraintNotSatisfied {
+                    param: type_parameter.name_ident.as_str().to_string(),
+                    ty: ty.friendly_type_str(),
+                    trait_name: constraint.call_path.suffix.as_str().to_string(),
+                    span: span.clone(),
+                });
+            }
+        }
+    }
+    if errors.is_empty() {
+        ok((), vec![], vec![])
+    } else {
+        err(vec![], errors)
+    }
+}
+
+/// Whether the canonical paths `a` and `b` refer to the same trait declaration.
+///
+/// The prefixes of a path to a trait in a dependency depend on the package it was resolved in, so
+/// the declarations are compared by the spans of their names instead.
+fn is_same_trait(a: &CallPath, b: &CallPath) -> bool {
+    a.suffix == b.suffix && a.suffix.span() == b.suffix.span()
+}
+
+/// Replace the placeholder bodies of calls to trait constraint methods with those of the
+/// actual implementations, now that the types they are called on are known.  Calls on types
+/// which are still generic are left alone.
+pub(crate) trait ResolveTraitConstraintMethods {
+    fn resolve_trait_constraint_methods(
+        &mut self,
+        namespace: &mut Namespace,
+        self_type: TypeId,
+    ) -> Vec<CompileError>;
+}
+
+impl ResolveTraitConstraintMethods for TypedCodeBlock {
+    fn resolve_trait_constraint_methods(
+        &mut self,
+        namespace: &mut Namespace,
+        self_type: TypeId,
+    ) -> Vec<CompileError> {
+        self.contents
+            .iter_mut()
+            .flat_map(|node| node.resolve_trait_constraint_methods(namespace, self_type))
+            .collect()
+    }
+}
+
+impl ResolveTraitConstraintMethods for TypedAstNode {
+    fn resolve_trait_constraint_methods(
+        &mut self,
+        namespace: &mut Namespace,
+        self_type: TypeId,
+    ) -> Vec<CompileError> {
+        use TypedAstNodeContent::*;
+        match self.content {
+            ReturnStatement(ref mut stmt) => stmt
+                .expr
+                .resolve_trait_constraint_methods(namespace, self_type),
+            Declaration(ref mut decl) => {
+                decl.resolve_trait_constraint_methods(namespace, self_type)
+            }
+            Expression(ref mut expr) | ImplicitReturnExpression(ref mut expr) => {
+                expr.resolve_trait_constraint_methods(namespace, self_type)
+            }
+            WhileLoop(TypedWhileLoop {
+                ref mut condition,
+                ref mut body,
+            }) => {
+                let mut errors = condition.resolve_trait_constraint_methods(namespace, self_type);
+                errors.append(&mut body.resolve_trait_constraint_methods(namespace, self_type));
+                errors
+            }
+            Break | Continue | SideEffect => vec![],
+        }
+    }
+}
+
+impl ResolveTraitConstraintMethods for TypedDeclaration {
+    fn resolve_trait_constraint_methods(
+        &mut self,
+        namespace: &mut Namespace,
+        self_type: TypeId,
+    ) -> Vec<CompileError> {
+        use TypedDeclaration::*;
+        match self {
+            VariableDeclaration(decl) => decl
+                .body
+                .resolve_trait_constraint_methods(namespace, self_type),
+            ConstantDeclaration(decl) => decl
+                .value
+                .resolve_trait_constraint_methods(namespace, self_type),
+            Reassignment(reassignment) => reassignment
+                .rhs
+                .resolve_trait_constraint_methods(namespace, self_type),
+            StorageReassignment(reassignment) => reassignment
+                .rhs
+                .resolve_trait_constraint_methods(namespace, self_type),
+            // Nested declarations are resolved as they are used.
+            FunctionDeclaration(_)
+            | TraitDeclaration(_)
+            | StructDeclaration(_)
+            | EnumDeclaration(_)
+            | ImplTrait { .. }
+            | AbiDeclaration(_)
+            | StorageDeclaration(_)
+            | TypeAliasDeclaration(_)
+            | GenericTypeForFunctionScope { .. }
+            | ConstGenericForFunctionScope { .. }
+            | ErrorRecovery => vec![],
+        }
+    }
+}
+
+impl ResolveTraitConstraintMethods for TypedExpression {
+    fn resolve_trait_constraint_methods(
+        &mut self,
+        namespace: &mut Namespace,
+        self_type: TypeId,
+    ) -> Vec<CompileError> {
+        use TypedExpressionVariant::*;
+        let mut errors = vec![];
+        match self.expression {
+            FunctionApplication {
+                ref mut arguments,
+                ref mut function_body,
+                ref mut trait_constraint_method,
+                ..
+            } => {
+                for (_, arg) in arguments.iter_mut() {
+                    errors.append(&mut arg.resolve_trait_constraint_methods(namespace, self_type));
+                }
+                let resolved_method = match trait_constraint_method {
+                    Some(TraitConstraintMethod {
+                        receiver_type,
+                        method_path,
+                    }) if !matches!(
+                        look_up_type_id(*receiver_type),
+                        TypeInfo::UnknownGeneric { .. }
+                    ) =>
+                    {
+                        let args_buf = arguments
+                            .iter()
+                            .map(|(_, arg)| arg.clone())
+                            .collect::<VecDeque<_>>();
+                        let mut warnings = vec![];
+                        namespace
+                            .find_method_for_type(*receiver_type, method_path, self_type, &args_buf)
+                            .ok(&mut warnings, &mut errors)
+                    }
+                    _ => None,
+                };
+                match resolved_method {
+                    Some(method) => {
+                        // The implementation may name its parameters differently to the trait.
+                        for ((name, _), param) in arguments.iter_mut().zip(method.parameters) {
+                            *name = param.name;
+                        }
+                        *function_body = method.body;
+                        *trait_constraint_method = None;
+                    }
+                    None => errors.append(
+                        &mut function_body.resolve_trait_constraint_methods(namespace, self_type),
+                    ),
+                }
+            }
+            LazyOperator {
+                ref mut lhs,
+                ref mut rhs,
+                ..
+            } => {
+                errors.append(&mut lhs.resolve_trait_constraint_methods(namespace, self_type));
+                errors.append(&mut rhs.resolve_trait_constraint_methods(namespace, self_type));
+            }
+            Tuple { ref mut fields } => {
+                for field in fields.iter_mut() {
+                    errors
+                        .append(&mut field.resolve_trait_constraint_methods(namespace, self_type));
+                }
+            }
+            Array { ref mut contents } => {
+                for elem in contents.iter_mut() {
+                    errors.append(&mut elem.resolve_trait_constraint_methods(namespace, self_type));
+                }
+            }
+            ArrayIndex {
+                ref mut prefix,
+                ref mut index,
+            } => {
+                errors.append(&mut prefix.resolve_trait_constraint_methods(namespace, self_type));
+                errors.append(&mut index.resolve_trait_constraint_methods(namespace, self_type));
+            }
+            StructExpression { ref mut fields, .. } => {
+                for field in fields.iter_mut() {
+                    errors.append(
+                        &mut field
+                            .value
+                            .resolve_trait_constraint_methods(namespace, self_type),
+                    );
+                }
+            }
+            CodeBlock(ref mut block) => {
+                errors.append(&mut block.resolve_trait_constraint_methods(namespace, self_type));
+            }
+            IfExp {
+                ref mut condition,
+                ref mut then,
+                ref mut r#else,
+            } => {
+                errors
+                    .append(&mut condition.resolve_trait_constraint_methods(namespace, self_type));
+                errors.append(&mut then.resolve_trait_constraint_methods(namespace, self_type));
+                if let Some(r#else) = r#else {
+                    errors
+                        .append(&mut r#else.resolve_trait_constraint_methods(namespace, self_type));
+                }
+            }
+            AsmExpression {
+                ref mut registers, ..
+            } => {
+                for initializer in registers.iter_mut().filter_map(|x| x.initializer.as_mut()) {
+                    errors.append(
+                        &mut initializer.resolve_trait_constraint_methods(namespace, self_type),
+                    );
+                }
+            }
+            StructFieldAccess { ref mut prefix, .. } | TupleElemAccess { ref mut prefix, .. } => {
+                errors.append(&mut prefix.resolve_trait_constraint_methods(namespace, self_type));
+            }
+            IfLet {
+                ref mut expr,
+                ref mut then,
+                ref mut r#else,
+                ..
+            } => {
+                errors.append(&mut expr.resolve_trait_constraint_methods(namespace, self_type));
+                errors.append(&mut then.resolve_trait_constraint_methods(namespace, self_type));
+                if let Some(r#else) = r#else {
+                    errors
+                        .append(&mut r#else.resolve_trait_constraint_methods(namespace, self_type));
+                }
+            }
+            EnumInstantiation {
+                contents: Some(ref mut contents),
+                ..
+            } => {
+                errors.append(&mut contents.resolve_trait_constraint_methods(namespace, self_type));
+            }
+            AbiCast {
+                ref mut address, ..
+            } => {
+                errors.append(&mut address.resolve_trait_constraint_methods(namespace, self_type));
+            }
+            SizeOfValue { ref mut expr } => {
+                errors.append(&mut expr.resolve_trait_constraint_methods(namespace, self_type));
+            }
+            Closure { ref mut body, .. } => {
+                errors.append(&mut body.resolve_trait_constraint_methods(namespace, self_type));
+            }
+            ClosureCall {
+                ref mut closure,
+                ref mut arguments,
+            } => {
+                errors.append(&mut closure.resolve_trait_constraint_methods(namespace, self_type));
+                for arg in arguments.iter_mut() {
+                    errors.append(&mut arg.resolve_trait_constraint_methods(namespace, self_type));
+                }
+            }
+            EnumInstantiation { contents: None, .. }
+            | Literal(_)
+            | VariableExpression { .. }
+            | MutRef { .. }
+            | FunctionParameter
+            | StorageAccess(_)
+            | TypeProperty { .. }
+            | GetStorageKey
+            | AbiName(_) => (),
+        }
+        errors
+    }
+}
+
+/// Pair each of the `type_parameters` of a generic struct or enum with the type it has been
+/// instantiated with, by finding a field or variant whose type in the generic declaration,
+/// `generic_types`, is that type parameter.  Type parameters which only appear nested within
+/// other types are skipped.
+pub(crate) fn instantiated_type_parameters(
+    type_parameters: &[TypeParameter],
+    generic_types: &[TypeId],
+    instantiated_types: &[TypeId],
+) -> TypeMapping {
+    type_parameters
+        .iter()
+        .filter_map(|type_parameter| {
+            generic_types
+                .iter()
+                .zip(instantiated_types.iter())
+                .find(|(generic_type, _)| {
+                    look_up_type_id(**generic_type)
+                        == TypeInfo::UnknownGeneric {
+                            name: type_parameter.name_ident.clone(),
+                        }
+                })
+                .map(|(_, instantiated_type)| (type_parameter.clone(), *instantiated_type))
+        })
+        .collect()
+}
@@ -89,10 +89,12 @@ impl Items {
         if self.symbols.get(&name).is_some() {
             match item {
                 TypedDeclaration::EnumDeclaration { .. }
-                | TypedDeclaration::StructDeclaration { .. } => {
+                | TypedDeclaration::StructDeclaration { .. }
+                | TypedDeclaration::TypeAliasDeclaration { .. } => {
                     errors.push(CompileError::ShadowsOtherSymbol { name: name.clone() });
                 }
-                TypedDeclaration::GenericTypeForFunctionScope { .. } => {
+                TypedDeclaration::GenericTypeForFunctionScope { .. }
+                | TypedDeclaration::ConstGenericForFunctionScope { .. } => {
                     errors.push(CompileError::GenericShadowsGeneric { name: name.clone() });
                 }
                 _ => {
@@ -163,13 +165,13 @@ impl Items {
         new_type: TypeInfo,
         type_mapping: &TypeMapping,
     ) {
-        // This map grabs all (trait name, vec of methods) from self.implemented_traits
+        // This grabs all (trait name, vec of methods) from self.implemented_traits
         // corresponding to `old_type`.
         let methods = self
             .implemented_traits
             .get_methods_for_type_by_trait(old_type);
 
-        // Insert into `self.implemented_traits` the contents of the map above but with `new_type`
+        // Insert into `self.implemented_traits` the contents of the list above but with `new_type`
         // as the `TypeInfo` key.
         for (trait_name, mut trait_methods) in methods.into_iter() {
             trait_methods
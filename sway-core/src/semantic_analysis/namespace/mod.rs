@@ -17,3 +17,35 @@ type ModuleName = String;
 
 pub type Path = [Ident];
 pub type PathBuf = Vec<Ident>;
+
+/// Join the given `prefixes` onto the module at `mod_path`, resolving a leading `crate`, `self`
+/// or any number of leading `super` segments along the way.
+///
+/// `crate` refers to the root module, `self` to the module at `mod_path` and each `super` to the
+/// parent of the module before it. A `super` at the root module refers to the root itself.
+pub(crate) fn join_mod_path<'a, T>(mod_path: &Path, prefixes: T) -> PathBuf
+where
+    T: IntoIterator<Item = &'a Ident>,
+{
+    let mut path = mod_path.to_vec();
+    let mut prefixes = prefixes.into_iter().peekable();
+    match prefixes.peek().copied().map(Ident::as_str) {
+        Some("crate") => {
+            path.clear();
+            prefixes.next();
+        }
+        Some("self") => {
+            prefixes.next();
+        }
+        _ => {
+            while prefixes
+                .next_if(|ident| ident.as_str() == "super")
+                .is_some()
+            {
+                path.pop();
+            }
+        }
+    }
+    path.extend(prefixes.cloned());
+    path
+}
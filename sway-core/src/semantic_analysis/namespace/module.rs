@@ -18,7 +18,7 @@ use sway_types::span::Span;
 ///
 /// A `Module` contains a set of all items that exist within the lexical scope via declaration or
 /// importing, along with a map of each of its submodules.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Module {
     /// Submodules of the current module represented as an ordered map from each submodule's name
     /// to the associated `Module`.
@@ -30,9 +30,44 @@ pub struct Module {
     pub(crate) submodules: im::OrdMap<ModuleName, Module>,
     /// The set of symbols, implementations, synonyms and aliases present within this module.
     items: Items,
+    /// Whether this module may be accessed from outside of its parent module.
+    ///
+    /// Modules introduced with `dep` are always public, while those declared with `mod` are
+    /// private unless marked `pub`.
+    visibility: Visibility,
+    /// Whether this module is the root of a package from the `[dependencies]` table of a `forc`
+    /// manifest, rather than a module of the package being compiled.
+    pub(crate) is_external: bool,
+}
+
+impl Default for Module {
+    fn default() -> Self {
+        Self {
+            submodules: Default::default(),
+            items: Default::default(),
+            visibility: Visibility::Public,
+            is_external: false,
+        }
+    }
 }
 
 impl Module {
+    /// Whether this module may be accessed from outside of its parent module.
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    /// Set whether this module may be accessed from outside of its parent module.
+    pub(crate) fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    /// Whether this module is the root of a package from the `[dependencies]` table of a `forc`
+    /// manifest, rather than a module of the package being compiled.
+    pub fn is_external(&self) -> bool {
+        self.is_external
+    }
+
     /// Immutable access to this module's submodules.
     pub fn submodules(&self) -> &im::OrdMap<ModuleName, Module> {
         &self.submodules
@@ -89,6 +124,33 @@ impl Module {
         }
     }
 
+    /// Check that every module along the path to the `src` module may be accessed from the `dst`
+    /// module.
+    ///
+    /// A private module is only accessible from within its parent module and the parent's
+    /// descendants. Paths are assumed to be relative to `self`.
+    pub(crate) fn check_module_privacy(&self, src: &Path, dst: &Path) -> CompileResult<()> {
+        let mut module = self;
+        for (ix, ident) in src.iter().enumerate() {
+            match module.submodules.get(ident.as_str()) {
+                Some(submodule) => {
+                    if submodule.visibility.is_private() && !dst.starts_with(&src[..ix]) {
+                        return err(
+                            vec![],
+                            vec![CompileError::ModuleIsPrivate {
+                                name: ident.clone(),
+                            }],
+                        );
+                    }
+                    module = submodule;
+                }
+                // A missing module is reported when the path is actually resolved.
+                None => break,
+            }
+        }
+        ok((), vec![], vec![])
+    }
+
     /// Given a path to a `src` module, create synonyms to every symbol in that module to the given
     /// `dst` module.
     ///
@@ -98,18 +160,26 @@ impl Module {
     pub(crate) fn star_import(&mut self, src: &Path, dst: &Path) -> CompileResult<()> {
         let mut warnings = vec![];
         let mut errors = vec![];
+        check!(
+            self.check_module_privacy(src, dst),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
         let src_ns = check!(
             self.check_submodule(src),
             return err(warnings, errors),
             warnings,
             errors
         );
+        // Private items are only visible to the module they're declared in and its descendants.
+        let is_descendant = dst.starts_with(src);
         let implemented_traits = src_ns.implemented_traits.clone();
         let symbols = src_ns
             .symbols
             .iter()
             .filter_map(|(symbol, decl)| {
-                if decl.visibility() == Visibility::Public {
+                if is_descendant || decl.visibility() == Visibility::Public {
                     Some(symbol.clone())
                 } else {
                     None
@@ -161,6 +231,12 @@ impl Module {
     ) -> CompileResult<()> {
         let mut warnings = vec![];
         let mut errors = vec![];
+        check!(
+            self.check_module_privacy(src, dst),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
         let src_ns = check!(
             self.check_submodule(src),
             return err(warnings, errors),
@@ -170,7 +246,9 @@ impl Module {
         let mut impls_to_insert = vec![];
         match src_ns.symbols.get(item).cloned() {
             Some(decl) => {
-                if decl.visibility() != Visibility::Public {
+                // Private items are only visible to the module they're declared in and its
+                // descendants.
+                if decl.visibility() != Visibility::Public && !dst.starts_with(src) {
                     errors.push(CompileError::ImportPrivateSymbol { name: item.clone() });
                 }
                 // if this is a const, insert it into the local namespace directly
@@ -3,7 +3,10 @@ use crate::{
     TypeInfo, TypedDeclaration, TypedFunctionDeclaration,
 };
 
-use super::{module::Module, root::Root, submodule_namespace::SubmoduleNamespace, Path, PathBuf};
+use super::{
+    join_mod_path, module::Module, root::Root, submodule_namespace::SubmoduleNamespace, Path,
+    PathBuf,
+};
 
 use sway_types::span::Span;
 
@@ -35,7 +38,16 @@ pub struct Namespace {
 
 impl Namespace {
     /// Initialise the namespace at its root from the given initial namespace.
-    pub fn init_root(init: Module) -> Self {
+    pub fn init_root(mut init: Module) -> Self {
+        // The submodules of the initial namespace are the package's external dependencies.
+        init.submodules = init
+            .submodules
+            .into_iter()
+            .map(|(name, mut submodule)| {
+                submodule.is_external = true;
+                (name, submodule)
+            })
+            .collect();
         let root = Root::from(init.clone());
         let mod_path = vec![];
         Self {
@@ -50,12 +62,13 @@ impl Namespace {
         &self.mod_path
     }
 
-    /// Find the module that these prefixes point to
+    /// Find the module that these prefixes point to, resolving any leading `crate`, `self` or
+    /// `super` segments relative to the current module.
     pub fn find_module_path<'a, T>(&'a self, prefixes: T) -> PathBuf
     where
         T: IntoIterator<Item = &'a Ident>,
     {
-        self.mod_path.iter().chain(prefixes).cloned().collect()
+        join_mod_path(&self.mod_path, prefixes)
     }
 
     /// A reference to the root of the project namespace.
@@ -92,6 +105,28 @@ impl Namespace {
         self.root.resolve_call_path(&self.mod_path, call_path)
     }
 
+    /// Short-hand for calling [Root::canonicalize_call_path] on `root` with the `mod_path`.
+    pub(crate) fn canonicalize_call_path(&self, call_path: &CallPath) -> CallPath {
+        self.root.canonicalize_call_path(&self.mod_path, call_path)
+    }
+
+    /// Insert an implementation of the trait at `trait_name` into the current module, keyed by
+    /// the canonical path of the trait so that it can be told apart from other traits of the
+    /// same name.
+    pub(crate) fn insert_trait_implementation(
+        &mut self,
+        trait_name: CallPath,
+        type_implementing_for: TypeInfo,
+        functions_buf: Vec<TypedFunctionDeclaration>,
+    ) -> CompileResult<()> {
+        let trait_name = self.canonicalize_call_path(&trait_name);
+        self.module_mut().insert_trait_implementation(
+            trait_name,
+            type_implementing_for,
+            functions_buf,
+        )
+    }
+
     /// Short-hand for calling [Root::resolve_type_with_self] on `root` with the `mod_path`.
     pub(crate) fn resolve_type_with_self(
         &mut self,
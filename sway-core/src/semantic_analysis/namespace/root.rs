@@ -1,12 +1,15 @@
 use crate::{
     error::*,
-    semantic_analysis::{ast_node::TypedExpression, declaration::CreateTypeId},
+    semantic_analysis::{
+        ast_node::{TypedExpression, TypedTraitDeclaration},
+        declaration::CreateTypeId,
+    },
     type_engine::*,
     CallPath, CompileResult, Ident, TypeArgument, TypeInfo, TypedDeclaration,
     TypedFunctionDeclaration,
 };
 
-use super::{module::Module, namespace::Namespace, Path};
+use super::{join_mod_path, module::Module, namespace::Namespace, Path, PathBuf};
 
 use sway_types::span::Span;
 
@@ -28,19 +31,17 @@ pub struct Root {
 impl Root {
     /// Resolve a symbol that is potentially prefixed with some path, e.g. `foo::bar::symbol`.
     ///
-    /// This is short-hand for concatenating the `mod_path` with the `call_path`'s prefixes and
-    /// then calling `resolve_symbol` with the resulting path and call_path's suffix.
+    /// This is short-hand for joining the `mod_path` with the `call_path`'s prefixes and then
+    /// calling `resolve_symbol` with the resulting path and call_path's suffix. Any private modules
+    /// along the way must be accessible from `mod_path`.
     pub(crate) fn resolve_call_path(
         &self,
         mod_path: &Path,
         call_path: &CallPath,
     ) -> CompileResult<&TypedDeclaration> {
-        let symbol_path: Vec<_> = mod_path
-            .iter()
-            .chain(&call_path.prefixes)
-            .cloned()
-            .collect();
-        self.resolve_symbol(&symbol_path, &call_path.suffix)
+        let symbol_path = join_mod_path(mod_path, &call_path.prefixes);
+        self.check_module_privacy(&symbol_path, mod_path)
+            .flat_map(|()| self.resolve_symbol(&symbol_path, &call_path.suffix))
     }
 
     /// Given a path to a module and the identifier of a symbol within that module, resolve its
@@ -67,6 +68,50 @@ impl Root {
         })
     }
 
+    /// Given the identifier of a symbol which has already been resolved from the module at
+    /// `mod_path`, find the path of the module in which it is declared by following any imports.
+    fn find_declaring_module_path(&self, mod_path: &Path, symbol: &Ident) -> PathBuf {
+        self.find_declaration(mod_path, symbol).0
+    }
+
+    /// Given the identifier of a symbol which has already been resolved from the module at
+    /// `mod_path`, find the path of the module in which it is declared and the name it is
+    /// declared with by following any imports and aliases.
+    fn find_declaration(&self, mod_path: &Path, symbol: &Ident) -> (PathBuf, Ident) {
+        let module = &self[mod_path];
+        let true_symbol = module.use_aliases.get(symbol.as_str()).unwrap_or(symbol);
+        match module.use_synonyms.get(symbol) {
+            Some(src_path) if mod_path != src_path => self.find_declaration(src_path, true_symbol),
+            _ => (mod_path.to_vec(), symbol.clone()),
+        }
+    }
+
+    /// Resolve `call_path` from the module at `mod_path` to the absolute path of the declaration
+    /// it refers to, following any imports and aliases.
+    ///
+    /// The suffix of a path to a trait is the identifier from the trait declaration itself, whose
+    /// span tells it apart from any other trait of the same name. This matters for traits declared
+    /// in dependencies, whose paths are only absolute within the package they were resolved in.
+    ///
+    /// If the call path can't be resolved it is returned unchanged.
+    pub(crate) fn canonicalize_call_path(&self, mod_path: &Path, call_path: &CallPath) -> CallPath {
+        let symbol_path = join_mod_path(mod_path, &call_path.prefixes);
+        let decl = match self.resolve_symbol(&symbol_path, &call_path.suffix).value {
+            Some(decl) => decl,
+            None => return call_path.clone(),
+        };
+        let (prefixes, suffix) = self.find_declaration(&symbol_path, &call_path.suffix);
+        let suffix = match decl {
+            TypedDeclaration::TraitDeclaration(TypedTraitDeclaration { name, .. }) => name.clone(),
+            _ => suffix,
+        };
+        CallPath {
+            prefixes,
+            suffix,
+            is_absolute: true,
+        }
+    }
+
     /// This function either returns a struct (i.e. custom type), `None`, denoting the type that is
     /// being looked for is actually a generic, not-yet-resolved type.
     ///
@@ -165,7 +210,30 @@ impl Root {
                             decl.create_type_id()
                         }
                     }
-                    Some(TypedDeclaration::GenericTypeForFunctionScope { name, .. }) => {
+                    Some(TypedDeclaration::TypeAliasDeclaration(decl)) => {
+                        let ty = check!(
+                            decl.instantiate(name, &new_type_arguments, enforce_type_args),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        );
+                        // The aliased type is resolved in the module the alias is declared in.
+                        let alias_mod_path = self.find_declaring_module_path(mod_path, name);
+                        check!(
+                            self.resolve_type_with_self(
+                                &alias_mod_path,
+                                ty,
+                                self_type,
+                                span,
+                                enforce_type_args
+                            ),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        )
+                    }
+                    Some(TypedDeclaration::GenericTypeForFunctionScope { name, .. })
+                    | Some(TypedDeclaration::ConstGenericForFunctionScope { name, .. }) => {
                         insert_type(TypeInfo::UnknownGeneric { name })
                     }
                     _ => {
@@ -174,6 +242,33 @@ impl Root {
                     }
                 }
             }
+            TypeInfo::Tuple(fields) => {
+                // Only fields which still name a type need resolving. The others keep their type
+                // ids so that any inference in progress on them is preserved.
+                let mut new_fields = vec![];
+                for field in fields.into_iter() {
+                    let type_id = match look_up_type_id(field.type_id) {
+                        ty @ (TypeInfo::Custom { .. } | TypeInfo::Tuple(_)) => check!(
+                            self.resolve_type_with_self(
+                                mod_path,
+                                ty,
+                                self_type,
+                                field.span.clone(),
+                                enforce_type_args
+                            ),
+                            insert_type(TypeInfo::ErrorRecovery),
+                            warnings,
+                            errors
+                        ),
+                        _ => field.type_id,
+                    };
+                    new_fields.push(TypeArgument {
+                        type_id,
+                        span: field.span,
+                    });
+                }
+                insert_type(TypeInfo::Tuple(new_fields))
+            }
             TypeInfo::Array(type_id, size) => {
                 let elem_type_id = check!(
                     self.resolve_type_with_self(
@@ -189,6 +284,67 @@ impl Root {
                 );
                 insert_type(TypeInfo::Array(elem_type_id, size))
             }
+            TypeInfo::GenericArray(type_id, length) => {
+                let elem_type_id = check!(
+                    self.resolve_type_with_self(
+                        mod_path,
+                        look_up_type_id(type_id),
+                        self_type,
+                        span,
+                        enforce_type_args
+                    ),
+                    insert_type(TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                let length = check!(
+                    self.resolve_array_length(mod_path, length),
+                    insert_type(TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                insert_type(TypeInfo::GenericArray(elem_type_id, length))
+            }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => {
+                let mut new_parameters = vec![];
+                for parameter in parameters.into_iter() {
+                    let new_type_id = check!(
+                        self.resolve_type_with_self(
+                            mod_path,
+                            look_up_type_id(parameter.type_id),
+                            self_type,
+                            parameter.span.clone(),
+                            enforce_type_args
+                        ),
+                        insert_type(TypeInfo::ErrorRecovery),
+                        warnings,
+                        errors
+                    );
+                    new_parameters.push(TypeArgument {
+                        type_id: new_type_id,
+                        span: parameter.span,
+                    });
+                }
+                let return_type = check!(
+                    self.resolve_type_with_self(
+                        mod_path,
+                        look_up_type_id(return_type),
+                        self_type,
+                        span,
+                        enforce_type_args
+                    ),
+                    insert_type(TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                insert_type(TypeInfo::Function {
+                    parameters: new_parameters,
+                    return_type,
+                })
+            }
             TypeInfo::SelfType => self_type,
             TypeInfo::Ref(id) => id,
             o => insert_type(o),
@@ -277,8 +433,59 @@ impl Root {
                         decl.create_type_id()
                     }
                 }
+                Some(TypedDeclaration::TypeAliasDeclaration(decl)) => {
+                    let mut new_type_arguments = vec![];
+                    for type_argument in type_arguments.into_iter() {
+                        let new_type_id = check!(
+                            self.resolve_type_without_self(
+                                mod_path,
+                                &look_up_type_id(type_argument.type_id),
+                            ),
+                            insert_type(TypeInfo::ErrorRecovery),
+                            warnings,
+                            errors
+                        );
+                        let type_argument = TypeArgument {
+                            type_id: new_type_id,
+                            span: type_argument.span,
+                        };
+                        new_type_arguments.push(type_argument);
+                    }
+                    let ty = check!(
+                        decl.instantiate(&name, &new_type_arguments, false),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    let alias_mod_path = self.find_declaring_module_path(mod_path, &name);
+                    check!(
+                        self.resolve_type_without_self(&alias_mod_path, &ty),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    )
+                }
                 _ => insert_type(TypeInfo::Unknown),
             },
+            TypeInfo::Tuple(fields) => {
+                let mut new_fields = vec![];
+                for field in fields.into_iter() {
+                    let type_id = match look_up_type_id(field.type_id) {
+                        ty @ (TypeInfo::Custom { .. } | TypeInfo::Tuple(_)) => check!(
+                            self.resolve_type_without_self(mod_path, &ty),
+                            insert_type(TypeInfo::ErrorRecovery),
+                            warnings,
+                            errors
+                        ),
+                        _ => field.type_id,
+                    };
+                    new_fields.push(TypeArgument {
+                        type_id,
+                        span: field.span,
+                    });
+                }
+                insert_type(TypeInfo::Tuple(new_fields))
+            }
             TypeInfo::Array(type_id, size) => {
                 let elem_type_id = check!(
                     self.resolve_type_without_self(mod_path, &look_up_type_id(type_id)),
@@ -288,12 +495,83 @@ impl Root {
                 );
                 insert_type(TypeInfo::Array(elem_type_id, size))
             }
+            TypeInfo::GenericArray(type_id, length) => {
+                let elem_type_id = check!(
+                    self.resolve_type_without_self(mod_path, &look_up_type_id(type_id)),
+                    insert_type(TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                let length = check!(
+                    self.resolve_array_length(mod_path, length),
+                    insert_type(TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                insert_type(TypeInfo::GenericArray(elem_type_id, length))
+            }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => {
+                let mut new_parameters = vec![];
+                for parameter in parameters.into_iter() {
+                    let new_type_id = check!(
+                        self.resolve_type_without_self(
+                            mod_path,
+                            &look_up_type_id(parameter.type_id)
+                        ),
+                        insert_type(TypeInfo::ErrorRecovery),
+                        warnings,
+                        errors
+                    );
+                    new_parameters.push(TypeArgument {
+                        type_id: new_type_id,
+                        span: parameter.span,
+                    });
+                }
+                let return_type = check!(
+                    self.resolve_type_without_self(mod_path, &look_up_type_id(return_type)),
+                    insert_type(TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                insert_type(TypeInfo::Function {
+                    parameters: new_parameters,
+                    return_type,
+                })
+            }
             TypeInfo::Ref(id) => id,
             o => insert_type(o),
         };
         ok(type_id, warnings, errors)
     }
 
+    /// Resolve the length of an array of generic length, e.g. `N` in `[T; N]`, which must name a
+    /// const generic parameter.
+    fn resolve_array_length(&self, mod_path: &Path, length: TypeId) -> CompileResult<TypeId> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let length = match look_up_type_id(length) {
+            TypeInfo::Custom { name, .. } => match self
+                .resolve_symbol(mod_path, &name)
+                .ok(&mut warnings, &mut errors)
+            {
+                Some(TypedDeclaration::ConstGenericForFunctionScope { name }) => {
+                    insert_type(TypeInfo::UnknownGeneric { name: name.clone() })
+                }
+                Some(_) => {
+                    errors.push(CompileError::ArrayLengthNotConstGeneric { name });
+                    return err(warnings, errors);
+                }
+                None => return err(warnings, errors),
+            },
+            // the length has already been resolved
+            _ => length,
+        };
+        ok(length, warnings, errors)
+    }
+
     /// Given a method and a type (plus a `self_type` to potentially resolve it), find that method
     /// in the namespace. Requires `args_buf` because of some special casing for the standard
     /// library where we pull the type from the arguments buffer.
@@ -355,10 +633,21 @@ impl Root {
         let mut methods = local_methods;
         methods.append(&mut type_methods);
 
-        match methods
+        // A type can implement a generic trait more than once, e.g. both `From<b256>` and
+        // `From<u64>`, so prefer the method whose parameters accept the given arguments.
+        let mut matching_methods = methods
             .into_iter()
-            .find(|TypedFunctionDeclaration { name, .. }| name == method_name)
+            .filter(|TypedFunctionDeclaration { name, .. }| name == method_name)
+            .collect::<Vec<_>>();
+        let method = match matching_methods
+            .iter()
+            .position(|method| method_accepts_arguments(method, args_buf))
         {
+            Some(position) => Some(matching_methods.swap_remove(position)),
+            None => matching_methods.into_iter().next(),
+        };
+
+        match method {
             Some(o) => ok(o, warnings, errors),
             None => {
                 if args_buf.get(0).map(|x| look_up_type_id(x.return_type))
@@ -375,6 +664,34 @@ impl Root {
     }
 }
 
+/// Whether `method` can be called with the already type checked arguments in `args_buf`.
+fn method_accepts_arguments(
+    method: &TypedFunctionDeclaration,
+    args_buf: &VecDeque<TypedExpression>,
+) -> bool {
+    method.parameters.len() == args_buf.len()
+        && method
+            .parameters
+            .iter()
+            .zip(args_buf.iter())
+            .all(|(param, arg)| {
+                match (
+                    look_up_type_id(param.r#type),
+                    look_up_type_id(arg.return_type),
+                ) {
+                    (TypeInfo::UnknownGeneric { .. }, _)
+                    | (_, TypeInfo::Unknown)
+                    | (_, TypeInfo::UnknownGeneric { .. })
+                    | (_, TypeInfo::ErrorRecovery) => true,
+                    (
+                        TypeInfo::UnsignedInteger(_) | TypeInfo::SignedInteger(_),
+                        TypeInfo::Numeric,
+                    ) => true,
+                    (param_type, arg_type) => param_type == arg_type,
+                }
+            })
+}
+
 impl std::ops::Deref for Root {
     type Target = Module;
     fn deref(&self) -> &Self::Target {
@@ -1,7 +1,5 @@
 use crate::{error::*, CallPath, CompileResult, TypeInfo, TypedFunctionDeclaration};
 
-use std::collections::HashMap;
-
 type TraitName = CallPath;
 
 // This cannot be a HashMap because of how TypeInfo's are handled.
@@ -97,17 +95,20 @@ impl TraitMap {
         methods
     }
 
+    /// Returns the methods implemented for `r#type`, grouped by trait. A trait can appear more
+    /// than once if it is generic and implemented with different type arguments, e.g. `From<b256>`
+    /// and `From<u64>`.
     pub(crate) fn get_methods_for_type_by_trait(
         &self,
         r#type: TypeInfo,
-    ) -> HashMap<TraitName, Vec<TypedFunctionDeclaration>> {
-        let mut methods: HashMap<TraitName, Vec<TypedFunctionDeclaration>> = HashMap::new();
+    ) -> Vec<(TraitName, Vec<TypedFunctionDeclaration>)> {
+        let mut methods = vec![];
         for ((trait_name, type_info), trait_methods) in self.trait_map.iter() {
             if *type_info == r#type {
-                methods.insert(
+                methods.push((
                     (*trait_name).clone(),
                     trait_methods.values().cloned().collect(),
-                );
+                ));
             }
         }
         methods
@@ -27,9 +27,9 @@ pub(crate) fn order_ast_nodes_by_dependency(nodes: Vec<AstNode>) -> CompileResul
         errors.sort_by_key(|err| err.span().start());
         err(Vec::new(), errors)
     } else {
-        // Reorder the parsed AstNodes based on dependency.  Includes first, then uses, then
-        // reordered declarations, then anything else.  To keep the list stable and simple we can
-        // use a basic insertion sort.
+        // Reorder the parsed AstNodes based on dependency.  Includes first, then nested modules,
+        // then uses, then reordered declarations, then anything else.  To keep the list stable
+        // and simple we can use a basic insertion sort.
         ok(
             nodes
                 .into_iter()
@@ -166,13 +166,20 @@ fn depends_on(
         (AstNodeContent::IncludeStatement(_), AstNodeContent::IncludeStatement(_)) => false,
         (_, AstNodeContent::IncludeStatement(_)) => true,
 
+        // Then nested modules, in the order they're declared.
+        (AstNodeContent::IncludeStatement(_), AstNodeContent::ModuleDeclaration(_)) => false,
+        (AstNodeContent::ModuleDeclaration(_), AstNodeContent::ModuleDeclaration(_)) => false,
+        (_, AstNodeContent::ModuleDeclaration(_)) => true,
+
         // Use statements next.
         (AstNodeContent::IncludeStatement(_), AstNodeContent::UseStatement(_)) => false,
+        (AstNodeContent::ModuleDeclaration(_), AstNodeContent::UseStatement(_)) => false,
         (AstNodeContent::UseStatement(_), AstNodeContent::UseStatement(_)) => false,
         (_, AstNodeContent::UseStatement(_)) => true,
 
         // Then declarations, ordered using the dependecies list.
         (AstNodeContent::IncludeStatement(_), AstNodeContent::Declaration(_)) => false,
+        (AstNodeContent::ModuleDeclaration(_), AstNodeContent::Declaration(_)) => false,
         (AstNodeContent::UseStatement(_), AstNodeContent::Declaration(_)) => false,
         (AstNodeContent::Declaration(dependant), AstNodeContent::Declaration(dependee)) => {
             match (decl_name(dependant), decl_name(dependee)) {
@@ -253,14 +260,17 @@ impl Dependencies {
                 .gather_from_type_parameters(type_parameters),
             Declaration::Reassignment(decl) => self.gather_from_expr(&decl.rhs),
             Declaration::TraitDeclaration(TraitDeclaration {
+                type_parameters,
                 interface_surface,
                 methods,
+                constants,
                 supertraits,
                 ..
             }) => self
                 .gather_from_iter(supertraits.iter(), |deps, sup| {
                     deps.gather_from_call_path(&sup.name, false, false)
                 })
+                .gather_from_type_parameters(type_parameters)
                 .gather_from_iter(interface_surface.iter(), |deps, sig| {
                     deps.gather_from_iter(sig.parameters.iter(), |deps, param| {
                         deps.gather_from_typeinfo(&look_up_type_id(param.type_id))
@@ -269,34 +279,57 @@ impl Dependencies {
                 })
                 .gather_from_iter(methods.iter(), |deps, fn_decl| {
                     deps.gather_from_fn_decl(fn_decl)
+                })
+                .gather_from_iter(constants.iter(), |deps, const_decl| {
+                    deps.gather_from_expr(&const_decl.value)
                 }),
             Declaration::ImplTrait(ImplTrait {
                 trait_name,
+                trait_type_arguments,
                 type_implementing_for,
                 type_arguments,
                 functions,
+                constants,
+                associated_types,
                 ..
             }) => self
                 .gather_from_call_path(trait_name, false, false)
+                .gather_from_type_arguments(trait_type_arguments)
                 .gather_from_typeinfo(type_implementing_for)
                 .gather_from_type_parameters(type_arguments)
                 .gather_from_iter(functions.iter(), |deps, fn_decl| {
                     deps.gather_from_fn_decl(fn_decl)
+                })
+                .gather_from_iter(constants.iter(), |deps, const_decl| {
+                    deps.gather_from_typeinfo(&const_decl.type_ascription)
+                        .gather_from_expr(&const_decl.value)
+                })
+                .gather_from_iter(associated_types.iter(), |deps, associated_type| {
+                    deps.gather_from_typeinfo(&look_up_type_id(
+                        associated_type.type_argument.type_id,
+                    ))
                 }),
             Declaration::ImplSelf(ImplSelf {
                 type_implementing_for,
                 functions,
+                constants,
                 ..
             }) => self
                 .gather_from_typeinfo(type_implementing_for)
                 .gather_from_iter(functions.iter(), |deps, fn_decl| {
                     deps.gather_from_fn_decl(fn_decl)
+                })
+                .gather_from_iter(constants.iter(), |deps, const_decl| {
+                    deps.gather_from_typeinfo(&const_decl.type_ascription)
+                        .gather_from_expr(&const_decl.value)
                 }),
             Declaration::AbiDeclaration(AbiDeclaration {
+                type_parameters,
                 interface_surface,
                 methods,
                 ..
             }) => self
+                .gather_from_type_parameters(type_parameters)
                 .gather_from_iter(interface_surface.iter(), |deps, sig| {
                     deps.gather_from_iter(sig.parameters.iter(), |deps, param| {
                         deps.gather_from_typeinfo(&look_up_type_id(param.type_id))
@@ -307,9 +340,25 @@ impl Dependencies {
                     deps.gather_from_fn_decl(fn_decl)
                 }),
             Declaration::StorageDeclaration(StorageDeclaration { fields, .. }) => self
-                .gather_from_iter(fields.iter(), |deps, StorageField { r#type, .. }| {
-                    deps.gather_from_typeinfo(r#type)
-                }),
+                .gather_from_iter(
+                    fields.iter(),
+                    |deps,
+                     StorageField {
+                         r#type,
+                         initializer,
+                         ..
+                     }| {
+                        deps.gather_from_typeinfo(r#type)
+                            .gather_from_opt_expr(initializer.as_ref())
+                    },
+                ),
+            Declaration::TypeAliasDeclaration(TypeAliasDeclaration {
+                type_parameters,
+                ty,
+                ..
+            }) => self
+                .gather_from_typeinfo(ty)
+                .gather_from_type_parameters(type_parameters),
         }
     }
 
@@ -397,9 +446,13 @@ impl Dependencies {
 
             // we should do address someday, but due to the whole `re_parse_expression` thing
             // it isn't possible right now
-            Expression::AbiCast { abi_name, .. } => {
-                self.gather_from_call_path(abi_name, false, false)
-            }
+            Expression::AbiCast {
+                abi_name,
+                type_arguments,
+                ..
+            } => self
+                .gather_from_call_path(abi_name, false, false)
+                .gather_from_type_arguments(type_arguments),
 
             Expression::Literal { .. } => self,
             Expression::Tuple { fields, .. } => {
@@ -416,6 +469,19 @@ impl Dependencies {
                 .gather_from_opt_expr(r#else.as_deref()),
             Expression::SizeOfVal { exp, .. } => self.gather_from_expr(exp),
             Expression::BuiltinGetTypeProperty { .. } => self,
+            Expression::BuiltinGetStorageKey { .. } => self,
+            Expression::Closure {
+                parameters,
+                return_type,
+                body,
+                ..
+            } => self
+                .gather_from_iter(parameters.iter(), |deps, param| {
+                    deps.gather_from_typeinfo(&look_up_type_id(param.type_id))
+                })
+                .gather_from_typeinfo(return_type)
+                .gather_from_expr(body),
+            Expression::MutRef { expr, .. } => self.gather_from_expr(expr),
         }
     }
 
@@ -445,8 +511,11 @@ impl Dependencies {
             }
 
             // No deps from these guys.
+            AstNodeContent::Break => self,
+            AstNodeContent::Continue => self,
             AstNodeContent::UseStatement(_) => self,
             AstNodeContent::IncludeStatement(_) => self,
+            AstNodeContent::ModuleDeclaration(_) => self,
         }
     }
 
@@ -501,6 +570,16 @@ impl Dependencies {
                 self.deps.insert(DependentSymbol::Symbol(name.to_string()));
                 self.gather_from_type_arguments(type_arguments)
             }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => self
+                .gather_from_type_arguments(parameters)
+                .gather_from_typeinfo(&look_up_type_id(*return_type)),
+            TypeInfo::Tuple(fields) => self.gather_from_type_arguments(fields),
+            TypeInfo::Array(elem_ty, _) | TypeInfo::GenericArray(elem_ty, _) => {
+                self.gather_from_typeinfo(&look_up_type_id(*elem_ty))
+            }
             _ => self,
         }
     }
@@ -581,6 +660,7 @@ fn decl_name(decl: &Declaration) -> Option<DependentSymbol> {
         Declaration::EnumDeclaration(decl) => dep_sym(decl.name.as_str().to_string()),
         Declaration::TraitDeclaration(decl) => dep_sym(decl.name.as_str().to_string()),
         Declaration::AbiDeclaration(decl) => dep_sym(decl.name.as_str().to_string()),
+        Declaration::TypeAliasDeclaration(decl) => dep_sym(decl.name.as_str().to_string()),
 
         // These have the added complexity of converting CallPath and/or TypeInfo into a name.
         Declaration::ImplSelf(decl) => {
@@ -631,6 +711,12 @@ fn type_info_name(type_info: &TypeInfo) -> String {
             IntegerBits::ThirtyTwo => "uint32",
             IntegerBits::SixtyFour => "uint64",
         },
+        TypeInfo::SignedInteger(n) => match n {
+            IntegerBits::Eight => "int8",
+            IntegerBits::Sixteen => "int16",
+            IntegerBits::ThirtyTwo => "int32",
+            IntegerBits::SixtyFour => "int64",
+        },
         TypeInfo::Boolean => "bool",
         TypeInfo::Custom { name, .. } => name.as_str(),
         TypeInfo::Tuple(fields) if fields.is_empty() => "unit",
@@ -649,8 +735,10 @@ fn type_info_name(type_info: &TypeInfo) -> String {
         }
         TypeInfo::Struct { .. } => "struct",
         TypeInfo::Enum { .. } => "enum",
-        TypeInfo::Array(..) => "array",
+        TypeInfo::Array(..) | TypeInfo::GenericArray(..) => "array",
+        TypeInfo::ConstGenericValue(..) => "const generic value",
         TypeInfo::Storage { .. } => "contract storage",
+        TypeInfo::Function { .. } => "fn",
     }
     .to_string()
 }
@@ -16,6 +16,7 @@ use crate::{
     type_engine::*,
     AstNode, ParseTree,
 };
+use fuel_tx::StorageSlot;
 use sway_types::{ident::Ident, span::Span};
 
 /// Represents the different variants of the AST.
@@ -87,15 +88,37 @@ impl TypedParseTree {
         }
     }
 
+    /// The storage slots to initialize when deploying a contract, from the initializers in its
+    /// storage declaration.  Other tree types have no storage.
+    pub fn get_storage_slots(&self) -> CompileResult<Vec<StorageSlot>> {
+        match self {
+            TypedParseTree::Contract {
+                declarations,
+                namespace,
+                ..
+            } => declarations
+                .iter()
+                .find_map(|decl| match decl {
+                    TypedDeclaration::StorageDeclaration(decl) => {
+                        Some(decl.get_initialized_storage_slots(namespace))
+                    }
+                    _ => None,
+                })
+                .unwrap_or_else(|| ok(vec![], vec![], vec![])),
+            _ => ok(vec![], vec![], vec![]),
+        }
+    }
+
     /// Ensures there are no unresolved types or types awaiting resolution in the AST.
     pub(crate) fn finalize_types(&self) -> CompileResult<()> {
         use TypedParseTree::*;
         // Get all of the entry points for this tree type. For libraries, that's everything
-        // public. For contracts, ABI entries. For scripts and predicates, any function named `main`.
+        // public other than generic functions, which are only checked once monomorphized. For
+        // contracts, ABI entries. For scripts and predicates, any function named `main`.
         let errors: Vec<_> = match self {
             Library { all_nodes, .. } => all_nodes
                 .iter()
-                .filter(|x| x.is_public())
+                .filter(|x| x.is_public() && !x.is_generic_function())
                 .flat_map(UnresolvedTypeCheck::check_for_unresolved_types)
                 .collect(),
             Script { all_nodes, .. } => all_nodes
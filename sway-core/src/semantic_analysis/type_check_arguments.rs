@@ -19,4 +19,7 @@ pub struct TypeCheckArguments<'a, T> {
 #[derive(Default, Clone, Copy)]
 pub struct TCOpts {
     pub(crate) purity: Purity,
+    /// Whether the node being checked is within the body of a loop, i.e., whether `break` and
+    /// `continue` are permitted.
+    pub(crate) in_loop: bool,
 }
@@ -25,6 +25,12 @@ impl Engine {
     pub fn look_up_type_id(&self, id: TypeId) -> TypeInfo {
         match self.slab.get(id) {
             TypeInfo::Ref(other) => self.look_up_type_id(other),
+            // Once the length of an array of generic length has been inferred, it is just an
+            // array.
+            TypeInfo::GenericArray(elem_ty, length) => match self.look_up_type_id(length) {
+                TypeInfo::ConstGenericValue(count) => TypeInfo::Array(elem_ty, count),
+                _ => TypeInfo::GenericArray(elem_ty, length),
+            },
             ty => ty,
         }
     }
@@ -109,7 +115,43 @@ impl Engine {
                 (warnings, errors)
             }
 
-            (UnsignedInteger(received_width), UnsignedInteger(expected_width)) => {
+            (
+                Function {
+                    parameters: received_parameters,
+                    return_type: received_return_type,
+                },
+                Function {
+                    parameters: expected_parameters,
+                    return_type: expected_return_type,
+                },
+            ) if received_parameters.len() == expected_parameters.len() => {
+                let mut warnings = vec![];
+                let mut errors = vec![];
+                for (received_parameter, expected_parameter) in
+                    received_parameters.iter().zip(expected_parameters.iter())
+                {
+                    let (new_warnings, new_errors) = self.unify(
+                        received_parameter.type_id,
+                        expected_parameter.type_id,
+                        &received_parameter.span,
+                        help_text.clone(),
+                    );
+                    warnings.extend(new_warnings);
+                    errors.extend(new_errors);
+                }
+                let (new_warnings, new_errors) = self.unify(
+                    received_return_type,
+                    expected_return_type,
+                    span,
+                    help_text.clone(),
+                );
+                warnings.extend(new_warnings);
+                errors.extend(new_errors);
+                (warnings, errors)
+            }
+
+            (UnsignedInteger(received_width), UnsignedInteger(expected_width))
+            | (SignedInteger(received_width), SignedInteger(expected_width)) => {
                 // E.g., in a variable declaration `let a: u32 = 10u64` the 'expected' type will be
                 // the annotation `u32`, and the 'received' type is 'self' of the initialiser, or
                 // `u64`.  So we're casting received TO expected.
@@ -223,13 +265,13 @@ impl Engine {
                 (warnings, errors)
             }
 
-            (Numeric, expected_info @ UnsignedInteger(_)) => {
+            (Numeric, expected_info @ (UnsignedInteger(_) | SignedInteger(_))) => {
                 match self.slab.replace(received, &Numeric, expected_info) {
                     None => (vec![], vec![]),
                     Some(_) => self.unify(received, expected, span, help_text),
                 }
             }
-            (received_info @ UnsignedInteger(_), Numeric) => {
+            (received_info @ (UnsignedInteger(_) | SignedInteger(_)), Numeric) => {
                 match self.slab.replace(expected, &Numeric, received_info) {
                     None => (vec![], vec![]),
                     Some(_) => self.unify(received, expected, span, help_text),
@@ -253,6 +295,38 @@ impl Engine {
                 (warnings, errors)
             }
 
+            (Array(a_elem, a_count), GenericArray(b_elem, b_length)) => {
+                let a_length = self.insert_type(ConstGenericValue(a_count));
+                self.unify_arrays(
+                    received,
+                    expected,
+                    (a_elem, a_length),
+                    (b_elem, b_length),
+                    span,
+                    help_text,
+                )
+            }
+            (GenericArray(a_elem, a_length), Array(b_elem, b_count)) => {
+                let b_length = self.insert_type(ConstGenericValue(b_count));
+                self.unify_arrays(
+                    received,
+                    expected,
+                    (a_elem, a_length),
+                    (b_elem, b_length),
+                    span,
+                    help_text,
+                )
+            }
+            (GenericArray(a_elem, a_length), GenericArray(b_elem, b_length)) => self.unify_arrays(
+                received,
+                expected,
+                (a_elem, a_length),
+                (b_elem, b_length),
+                span,
+                help_text,
+            ),
+            (ConstGenericValue(l), ConstGenericValue(r)) if l == r => (vec![], vec![]),
+
             (
                 TypeInfo::ContractCaller {
                     abi_name: ref abi_name_a,
@@ -292,10 +366,6 @@ impl Engine {
             // When unifying complex types, we must check their sub-types. This
             // can be trivially implemented for tuples, sum types, etc.
             // (List(a_item), List(b_item)) => self.unify(a_item, b_item),
-            // this can be used for curried function types but we might not want that
-            // (Func(a_i, a_o), Func(b_i, b_o)) => {
-            //     self.unify(a_i, b_i).and_then(|_| self.unify(a_o, b_o))
-            // }
 
             // If no previous attempts to unify were successful, raise an error
             (TypeInfo::ErrorRecovery, _) => (vec![], vec![]),
@@ -312,6 +382,38 @@ impl Engine {
         }
     }
 
+    /// Unify two arrays whose lengths may be const generic parameters by unifying their element
+    /// types and their lengths, which infers the value of any such parameter.
+    fn unify_arrays(
+        &self,
+        received: TypeId,
+        expected: TypeId,
+        (received_elem, received_length): (TypeId, TypeId),
+        (expected_elem, expected_length): (TypeId, TypeId),
+        span: &Span,
+        help_text: String,
+    ) -> (Vec<CompileWarning>, Vec<TypeError>) {
+        let (mut warnings, mut new_errors) =
+            self.unify(received_elem, expected_elem, span, help_text.clone());
+        let (length_warnings, length_errors) =
+            self.unify(received_length, expected_length, span, help_text.clone());
+        warnings.extend(length_warnings);
+        new_errors.extend(length_errors);
+
+        // If there was an error then we want to report the array types as mismatching, not
+        // the elem types or lengths.
+        let mut errors = vec![];
+        if !new_errors.is_empty() {
+            errors.push(TypeError::MismatchedType {
+                expected,
+                received,
+                help_text,
+                span: span.clone(),
+            });
+        }
+        (warnings, errors)
+    }
+
     pub fn unify_with_self(
         &self,
         received: TypeId,
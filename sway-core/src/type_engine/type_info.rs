@@ -37,6 +37,7 @@ pub enum TypeInfo {
     },
     Str(u64),
     UnsignedInteger(IntegerBits),
+    SignedInteger(IntegerBits),
     Enum {
         name: Ident,
         type_parameters: Vec<TypeParameter>,
@@ -78,12 +79,25 @@ pub enum TypeInfo {
     ErrorRecovery,
     // Static, constant size arrays.
     Array(TypeId, usize),
+    /// A static array whose length is a const generic parameter, e.g. `[T; N]`. The second id is
+    /// the length, which is the parameter itself until its value has been inferred. From then on
+    /// the type engine looks this type up as an `Array`.
+    GenericArray(TypeId, TypeId),
+    /// The value which a const generic parameter has been inferred to have.
+    ConstGenericValue(usize),
     /// Represents the entire storage declaration struct
     /// Stored without initializers here, as typed struct fields,
     /// so type checking is able to treat it as a struct with fields.
     Storage {
         fields: Vec<TypedStructField>,
     },
+    /// The type of a closure or function value, written `fn(T, ..) -> U`.
+    /// Values of this type are never materialized at runtime; they are inlined into
+    /// every call site during IR generation.
+    Function {
+        parameters: Vec<TypeArgument>,
+        return_type: TypeId,
+    },
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -182,6 +196,27 @@ impl Hash for TypeInfo {
                 state.write_u8(19);
                 fields.hash(state);
             }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => {
+                state.write_u8(20);
+                parameters.hash(state);
+                look_up_type_id(*return_type).hash(state);
+            }
+            TypeInfo::GenericArray(elem_ty, length) => {
+                state.write_u8(21);
+                look_up_type_id(*elem_ty).hash(state);
+                look_up_type_id(*length).hash(state);
+            }
+            TypeInfo::ConstGenericValue(value) => {
+                state.write_u8(22);
+                value.hash(state);
+            }
+            TypeInfo::SignedInteger(bits) => {
+                state.write_u8(23);
+                bits.hash(state);
+            }
         }
     }
 }
@@ -213,6 +248,7 @@ impl PartialEq for TypeInfo {
             ) => l_name == r_name && l_type_args == r_type_args,
             (Self::Str(l), Self::Str(r)) => l == r,
             (Self::UnsignedInteger(l), Self::UnsignedInteger(r)) => l == r,
+            (Self::SignedInteger(l), Self::SignedInteger(r)) => l == r,
             (
                 Self::Enum {
                     name: l_name,
@@ -256,9 +292,31 @@ impl PartialEq for TypeInfo {
             (Self::Array(l0, l1), Self::Array(r0, r1)) => {
                 look_up_type_id(*l0) == look_up_type_id(*r0) && l1 == r1
             }
+            (Self::GenericArray(l0, l1), Self::GenericArray(r0, r1)) => {
+                look_up_type_id(*l0) == look_up_type_id(*r0)
+                    && look_up_type_id(*l1) == look_up_type_id(*r1)
+            }
+            (Self::ConstGenericValue(l), Self::ConstGenericValue(r)) => l == r,
             (TypeInfo::Storage { fields: l_fields }, TypeInfo::Storage { fields: r_fields }) => {
                 l_fields == r_fields
             }
+            (
+                Self::Function {
+                    parameters: l_parameters,
+                    return_type: l_return_type,
+                },
+                Self::Function {
+                    parameters: r_parameters,
+                    return_type: r_return_type,
+                },
+            ) => {
+                l_parameters.len() == r_parameters.len()
+                    && l_parameters
+                        .iter()
+                        .zip(r_parameters.iter())
+                        .all(|(l, r)| look_up_type_id(l.type_id) == look_up_type_id(r.type_id))
+                    && look_up_type_id(*l_return_type) == look_up_type_id(*r_return_type)
+            }
             _ => false,
         }
     }
@@ -286,6 +344,13 @@ impl TypeInfo {
                 IntegerBits::SixtyFour => "u64",
             }
             .into(),
+            SignedInteger(x) => match x {
+                IntegerBits::Eight => "i8",
+                IntegerBits::Sixteen => "i16",
+                IntegerBits::ThirtyTwo => "i32",
+                IntegerBits::SixtyFour => "i64",
+            }
+            .into(),
             Boolean => "bool".into(),
             Custom { name, .. } => format!("unresolved {}", name.as_str()),
             Ref(id) => format!("T{} ({})", id, (*id).friendly_type_str()),
@@ -318,7 +383,27 @@ impl TypeInfo {
                 format!("contract caller {}", abi_name)
             }
             Array(elem_ty, count) => format!("[{}; {}]", elem_ty.friendly_type_str(), count),
+            GenericArray(elem_ty, length) => format!(
+                "[{}; {}]",
+                elem_ty.friendly_type_str(),
+                length.friendly_type_str()
+            ),
+            ConstGenericValue(value) => value.to_string(),
             Storage { .. } => "contract storage".into(),
+            Function {
+                parameters,
+                return_type,
+            } => {
+                let parameter_strs = parameters
+                    .iter()
+                    .map(|parameter| parameter.friendly_type_str())
+                    .collect::<Vec<String>>();
+                format!(
+                    "fn({}) -> {}",
+                    parameter_strs.join(", "),
+                    return_type.friendly_type_str()
+                )
+            }
         }
     }
 
@@ -335,6 +420,13 @@ impl TypeInfo {
                 IntegerBits::SixtyFour => "u64",
             }
             .into(),
+            SignedInteger(x) => match x {
+                IntegerBits::Eight => "i8",
+                IntegerBits::Sixteen => "i16",
+                IntegerBits::ThirtyTwo => "i32",
+                IntegerBits::SixtyFour => "i64",
+            }
+            .into(),
             Boolean => "bool".into(),
             Custom { name, .. } => format!("unresolved {}", name.as_str()),
             Ref(id) => format!("T{} ({})", id, (*id).json_abi_str()),
@@ -361,7 +453,25 @@ impl TypeInfo {
                 format!("contract caller {}", abi_name)
             }
             Array(elem_ty, count) => format!("[{}; {}]", elem_ty.json_abi_str(), count),
+            GenericArray(elem_ty, length) => {
+                format!("[{}; {}]", elem_ty.json_abi_str(), length.json_abi_str())
+            }
+            ConstGenericValue(value) => value.to_string(),
             Storage { .. } => "contract storage".into(),
+            Function {
+                parameters,
+                return_type,
+            } => {
+                let parameter_strs = parameters
+                    .iter()
+                    .map(|parameter| parameter.json_abi_str())
+                    .collect::<Vec<String>>();
+                format!(
+                    "fn({}) -> {}",
+                    parameter_strs.join(", "),
+                    return_type.json_abi_str()
+                )
+            }
         }
     }
 
@@ -380,6 +490,16 @@ impl TypeInfo {
                 }
                 .into()
             }
+            SignedInteger(bits) => {
+                use IntegerBits::*;
+                match bits {
+                    Eight => "i8",
+                    Sixteen => "i16",
+                    ThirtyTwo => "i32",
+                    SixtyFour => "i64",
+                }
+                .into()
+            }
             Boolean => "bool".into(),
 
             Tuple(fields) => {
@@ -470,8 +590,8 @@ impl TypeInfo {
             // Each char is a byte, so the size is the num of characters / 8
             // rounded up to the nearest word
             TypeInfo::Str(len) => Ok((len + 7) / 8),
-            // Since things are unpacked, all unsigned integers are 64 bits.....for now
-            TypeInfo::UnsignedInteger(_) | TypeInfo::Numeric => Ok(1),
+            // Since things are unpacked, all integers are 64 bits.....for now
+            TypeInfo::UnsignedInteger(_) | TypeInfo::SignedInteger(_) | TypeInfo::Numeric => Ok(1),
             TypeInfo::Boolean => Ok(1),
             TypeInfo::Tuple(fields) => Ok(fields
                 .iter()
@@ -510,11 +630,15 @@ impl TypeInfo {
             // calling methods
             TypeInfo::ContractCaller { .. } => Ok(0),
             TypeInfo::Contract => unreachable!("contract types are never instantiated"),
+            TypeInfo::ConstGenericValue(_) => {
+                unreachable!("const generic values are never instantiated")
+            }
             TypeInfo::ErrorRecovery => unreachable!(),
             TypeInfo::Unknown
             | TypeInfo::Custom { .. }
             | TypeInfo::SelfType
-            | TypeInfo::UnknownGeneric { .. } => Err(CompileError::UnableToInferGeneric {
+            | TypeInfo::UnknownGeneric { .. }
+            | TypeInfo::GenericArray(..) => Err(CompileError::UnableToInferGeneric {
                 ty: self.friendly_type_str(),
                 span: err_span.clone(),
             }),
@@ -523,6 +647,8 @@ impl TypeInfo {
                 Ok(look_up_type_id(*elem_ty).size_in_words(err_span)? * *count as u64)
             }
             TypeInfo::Storage { .. } => Ok(0),
+            // Function values are always inlined at their call sites and never stored.
+            TypeInfo::Function { .. } => Ok(0),
         }
     }
 
@@ -530,6 +656,7 @@ impl TypeInfo {
         match self {
             // Copy types.
             TypeInfo::UnsignedInteger(_)
+            | TypeInfo::SignedInteger(_)
             | TypeInfo::Numeric
             | TypeInfo::Boolean
             | TypeInfo::Byte => Ok(true),
@@ -703,6 +830,18 @@ impl TypeInfo {
             TypeInfo::Array(ary_ty_id, count) => look_up_type_id(*ary_ty_id)
                 .matches_type_parameter(mapping)
                 .map(|matching_id| insert_type(TypeInfo::Array(matching_id, *count))),
+            TypeInfo::GenericArray(ary_ty_id, length) => {
+                let new_ary_ty_id = look_up_type_id(*ary_ty_id).matches_type_parameter(mapping);
+                let new_length = look_up_type_id(*length).matches_type_parameter(mapping);
+                if new_ary_ty_id.is_none() && new_length.is_none() {
+                    None
+                } else {
+                    Some(insert_type(TypeInfo::GenericArray(
+                        new_ary_ty_id.unwrap_or(*ary_ty_id),
+                        new_length.unwrap_or(*length),
+                    )))
+                }
+            }
             TypeInfo::Tuple(fields) => {
                 let mut new_fields = Vec::new();
                 let mut index = 0;
@@ -739,9 +878,47 @@ impl TypeInfo {
                     Some(insert_type(TypeInfo::Tuple(new_fields)))
                 }
             }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => {
+                let mut changed = false;
+                let new_parameters = parameters
+                    .iter()
+                    .map(|parameter| {
+                        match look_up_type_id(parameter.type_id).matches_type_parameter(mapping) {
+                            Some(new_parameter_id) => {
+                                changed = true;
+                                TypeArgument {
+                                    type_id: insert_type(TypeInfo::Ref(new_parameter_id)),
+                                    span: parameter.span.clone(),
+                                }
+                            }
+                            None => parameter.clone(),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let new_return_type =
+                    match look_up_type_id(*return_type).matches_type_parameter(mapping) {
+                        Some(new_return_type_id) => {
+                            changed = true;
+                            insert_type(TypeInfo::Ref(new_return_type_id))
+                        }
+                        None => *return_type,
+                    };
+                if changed {
+                    Some(insert_type(TypeInfo::Function {
+                        parameters: new_parameters,
+                        return_type: new_return_type,
+                    }))
+                } else {
+                    None
+                }
+            }
             Unknown
             | Str(..)
             | UnsignedInteger(..)
+            | SignedInteger(..)
             | Boolean
             | Ref(..)
             | ContractCaller { .. }
@@ -751,9 +928,61 @@ impl TypeInfo {
             | Numeric
             | Contract
             | Storage { .. }
+            | ConstGenericValue(..)
             | ErrorRecovery => None,
         }
     }
+
+    /// Given a `TypeInfo` which has yet to be resolved, e.g. the body of a type alias, replace
+    /// every occurrence of a type parameter in `mapping` with a reference to its type argument.
+    ///
+    /// Unlike [TypeInfo::matches_type_parameter], this also looks inside the type arguments of
+    /// `TypeInfo::Custom`, so `Option<T>` becomes `Option<u64>` when `T` maps to `u64`.
+    pub(crate) fn substitute_type_parameters(&self, mapping: &[(TypeParameter, TypeId)]) -> Self {
+        let substitute = |type_id: TypeId| {
+            insert_type(look_up_type_id(type_id).substitute_type_parameters(mapping))
+        };
+        let substitute_type_argument = |type_argument: &TypeArgument| TypeArgument {
+            type_id: substitute(type_argument.type_id),
+            span: type_argument.span.clone(),
+        };
+        match self {
+            TypeInfo::Custom {
+                name,
+                type_arguments,
+            } => {
+                if type_arguments.is_empty() {
+                    for (param, ty_id) in mapping.iter() {
+                        if param.name_ident == *name {
+                            return TypeInfo::Ref(*ty_id);
+                        }
+                    }
+                }
+                TypeInfo::Custom {
+                    name: name.clone(),
+                    type_arguments: type_arguments
+                        .iter()
+                        .map(substitute_type_argument)
+                        .collect(),
+                }
+            }
+            TypeInfo::Tuple(fields) => {
+                TypeInfo::Tuple(fields.iter().map(substitute_type_argument).collect())
+            }
+            TypeInfo::Array(elem_ty, count) => TypeInfo::Array(substitute(*elem_ty), *count),
+            TypeInfo::GenericArray(elem_ty, length) => {
+                TypeInfo::GenericArray(substitute(*elem_ty), substitute(*length))
+            }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => TypeInfo::Function {
+                parameters: parameters.iter().map(substitute_type_argument).collect(),
+                return_type: substitute(*return_type),
+            },
+            ty => ty.clone(),
+        }
+    }
 }
 
 fn print_inner_types(name: String, inner_types: impl Iterator<Item = TypeId>) -> String {
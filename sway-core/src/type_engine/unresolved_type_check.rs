@@ -43,7 +43,7 @@ impl UnresolvedTypeCheck for TypedAstNodeContent {
                 condition.append(&mut body);
                 condition
             }
-            SideEffect => vec![],
+            Break | Continue | SideEffect => vec![],
         }
     }
 }
@@ -188,8 +188,23 @@ impl UnresolvedTypeCheck for TypedExpression {
             }
             SizeOfValue { expr } => expr.check_for_unresolved_types(),
             AbiCast { address, .. } => address.check_for_unresolved_types(),
+            Closure { body, .. } => body.check_for_unresolved_types(),
+            ClosureCall { closure, arguments } => closure
+                .check_for_unresolved_types()
+                .into_iter()
+                .chain(
+                    arguments
+                        .iter()
+                        .flat_map(UnresolvedTypeCheck::check_for_unresolved_types),
+                )
+                .collect(),
             // storage access can never be generic
-            StorageAccess { .. } | Literal(_) | AbiName(_) | FunctionParameter => vec![],
+            StorageAccess { .. }
+            | GetStorageKey
+            | Literal(_)
+            | AbiName(_)
+            | FunctionParameter
+            | MutRef { .. } => vec![],
         }
     }
 }
@@ -237,12 +252,14 @@ impl UnresolvedTypeCheck for TypedDeclaration {
             Reassignment(TypedReassignment { rhs, .. }) => rhs.check_for_unresolved_types(),
             ErrorRecovery
             | StorageDeclaration(_)
+            | TypeAliasDeclaration(_)
             | TraitDeclaration(_)
             | StructDeclaration(_)
             | EnumDeclaration(_)
             | ImplTrait { .. }
             | AbiDeclaration(_)
-            | GenericTypeForFunctionScope { .. } => vec![],
+            | GenericTypeForFunctionScope { .. }
+            | ConstGenericForFunctionScope { .. } => vec![],
         }
     }
 }
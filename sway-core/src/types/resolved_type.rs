@@ -9,6 +9,7 @@ pub enum ResolvedType {
     /// The number in a `Str` represents its size, which must be known at compile time
     Str(u64),
     UnsignedInteger(IntegerBits),
+    SignedInteger(IntegerBits),
     Boolean,
     Unit,
     Byte,
@@ -53,10 +54,14 @@ impl ResolvedType {
                 | ResolvedType::Byte
                 | ResolvedType::Unit
                 | ResolvedType::UnsignedInteger(_)
+                | ResolvedType::SignedInteger(_)
         )
     }
 
     pub fn is_numeric(&self) -> bool {
-        matches!(self, ResolvedType::UnsignedInteger(_))
+        matches!(
+            self,
+            ResolvedType::UnsignedInteger(_) | ResolvedType::SignedInteger(_)
+        )
     }
 }
@@ -0,0 +1,16 @@
+.program:
+ji   i4
+noop
+DATA_SECTION_OFFSET[0..32]
+DATA_SECTION_OFFSET[32..64]
+lw   $ds $is 1
+add  $$ds $$ds $is
+lw   $r1 data_0               ; literal instantiation
+lw   $r0 data_1               ; literal instantiation
+add  $r1 $r1 $r0
+lw   $r0 data_1               ; literal instantiation
+mul  $r0 $r1 $r0
+ret  $r0
+.data:
+data_0 .u64 0x06
+data_1 .u64 0x07
@@ -0,0 +1,13 @@
+script;
+
+struct Coin {
+    value: u64,
+}
+
+impl Coin {
+    const ZERO: u64 = 0;
+}
+
+fn main() -> u64 {
+    Coin::ZERO
+}
@@ -0,0 +1,139 @@
+script;
+
+// These tests are compiled without the core library, so just enough of `core::ops` is declared
+// here for the operators to resolve.
+mod core {
+    pub mod ops {
+        pub trait Add {
+            fn add(self, other: Self) -> Self;
+        }
+
+        pub trait Eq {
+            fn eq(self, other: Self) -> bool;
+        }
+
+        pub trait Ord {
+            fn gt(self, other: Self) -> bool;
+            fn lt(self, other: Self) -> bool;
+        }
+
+        pub trait Shiftable {
+            fn lsh(self, other: u64) -> Self;
+            fn rsh(self, other: u64) -> Self;
+        }
+
+        impl Add for u64 {
+            fn add(self, other: Self) -> Self {
+                asm(r1: self, r2: other, r3) {
+                    add r3 r2 r1;
+                    r3: u64
+                }
+            }
+        }
+
+        impl Add for i64 {
+            fn add(self, other: Self) -> Self {
+                asm(r1: self, r2: other, r3) {
+                    add r3 r2 r1;
+                    r3: i64
+                }
+            }
+        }
+
+        impl Eq for u64 {
+            fn eq(self, other: Self) -> bool {
+                asm(r1: self, r2: other, r3) {
+                    eq r3 r1 r2;
+                    r3: bool
+                }
+            }
+        }
+
+        impl Eq for i64 {
+            fn eq(self, other: Self) -> bool {
+                asm(r1: self, r2: other, r3) {
+                    eq r3 r1 r2;
+                    r3: bool
+                }
+            }
+        }
+
+        impl Ord for u8 {
+            fn gt(self, other: Self) -> bool {
+                asm(r1: self, r2: other, r3) {
+                    gt r3 r1 r2;
+                    r3: bool
+                }
+            }
+            fn lt(self, other: Self) -> bool {
+                asm(r1: self, r2: other, r3) {
+                    lt r3 r1 r2;
+                    r3: bool
+                }
+            }
+        }
+
+        impl Shiftable for u64 {
+            fn lsh(self, other: u64) -> Self {
+                asm(r1: self, r2: other, r3) {
+                    sll r3 r1 r2;
+                    r3: u64
+                }
+            }
+            fn rsh(self, other: u64) -> Self {
+                asm(r1: self, r2: other, r3) {
+                    srl r3 r1 r2;
+                    r3: u64
+                }
+            }
+        }
+    }
+}
+
+use core::ops::Add;
+
+struct Wrapper {
+    value: u64,
+}
+
+impl Add for Wrapper {
+    fn add(self, other: Self) -> Self {
+        other
+    }
+}
+
+// The unsigned integer ops are lowered to instructions.
+fn add_shift(a: u64, b: u64) -> u64 {
+    ((a + b) << 2) >> 1
+}
+
+fn compare(a: u64, b: u64) -> bool {
+    a == b
+}
+
+fn compare_u8(c: u8, d: u8) -> bool {
+    c < d
+}
+
+// The signed integer arithmetic and user impls are still calls, but signed equality is the same
+// instruction.
+fn add_signed(a: i64, b: i64) -> i64 {
+    a + b
+}
+
+fn compare_signed(a: i64, b: i64) -> bool {
+    a == b
+}
+
+fn add_wrappers(x: Wrapper, y: Wrapper) -> Wrapper {
+    x + y
+}
+
+fn main() -> u64 {
+    compare(1, 2);
+    compare_u8(3, 4);
+    add_signed(5i64, 6i64);
+    compare_signed(5i64, 6i64);
+    add_wrappers(Wrapper { value: 7 }, Wrapper { value: 8 });
+    add_shift(1, 2)
+}
@@ -0,0 +1,23 @@
+library lib;
+
+pub fn forty_two() -> u64 {
+    42
+}
+
+fn private_fn() -> bool {
+    true
+}
+
+pub fn id<T>(x: T) -> T {
+    x
+}
+
+mod inner {
+    pub fn seven() -> u64 {
+        7
+    }
+
+    fn hidden() -> u64 {
+        8
+    }
+}
@@ -38,6 +38,8 @@ impl Formatter {
                     Abi(item_abi) => item_abi.format(self),
                     Const(item_const) => item_const.format(self),
                     Storage(item_storage) => item_storage.format(self),
+                    Mod(item_mod) => item_mod.format(self),
+                    TypeAlias(item_type_alias) => item_type_alias.format(self),
                 })
             })
             .collect::<Result<Vec<String>, _>>()?
@@ -3,7 +3,9 @@ mod item_const;
 mod item_enum;
 mod item_fn;
 mod item_impl;
+mod item_mod;
 mod item_storage;
 mod item_struct;
 mod item_trait;
+mod item_type_alias;
 mod item_use;
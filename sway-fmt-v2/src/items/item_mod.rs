@@ -0,0 +1,8 @@
+use crate::fmt::{Format, FormattedCode, Formatter};
+use sway_parse::ItemMod;
+
+impl Format for ItemMod {
+    fn format(&self, _formatter: &Formatter) -> FormattedCode {
+        todo!()
+    }
+}
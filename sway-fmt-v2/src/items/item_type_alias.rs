@@ -0,0 +1,8 @@
+use crate::fmt::{Format, FormattedCode, Formatter};
+use sway_parse::ItemTypeAlias;
+
+impl Format for ItemTypeAlias {
+    fn format(&self, _formatter: &Formatter) -> FormattedCode {
+        todo!()
+    }
+}
@@ -26,6 +26,9 @@ fn main() -> std::io::Result<()> {
         match pass.name.as_ref() {
             "inline" => perform_inline(&mut ir).map_err(&to_err)?,
             "constcombine" => perform_combine_constants(&mut ir).map_err(&to_err)?,
+            "dce" => perform_dce(&mut ir).map_err(&to_err)?,
+            "mem2reg" => perform_mem2reg(&mut ir).map_err(&to_err)?,
+            "simplifycfg" => perform_simplify_cfg(&mut ir).map_err(&to_err)?,
             _otherwise => unreachable!("Unknown pass name: {}", pass.name),
         };
     }
@@ -88,6 +91,45 @@ fn perform_combine_constants(ir: &mut Context) -> Result<bool, IrError> {
     Ok(modified)
 }
 
+// -------------------------------------------------------------------------------------------------
+
+fn perform_dce(ir: &mut Context) -> Result<bool, IrError> {
+    let funcs = ir.functions.iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+    let mut modified = false;
+    for idx in funcs {
+        if optimize::dce(ir, &Function(idx))? {
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn perform_mem2reg(ir: &mut Context) -> Result<bool, IrError> {
+    let funcs = ir.functions.iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+    let mut modified = false;
+    for idx in funcs {
+        if optimize::mem2reg(ir, &Function(idx))? {
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn perform_simplify_cfg(ir: &mut Context) -> Result<bool, IrError> {
+    let funcs = ir.functions.iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+    let mut modified = false;
+    for idx in funcs {
+        if optimize::simplify_cfg(ir, &Function(idx))? {
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
 // -------------------------------------------------------------------------------------------------
 // Using a bespoke CLI parser since the order in which passes are specified is important.
 
@@ -150,6 +192,9 @@ impl<I: Iterator<Item = String>> ConfigBuilder<I> {
 
                     "inline" => self.build_inline_pass(),
                     "constcombine" => self.build_const_combine_pass(),
+                    "dce" => self.build_dce_pass(),
+                    "mem2reg" => self.build_mem2reg_pass(),
+                    "simplifycfg" => self.build_simplify_cfg_pass(),
 
                     _otherwise => Err(format!("Unrecognised option '{}'.", opt)),
                 }
@@ -194,6 +239,30 @@ impl<I: Iterator<Item = String>> ConfigBuilder<I> {
         self.next = self.rest.next();
         self.build_root()
     }
+
+    fn build_dce_pass(mut self) -> Result<Config, String> {
+        // No args yet.  Eventually we should allow specifying which functions should have dead
+        // code removed.
+        self.cfg.passes.push("dce".into());
+        self.next = self.rest.next();
+        self.build_root()
+    }
+
+    fn build_mem2reg_pass(mut self) -> Result<Config, String> {
+        // No args yet.  Eventually we should allow specifying which functions should have their
+        // locals promoted.
+        self.cfg.passes.push("mem2reg".into());
+        self.next = self.rest.next();
+        self.build_root()
+    }
+
+    fn build_simplify_cfg_pass(mut self) -> Result<Config, String> {
+        // No args yet.  Eventually we should allow specifying which functions should have their
+        // control flow simplified.
+        self.cfg.passes.push("simplifycfg".into());
+        self.next = self.rest.next();
+        self.build_root()
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
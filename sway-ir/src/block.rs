@@ -135,6 +135,34 @@ impl Block {
         }
     }
 
+    /// Remove the entry for `from_block` from the phi instruction, if there is one.
+    ///
+    /// This is needed when `from_block` no longer branches to this block.
+    pub fn remove_phi_val_coming_from(&self, context: &mut Context, from_block: &Block) {
+        let phi_val = self.get_phi(context);
+        if let ValueDatum::Instruction(Instruction::Phi(ref mut pairs)) =
+            &mut context.values[phi_val.0].value
+        {
+            pairs.retain(|(block, _)| block != from_block);
+        } else {
+            unreachable!("Phi value must be a PHI instruction.");
+        }
+    }
+
+    /// Get the blocks which this block may branch to, according to its terminator.
+    pub fn successors(&self, context: &Context) -> Vec<Block> {
+        match self.get_term_inst(context) {
+            Some(Instruction::Branch(to_block)) => vec![*to_block],
+            Some(Instruction::ConditionalBranch {
+                true_block,
+                false_block,
+                ..
+            }) => vec![*true_block, *false_block],
+
+            _ => Vec::new(),
+        }
+    }
+
     /// Get a reference to the block terminator.
     ///
     /// Returns `None` if block is empty.
@@ -229,20 +257,7 @@ impl Block {
             //
             // Copying the candidate blocks and putting them in a vector to avoid borrowing context
             // as immutable and then mutable in the loop body.
-            for to_block in match new_block.get_term_inst(context) {
-                Some(Instruction::Branch(to_block)) => {
-                    vec![*to_block]
-                }
-                Some(Instruction::ConditionalBranch {
-                    true_block,
-                    false_block,
-                    ..
-                }) => {
-                    vec![*true_block, *false_block]
-                }
-
-                _ => Vec::new(),
-            } {
+            for to_block in new_block.successors(context) {
                 to_block.update_phi_source_block(context, *self, new_block);
             }
 
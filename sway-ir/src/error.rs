@@ -20,12 +20,16 @@ pub enum IrError {
     VerifyAccessValueInvalidIndices,
     VerifyAccessValueOnNonStruct,
     VerifyArgumentValueIsNotArgument(String),
+    VerifyBinaryOpBadTypes(String, String),
+    VerifyBinaryOpTypeMismatch(String, String),
+    VerifyBinaryOpUnknownTypes,
     VerifyBitcastUnknownSourceType,
     VerifyBitcastFromNonCopyType(String),
     VerifyBitcastToNonCopyType(String),
     VerifyBitcastBetweenInvalidTypes(String, String),
     VerifyBranchToMissingBlock(String),
     VerifyCallArgTypeMismatch(String),
+    VerifyCallPointerArgNotLocalPointer(String),
     VerifyCallToMissingFunction(String),
     VerifyCmpBadTypes(String, String),
     VerifyCmpTypeMismatch(String, String),
@@ -112,6 +116,18 @@ impl fmt::Display for IrError {
                 f,
                 "Verification failed: Argument specifier for function '{callee}' is not an argument value."
             ),
+            IrError::VerifyBinaryOpBadTypes(lhs_ty, rhs_ty) => write!(
+                f,
+                "Verification failed: Binary operator applied to non-integer types {lhs_ty} and {rhs_ty}."
+            ),
+            IrError::VerifyBinaryOpTypeMismatch(lhs_ty, rhs_ty) => write!(
+                f,
+                "Verification failed: Binary operator applied to different widths of {lhs_ty} and {rhs_ty}."
+            ),
+            IrError::VerifyBinaryOpUnknownTypes => write!(
+                f,
+                "Verification failed: Unable to determine type(s) of binary operator argument(s)."
+            ),
             IrError::VerifyBitcastUnknownSourceType => write!(
                 f,
                 "Verification failed: Bitcast unable to determine source type."
@@ -140,6 +156,13 @@ impl fmt::Display for IrError {
                     "Verification failed: Type mismatch found for call to '{callee}'."
                 )
             }
+            IrError::VerifyCallPointerArgNotLocalPointer(callee) => {
+                write!(
+                    f,
+                    "Verification failed: Pointer argument for call to '{callee}' must be a \
+                    pointer to a local value."
+                )
+            }
             IrError::VerifyCallToMissingFunction(callee) => {
                 write!(
                     f,
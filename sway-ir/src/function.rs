@@ -35,6 +35,7 @@ pub struct FunctionContent {
     pub selector: Option<[u8; 4]>,
 
     pub local_storage: BTreeMap<String, Pointer>, // BTree rather than Hash for deterministic ordering.
+    pub pointer_arguments: BTreeMap<String, Pointer>,
 
     next_label_idx: u64,
 }
@@ -47,19 +48,27 @@ impl Function {
     /// `name`, `args`, `return_type` and `is_public` are the usual suspects.  `selector` is a
     /// special value used for Sway contract calls; much like `name` is unique and not particularly
     /// used elsewhere in the IR.
+    ///
+    /// An arg flagged as a pointer arg is passed by reference: the caller passes a pointer to one
+    /// of its own locals, and within this function the arg is accessed like a local, via
+    /// [`Function::get_local_ptr`].
     pub fn new(
         context: &mut Context,
         module: Module,
         name: String,
-        args: Vec<(String, Type, Option<MetadataIndex>)>,
+        args: Vec<(String, Type, bool, Option<MetadataIndex>)>,
         return_type: Type,
         selector: Option<[u8; 4]>,
         is_public: bool,
     ) -> Function {
-        let arguments = args
-            .into_iter()
-            .map(|(name, ty, span_md_idx)| (name, Value::new_argument(context, ty, span_md_idx)))
-            .collect();
+        let mut arguments = Vec::with_capacity(args.len());
+        let mut pointer_arguments = BTreeMap::new();
+        for (name, ty, is_pointer, span_md_idx) in args {
+            if is_pointer {
+                pointer_arguments.insert(name.clone(), Pointer::new(context, ty, true, None));
+            }
+            arguments.push((name, Value::new_argument(context, ty, span_md_idx)));
+        }
         let content = FunctionContent {
             name,
             arguments,
@@ -68,6 +77,7 @@ impl Function {
             is_public,
             selector,
             local_storage: BTreeMap::new(),
+            pointer_arguments,
             next_label_idx: 0,
         };
         let func = Function(context.functions.insert(content));
@@ -145,6 +155,39 @@ impl Function {
             })
     }
 
+    /// Move a [`Block`] in this function to the end of its list of blocks.
+    pub fn move_block_to_end(&self, context: &mut Context, block: &Block) -> Result<(), IrError> {
+        let func = context.functions.get_mut(self.0).unwrap();
+        match func.blocks.iter().position(|b| b == block) {
+            None => Err(IrError::MissingBlock(block.get_label(context))),
+            Some(idx) => {
+                let block = func.blocks.remove(idx);
+                func.blocks.push(block);
+                Ok(())
+            }
+        }
+    }
+
+    /// Remove a [`Block`] from this function.
+    ///
+    /// The block and its instructions are removed from the context too, so the caller must make
+    /// sure nothing still refers to them, e.g., branches or phi entries in the remaining blocks.
+    pub fn remove_block(&self, context: &mut Context, block: &Block) -> Result<(), IrError> {
+        let func = context.functions.get_mut(self.0).unwrap();
+        match func.blocks.iter().position(|b| b == block) {
+            None => Err(IrError::MissingBlock(block.get_label(context))),
+            Some(idx) => {
+                func.blocks.remove(idx);
+                if let Some(content) = context.blocks.remove(block.0) {
+                    for ins in content.instructions {
+                        context.values.remove(ins.0);
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
     /// Get a new unique block label.
     ///
     /// If `hint` is `None` then the label will be in the form `"blockN"` where N is an
@@ -222,16 +265,29 @@ impl Function {
         context.functions[self.0].arguments.iter()
     }
 
-    /// Get a pointer to a local value by name, if found.
+    /// Get the pointer for a pointer arg by name, if found.
+    pub fn get_pointer_arg(&self, context: &Context, name: &str) -> Option<Pointer> {
+        context.functions[self.0]
+            .pointer_arguments
+            .get(name)
+            .copied()
+    }
+
+    /// Get a pointer to a local value by name, if found.  Pointer args are found too.
     pub fn get_local_ptr(&self, context: &Context, name: &str) -> Option<Pointer> {
-        context.functions[self.0].local_storage.get(name).copied()
+        let func = &context.functions[self.0];
+        func.local_storage
+            .get(name)
+            .or_else(|| func.pointer_arguments.get(name))
+            .copied()
     }
 
-    /// Find the name of a local value by pointer.
+    /// Find the name of a local value or pointer arg by pointer.
     pub fn lookup_local_name<'a>(&self, context: &'a Context, ptr: &Pointer) -> Option<&'a String> {
-        context.functions[self.0]
-            .local_storage
+        let func = &context.functions[self.0];
+        func.local_storage
             .iter()
+            .chain(func.pointer_arguments.iter())
             .find_map(|(name, local_ptr)| if local_ptr == ptr { Some(name) } else { None })
     }
 
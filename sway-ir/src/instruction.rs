@@ -1,12 +1,12 @@
 //! Instructions for data manipulation, but mostly control flow.
 //!
 //! Since Sway abstracts most low level operations behind traits they are translated into function
-//! calls which contain ASM blocks.  Therefore _at this stage_ Sway-IR doesn't need low level
-//! operations such as binary arithmetic and logic operators.
+//! calls which contain ASM blocks.  Binary arithmetic and logic operators on primitive integers
+//! are the exception; they are lowered to [`Instruction::BinaryOp`] and [`Instruction::Cmp`] so
+//! that they're visible to optimizations such as constant folding.
 //!
-//! Unfortuntely, using opaque ASM blocks limits the effectiveness of certain optimizations and
-//! this should be addressed in the future, perhaps by using compiler intrinsic calls instead of
-//! the ASM blocks where possible.
+//! Using opaque ASM blocks for everything else still limits the effectiveness of certain
+//! optimizations.
 
 use sway_types::ident::Ident;
 
@@ -25,6 +25,12 @@ use crate::{
 pub enum Instruction {
     /// An opaque list of ASM instructions passed directly to codegen.
     AsmBlock(AsmBlock, Vec<AsmArg>),
+    /// A binary arithmetic or logic operation on two integers of the same type.
+    BinaryOp {
+        op: BinaryOpKind,
+        lhs: Value,
+        rhs: Value,
+    },
     /// Cast the type of a value without changing its actual content.
     BitCast(Value, Type),
     /// An unconditional jump.
@@ -108,7 +114,40 @@ pub enum Instruction {
 pub enum Predicate {
     /// Equivalence.
     Equal,
-    // More soon.  NotEqual, LessThan, LessThanOrEqual, GreaterThan, GreaterThanOrEqual.
+    /// Non-equivalence.
+    NotEqual,
+    /// Unsigned less than.
+    LessThan,
+    /// Unsigned greater than.
+    GreaterThan,
+    /// Unsigned less than or equal.
+    LessThanOrEqual,
+    /// Unsigned greater than or equal.
+    GreaterThanOrEqual,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum BinaryOpKind {
+    /// Addition, which traps on overflow.
+    Add,
+    /// Subtraction, which traps on underflow.
+    Sub,
+    /// Multiplication, which traps on overflow.
+    Mul,
+    /// Unsigned division.
+    Div,
+    /// Unsigned remainder.
+    Mod,
+    /// Bitwise and.
+    And,
+    /// Bitwise or.
+    Or,
+    /// Bitwise exclusive or.
+    Xor,
+    /// Logical shift left.
+    Lsh,
+    /// Logical shift right.
+    Rsh,
 }
 
 /// Special registers in the Fuel Virtual Machine.
@@ -152,6 +191,7 @@ impl Instruction {
     pub fn get_type(&self, context: &Context) -> Option<Type> {
         match self {
             Instruction::AsmBlock(asm_block, _) => asm_block.get_type(context),
+            Instruction::BinaryOp { lhs, .. } => lhs.get_type(context),
             Instruction::BitCast(_, ty) => Some(*ty),
             Instruction::Call(function, _) => Some(context.functions[function.0].return_type),
             Instruction::Cmp(..) => Some(Type::Bool),
@@ -223,6 +263,93 @@ impl Instruction {
         }
     }
 
+    /// Return every value referenced by this instruction's arguments, including pointer values
+    /// which are loaded from or stored to.
+    pub fn get_operands(&self) -> Vec<Value> {
+        match self {
+            Instruction::AsmBlock(_, args) => args
+                .iter()
+                .filter_map(|asm_arg| asm_arg.initializer)
+                .collect(),
+            Instruction::BinaryOp { lhs, rhs, .. } => vec![*lhs, *rhs],
+            Instruction::BitCast(value, _) => vec![*value],
+            Instruction::Branch(_) => Vec::new(),
+            Instruction::Call(_, args) => args.clone(),
+            Instruction::Cmp(_, lhs_val, rhs_val) => vec![*lhs_val, *rhs_val],
+            Instruction::ConditionalBranch { cond_value, .. } => vec![*cond_value],
+            Instruction::ContractCall {
+                params,
+                coins,
+                asset_id,
+                gas,
+                ..
+            } => vec![*params, *coins, *asset_id, *gas],
+            Instruction::GetPointer { .. } => Vec::new(),
+            Instruction::InsertElement {
+                array,
+                value,
+                index_val,
+                ..
+            } => vec![*array, *value, *index_val],
+            Instruction::InsertValue {
+                aggregate, value, ..
+            } => vec![*aggregate, *value],
+            Instruction::ExtractElement {
+                array, index_val, ..
+            } => vec![*array, *index_val],
+            Instruction::ExtractValue { aggregate, .. } => vec![*aggregate],
+            Instruction::Load(src_val) => vec![*src_val],
+            Instruction::Nop => Vec::new(),
+            Instruction::Phi(pairs) => pairs.iter().map(|(_, val)| *val).collect(),
+            Instruction::ReadRegister { .. } => Vec::new(),
+            Instruction::Ret(ret_val, _) => vec![*ret_val],
+            Instruction::StateLoadQuadWord { load_val, key } => vec![*load_val, *key],
+            Instruction::StateLoadWord(key) => vec![*key],
+            Instruction::StateStoreQuadWord { stored_val, key } => vec![*stored_val, *key],
+            Instruction::StateStoreWord { stored_val, key } => vec![*stored_val, *key],
+            Instruction::Store {
+                dst_val,
+                stored_val,
+            } => vec![*dst_val, *stored_val],
+        }
+    }
+
+    /// Whether this instruction does anything other than produce its value, i.e., whether it
+    /// must be kept even when its value is unused.
+    ///
+    /// ASM blocks are opaque and calls may modify their pointer arguments, so they're assumed to
+    /// have side effects.  Storage reads are kept along with storage writes.
+    pub fn may_have_side_effect(&self) -> bool {
+        match self {
+            Instruction::AsmBlock(..)
+            | Instruction::Call(..)
+            | Instruction::ContractCall { .. }
+            | Instruction::StateLoadQuadWord { .. }
+            | Instruction::StateLoadWord(_)
+            | Instruction::StateStoreQuadWord { .. }
+            | Instruction::StateStoreWord { .. }
+            | Instruction::Store { .. } => true,
+
+            // Terminators and phis give the block its structure.
+            Instruction::Branch(_)
+            | Instruction::ConditionalBranch { .. }
+            | Instruction::Ret(..)
+            | Instruction::Phi(_) => true,
+
+            Instruction::BinaryOp { .. }
+            | Instruction::BitCast(..)
+            | Instruction::Cmp(..)
+            | Instruction::ExtractElement { .. }
+            | Instruction::ExtractValue { .. }
+            | Instruction::GetPointer { .. }
+            | Instruction::InsertElement { .. }
+            | Instruction::InsertValue { .. }
+            | Instruction::Load(_)
+            | Instruction::Nop
+            | Instruction::ReadRegister(_) => false,
+        }
+    }
+
     /// Replace `old_val` with `new_val` if it is referenced by this instruction's arguments.
     pub fn replace_value(&mut self, old_val: Value, new_val: Value) {
         let replace = |val: &mut Value| {
@@ -237,6 +364,10 @@ impl Instruction {
                     .iter_mut()
                     .for_each(|init_val| replace(init_val))
             }),
+            Instruction::BinaryOp { lhs, rhs, .. } => {
+                replace(lhs);
+                replace(rhs);
+            }
             Instruction::BitCast(value, _) => replace(value),
             Instruction::Branch(_) => (),
             Instruction::Call(_, args) => args.iter_mut().for_each(replace),
@@ -392,6 +523,24 @@ impl<'a> InstructionInserter<'a> {
         asm_val
     }
 
+    pub fn binary_op(
+        self,
+        op: BinaryOpKind,
+        lhs: Value,
+        rhs: Value,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Value {
+        let binary_op_val = Value::new_instruction(
+            self.context,
+            Instruction::BinaryOp { op, lhs, rhs },
+            span_md_idx,
+        );
+        self.context.blocks[self.block.0]
+            .instructions
+            .push(binary_op_val);
+        binary_op_val
+    }
+
     pub fn bitcast(self, value: Value, ty: Type, span_md_idx: Option<MetadataIndex>) -> Value {
         let bitcast_val =
             Value::new_instruction(self.context, Instruction::BitCast(value, ty), span_md_idx);
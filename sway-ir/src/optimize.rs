@@ -17,3 +17,9 @@ pub mod inline;
 pub use inline::*;
 pub mod constants;
 pub use constants::*;
+pub mod dce;
+pub use dce::*;
+pub mod mem2reg;
+pub use mem2reg::*;
+pub mod simplify_cfg;
+pub use simplify_cfg::*;
@@ -3,13 +3,15 @@
 //! - combining - compile time evaluation of constant expressions.
 //!   - combine insert_values - reduce expressions which insert a constant value into a constant
 //!     struct.
+//!   - combine cmps - reduce comparisons between constants to a constant bool.
+//!   - combine binary ops - reduce arithmetic and logic between constants to a constant integer.
 
 use crate::{
     constant::{Constant, ConstantValue},
     context::Context,
     error::IrError,
     function::Function,
-    instruction::Instruction,
+    instruction::{BinaryOpKind, Instruction, Predicate},
     value::{Value, ValueContent, ValueDatum},
 };
 
@@ -22,6 +24,16 @@ pub fn combine_constants(context: &mut Context, function: &Function) -> Result<b
             continue;
         }
 
+        if combine_const_cmps(context, function) {
+            modified = true;
+            continue;
+        }
+
+        if combine_const_binary_ops(context, function) {
+            modified = true;
+            continue;
+        }
+
         // Other passes here... always continue to the top if pass returns true.
         break;
     }
@@ -81,6 +93,136 @@ fn combine_const_insert_values(context: &mut Context, function: &Function) -> bo
     false
 }
 
+fn combine_const_cmps(context: &mut Context, function: &Function) -> bool {
+    // Find a candidate `cmp` instruction with two constant operands.
+    let candidate = function
+        .instruction_iter(context)
+        .find_map(|(block, ins_val)| match &context.values[ins_val.0].value {
+            ValueDatum::Instruction(Instruction::Cmp(pred, lhs_val, rhs_val)) => {
+                match (
+                    &context.values[lhs_val.0].value,
+                    &context.values[rhs_val.0].value,
+                ) {
+                    (
+                        ValueDatum::Constant(Constant {
+                            value: ConstantValue::Uint(lhs),
+                            ..
+                        }),
+                        ValueDatum::Constant(Constant {
+                            value: ConstantValue::Uint(rhs),
+                            ..
+                        }),
+                    ) => Some((block, ins_val, compare(pred, lhs, rhs))),
+                    (
+                        ValueDatum::Constant(Constant {
+                            value: ConstantValue::Bool(lhs),
+                            ..
+                        }),
+                        ValueDatum::Constant(Constant {
+                            value: ConstantValue::Bool(rhs),
+                            ..
+                        }),
+                    ) => Some((block, ins_val, compare(pred, lhs, rhs))),
+                    _otherwise => None,
+                }
+            }
+            _otherwise => None,
+        });
+
+    if let Some((block, ins_val, result)) = candidate {
+        // Replace uses of the `cmp` with the constant result and then remove it.
+        let span_md_idx = context.values[ins_val.0].span_md_idx;
+        let result_val = Value::new_constant(context, Constant::new_bool(result), span_md_idx);
+        function.replace_value(context, ins_val, result_val, None);
+        block.remove_instruction(context, ins_val);
+        return true;
+    }
+
+    false
+}
+
+fn compare<T: Ord>(pred: &Predicate, lhs: T, rhs: T) -> bool {
+    match pred {
+        Predicate::Equal => lhs == rhs,
+        Predicate::NotEqual => lhs != rhs,
+        Predicate::LessThan => lhs < rhs,
+        Predicate::GreaterThan => lhs > rhs,
+        Predicate::LessThanOrEqual => lhs <= rhs,
+        Predicate::GreaterThanOrEqual => lhs >= rhs,
+    }
+}
+
+fn combine_const_binary_ops(context: &mut Context, function: &Function) -> bool {
+    // Find a candidate binary op instruction with two constant operands which can be evaluated.
+    let candidate = function
+        .instruction_iter(context)
+        .find_map(|(block, ins_val)| match &context.values[ins_val.0].value {
+            ValueDatum::Instruction(Instruction::BinaryOp { op, lhs, rhs }) => {
+                match (&context.values[lhs.0].value, &context.values[rhs.0].value) {
+                    (
+                        ValueDatum::Constant(Constant {
+                            ty,
+                            value: ConstantValue::Uint(lhs),
+                        }),
+                        ValueDatum::Constant(Constant {
+                            value: ConstantValue::Uint(rhs),
+                            ..
+                        }),
+                    ) => evaluate_binary_op(op, *lhs, *rhs).map(|result| {
+                        (
+                            block,
+                            ins_val,
+                            Constant {
+                                ty: *ty,
+                                value: ConstantValue::Uint(result),
+                            },
+                        )
+                    }),
+                    _otherwise => None,
+                }
+            }
+            _otherwise => None,
+        });
+
+    if let Some((block, ins_val, result)) = candidate {
+        // Replace uses of the binary op with the constant result and then remove it.
+        let span_md_idx = context.values[ins_val.0].span_md_idx;
+        let result_val = Value::new_constant(context, result, span_md_idx);
+        function.replace_value(context, ins_val, result_val, None);
+        block.remove_instruction(context, ins_val);
+        return true;
+    }
+
+    false
+}
+
+/// Evaluate `op` the way the VM would, using full 64 bit registers.  Overflow and division by
+/// zero are left for the VM to report at run time, so they aren't evaluated.
+fn evaluate_binary_op(op: &BinaryOpKind, lhs: u64, rhs: u64) -> Option<u64> {
+    match op {
+        BinaryOpKind::Add => lhs.checked_add(rhs),
+        BinaryOpKind::Sub => lhs.checked_sub(rhs),
+        BinaryOpKind::Mul => lhs.checked_mul(rhs),
+        BinaryOpKind::Div => lhs.checked_div(rhs),
+        BinaryOpKind::Mod => lhs.checked_rem(rhs),
+        BinaryOpKind::And => Some(lhs & rhs),
+        BinaryOpKind::Or => Some(lhs | rhs),
+        BinaryOpKind::Xor => Some(lhs ^ rhs),
+        BinaryOpKind::Lsh => Some(
+            u32::try_from(rhs)
+                .ok()
+                .and_then(|rhs| lhs.checked_shl(rhs))
+                .unwrap_or(0),
+        ),
+        BinaryOpKind::Rsh => Some(
+            u32::try_from(rhs)
+                .ok()
+                .and_then(|rhs| lhs.checked_shr(rhs))
+                .unwrap_or(0),
+        ),
+    }
+}
+
 fn combine_const_aggregate_field(
     context: &mut Context,
     function: &Function,
@@ -0,0 +1,100 @@
+//! Dead code elimination.
+//!
+//! - unreachable blocks - remove blocks which can't be reached from the entry block.
+//! - dead instructions - remove instructions whose values are unused and which have no side
+//!   effects.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    block::Block,
+    context::Context,
+    error::IrError,
+    function::Function,
+    value::{Value, ValueDatum},
+};
+
+/// Remove unreachable blocks and dead instructions from `function`.
+pub fn dce(context: &mut Context, function: &Function) -> Result<bool, IrError> {
+    let removed_blocks = remove_unreachable_blocks(context, function)?;
+    let removed_instructions = remove_dead_instructions(context, function);
+    Ok(removed_blocks || removed_instructions)
+}
+
+/// Remove the blocks in `function` which can't be reached from its entry block.
+pub(crate) fn remove_unreachable_blocks(
+    context: &mut Context,
+    function: &Function,
+) -> Result<bool, IrError> {
+    // Walk the control flow graph from the entry block.
+    let mut reachable = HashSet::new();
+    let mut worklist = vec![function.get_entry_block(context)];
+    while let Some(block) = worklist.pop() {
+        if reachable.insert(block) {
+            worklist.append(&mut block.successors(context));
+        }
+    }
+
+    let unreachable = function
+        .block_iter(context)
+        .filter(|block| !reachable.contains(block))
+        .collect::<Vec<_>>();
+
+    // Reachable blocks may still have phi entries for unreachable predecessors.
+    for dead_block in &unreachable {
+        for succ in dead_block.successors(context) {
+            if reachable.contains(&succ) {
+                succ.remove_phi_val_coming_from(context, dead_block);
+            }
+        }
+    }
+    for dead_block in &unreachable {
+        function.remove_block(context, dead_block)?;
+    }
+
+    Ok(!unreachable.is_empty())
+}
+
+fn remove_dead_instructions(context: &mut Context, function: &Function) -> bool {
+    // Count the uses of each instruction value, and note where each instruction is so it can be
+    // removed.
+    let mut num_uses: HashMap<Value, usize> = HashMap::new();
+    let mut ins_blocks: HashMap<Value, Block> = HashMap::new();
+    for (block, ins_val) in function.instruction_iter(context) {
+        ins_blocks.insert(ins_val, block);
+        if let ValueDatum::Instruction(ins) = &context.values[ins_val.0].value {
+            for operand in ins.get_operands() {
+                *num_uses.entry(operand).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut worklist = ins_blocks
+        .keys()
+        .filter(|ins_val| !num_uses.contains_key(*ins_val))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let mut modified = false;
+    while let Some(ins_val) = worklist.pop() {
+        let operands = match &context.values[ins_val.0].value {
+            ValueDatum::Instruction(ins) if !ins.may_have_side_effect() => ins.get_operands(),
+            _otherwise => continue,
+        };
+
+        ins_blocks[&ins_val].remove_instruction(context, ins_val);
+        modified = true;
+
+        // The operands of the removed instruction may be dead now too.
+        for operand in operands {
+            if let Some(count) = num_uses.get_mut(&operand) {
+                *count -= 1;
+                if *count == 0 && ins_blocks.contains_key(&operand) {
+                    worklist.push(operand);
+                }
+            }
+        }
+    }
+
+    modified
+}
@@ -78,19 +78,32 @@ pub fn inline_function_call(
 
     // Take the locals from the inlined function and add them to this function.  `value_map` is a
     // map from the original local ptrs to the new ptrs.
-    let ptr_map = function.merge_locals_from(context, inlined_function)?;
+    let mut ptr_map = function.merge_locals_from(context, inlined_function)?;
     let mut value_map = HashMap::new();
 
     // Add the mapping from argument values in the inlined function to the args passed to the call.
+    // Pointer args are instead replaced by the local pointed to by the passed value.
     if let ValueDatum::Instruction(Instruction::Call(_, passed_vals)) =
         &context.values[call_site.0].value
     {
-        for (arg_val, passed_val) in context.functions[inlined_function.0]
-            .arguments
-            .iter()
-            .zip(passed_vals.iter())
+        let inlined_content = &context.functions[inlined_function.0];
+        for ((arg_name, arg_val), passed_val) in
+            inlined_content.arguments.iter().zip(passed_vals.iter())
         {
-            value_map.insert(arg_val.1, *passed_val);
+            match (
+                inlined_content.pointer_arguments.get(arg_name),
+                &context.values[passed_val.0].value,
+            ) {
+                (
+                    Some(arg_ptr),
+                    ValueDatum::Instruction(Instruction::GetPointer { base_ptr, .. }),
+                ) => {
+                    ptr_map.insert(*arg_ptr, *base_ptr);
+                }
+                _otherwise => {
+                    value_map.insert(*arg_val, *passed_val);
+                }
+            }
         }
     }
 
@@ -212,6 +225,11 @@ fn inline_instruction(
                     .ins(context)
                     .asm_block_from_asm(asm, new_args, span_md_idx)
             }
+            Instruction::BinaryOp { op, lhs, rhs } => {
+                new_block
+                    .ins(context)
+                    .binary_op(op, map_value(lhs), map_value(rhs), span_md_idx)
+            }
             Instruction::BitCast(value, ty) => {
                 new_block
                     .ins(context)
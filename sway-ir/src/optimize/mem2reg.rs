@@ -0,0 +1,401 @@
+//! Promotion of function locals to SSA values.
+//!
+//! Locals are lowered to pointers which are read and written with `load` and `store`.  When a
+//! local has a copy type and its pointer never escapes, i.e., it is only ever loaded from or
+//! stored to directly, then its loads can be replaced with the values last stored to it, merging
+//! them with phi instructions where control flow joins.  The local is then removed altogether.
+//!
+//! Since each [`Block`] has a single phi instruction, a local which needs a phi in a block whose
+//! phi is already in use is left alone.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    block::Block,
+    context::Context,
+    error::IrError,
+    function::Function,
+    instruction::Instruction,
+    pointer::Pointer,
+    value::{Value, ValueDatum},
+};
+
+/// Promote the eligible locals in `function` to SSA values.
+pub fn mem2reg(context: &mut Context, function: &Function) -> Result<bool, IrError> {
+    // Promotion only ever adds phi entries and removes instructions, so the control flow graph
+    // stays the same throughout.
+    let cfg = ControlFlowGraph::new(context, function);
+
+    let candidates = function
+        .locals_iter(context)
+        .filter(|(_, ptr)| ptr.get_type(context).is_copy_type())
+        .map(|(name, ptr)| (name.clone(), *ptr))
+        .collect::<Vec<_>>();
+
+    let mut modified = false;
+    for (name, ptr) in candidates {
+        if promote_local(context, function, &cfg, &name, ptr)? {
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
+fn promote_local(
+    context: &mut Context,
+    function: &Function,
+    cfg: &ControlFlowGraph,
+    name: &str,
+    ptr: Pointer,
+) -> Result<bool, IrError> {
+    let ptr_ty = *ptr.get_type(context);
+
+    // Find every `get_ptr` to the local.  They must all be to the whole local.
+    let mut get_ptrs = HashSet::new();
+    let mut accesses = Vec::new();
+    for (block, ins_val) in function.instruction_iter(context) {
+        if let ValueDatum::Instruction(Instruction::GetPointer {
+            base_ptr,
+            ptr_ty: get_ptr_ty,
+            offset,
+        }) = &context.values[ins_val.0].value
+        {
+            if *base_ptr == ptr {
+                if *offset != 0 || !get_ptr_ty.eq(context, &ptr_ty) {
+                    return Ok(false);
+                }
+                get_ptrs.insert(ins_val);
+                accesses.push((block, ins_val));
+            }
+        }
+    }
+
+    // Every use of those pointers must be a plain `load` or `store`, otherwise the local escapes.
+    let mut def_blocks = HashSet::new();
+    let mut upward_exposed_blocks = HashSet::new();
+    for (block, ins_val) in function.instruction_iter(context) {
+        let ins = match &context.values[ins_val.0].value {
+            ValueDatum::Instruction(ins) => ins,
+            _otherwise => continue,
+        };
+        match ins {
+            Instruction::Load(src_val) if get_ptrs.contains(src_val) => {
+                if !def_blocks.contains(&block) {
+                    upward_exposed_blocks.insert(block);
+                }
+            }
+            Instruction::Store {
+                dst_val,
+                stored_val,
+            } if get_ptrs.contains(dst_val) && !get_ptrs.contains(stored_val) => {
+                def_blocks.insert(block);
+            }
+            _otherwise => {
+                if ins
+                    .get_operands()
+                    .iter()
+                    .any(|operand| get_ptrs.contains(operand))
+                {
+                    return Ok(false);
+                }
+                continue;
+            }
+        }
+        if !cfg.is_reachable(&block) {
+            return Ok(false);
+        }
+        accesses.push((block, ins_val));
+    }
+
+    // The local is live on entry to a block if it may be loaded before it is next stored to.
+    let mut live_in_blocks = upward_exposed_blocks.clone();
+    let mut worklist = upward_exposed_blocks.into_iter().collect::<Vec<_>>();
+    while let Some(block) = worklist.pop() {
+        for pred in cfg.predecessors(&block) {
+            if !def_blocks.contains(pred) && live_in_blocks.insert(*pred) {
+                worklist.push(*pred);
+            }
+        }
+    }
+
+    // A load which may happen before any store would read uninitialized memory.
+    let entry_block = function.get_entry_block(context);
+    let entry_value = match &context.pointers[ptr.0].initializer {
+        Some(initializer) => Some(initializer.clone()),
+        None if live_in_blocks.contains(&entry_block) => return Ok(false),
+        None => None,
+    };
+
+    // Place phis in the iterated dominance frontier of the stores, wherever the local is live.
+    let mut phi_blocks = HashSet::new();
+    let mut worklist = def_blocks.iter().copied().collect::<Vec<_>>();
+    while let Some(block) = worklist.pop() {
+        for frontier_block in cfg.dominance_frontier(&block) {
+            if phi_blocks.insert(*frontier_block) {
+                worklist.push(*frontier_block);
+            }
+        }
+    }
+    phi_blocks.retain(|block| live_in_blocks.contains(block));
+
+    for phi_block in &phi_blocks {
+        if *phi_block == entry_block || !phi_is_unused(context, phi_block) {
+            return Ok(false);
+        }
+
+        // The phi value is set by its predecessors just before they branch.  A conditional
+        // branch sets it for both destinations, so the other destination mustn't be able to
+        // still use the old value.
+        for pred in cfg.predecessors(phi_block) {
+            if matches!(
+                pred.get_term_inst(context),
+                Some(Instruction::ConditionalBranch { .. })
+            ) && cfg
+                .successors(pred)
+                .iter()
+                .any(|succ| succ != phi_block && cfg.dominates(phi_block, succ))
+            {
+                return Ok(false);
+            }
+        }
+    }
+
+    // Walk the dominator tree to find the value of the local at each load and at the end of each
+    // block which leads to a phi.
+    let entry_value = entry_value.map(|constant| Value::new_constant(context, constant, None));
+    let mut load_values = HashMap::new();
+    let mut phi_entries = Vec::new();
+    let mut worklist = vec![(entry_block, entry_value)];
+    while let Some((block, mut current_value)) = worklist.pop() {
+        if phi_blocks.contains(&block) {
+            current_value = Some(block.get_phi(context));
+        }
+        for ins_val in block.instruction_iter(context) {
+            match &context.values[ins_val.0].value {
+                ValueDatum::Instruction(Instruction::Load(src_val))
+                    if get_ptrs.contains(src_val) =>
+                {
+                    match current_value {
+                        Some(value) => load_values.insert(ins_val, value),
+                        None => return Ok(false),
+                    };
+                }
+                ValueDatum::Instruction(Instruction::Store {
+                    dst_val,
+                    stored_val,
+                }) if get_ptrs.contains(dst_val) => current_value = Some(*stored_val),
+                _otherwise => (),
+            }
+        }
+        for succ in cfg.successors(&block) {
+            if phi_blocks.contains(succ) {
+                match current_value {
+                    Some(value) => phi_entries.push((*succ, block, value)),
+                    None => return Ok(false),
+                }
+            }
+        }
+        for child in cfg.dominator_tree_children(&block) {
+            worklist.push((*child, current_value));
+        }
+    }
+
+    // Everything checks out, so the IR can now be updated.
+    for (phi_block, from_block, value) in phi_entries {
+        phi_block.add_phi(context, from_block, value);
+    }
+
+    // A stored value may itself be a load from the local, so follow those through.
+    let resolved_values = load_values
+        .keys()
+        .map(|load_val| {
+            let mut value = load_values[load_val];
+            while let Some(next_value) = load_values.get(&value) {
+                value = *next_value;
+            }
+            (*load_val, value)
+        })
+        .collect::<Vec<_>>();
+    for (load_val, value) in resolved_values {
+        function.replace_value(context, load_val, value, None);
+    }
+
+    for (block, ins_val) in accesses {
+        block.remove_instruction(context, ins_val);
+    }
+    context.functions[function.0].local_storage.remove(name);
+    context.pointers.remove(ptr.0);
+
+    Ok(true)
+}
+
+fn phi_is_unused(context: &Context, block: &Block) -> bool {
+    match &context.values[block.get_phi(context).0].value {
+        ValueDatum::Instruction(Instruction::Phi(pairs)) => pairs.is_empty(),
+        _otherwise => unreachable!("Phi value must be a PHI instruction."),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The reachable blocks of a function and their dominance relationships.
+pub(crate) struct ControlFlowGraph {
+    /// Reachable blocks in reverse post-order, so the entry block is first.
+    blocks: Vec<Block>,
+    rpo_indices: HashMap<Block, usize>,
+    successors: HashMap<Block, Vec<Block>>,
+    predecessors: HashMap<Block, Vec<Block>>,
+    idoms: HashMap<Block, Block>,
+    dom_children: HashMap<Block, Vec<Block>>,
+    dom_frontiers: HashMap<Block, HashSet<Block>>,
+}
+
+impl ControlFlowGraph {
+    pub(crate) fn new(context: &Context, function: &Function) -> Self {
+        let entry_block = function.get_entry_block(context);
+
+        // Each block's distinct successors.  A conditional branch may have the same destination
+        // twice.
+        let block_successors = |block: &Block| {
+            let mut succs = block.successors(context);
+            succs.dedup();
+            succs
+        };
+
+        // Depth first search for the post-order.
+        let mut successors = HashMap::new();
+        let mut post_order = Vec::new();
+        let mut stack = vec![(entry_block, 0)];
+        successors.insert(entry_block, block_successors(&entry_block));
+        while let Some((block, succ_idx)) = stack.pop() {
+            match successors[&block].get(succ_idx).copied() {
+                Some(succ) => {
+                    stack.push((block, succ_idx + 1));
+                    if !successors.contains_key(&succ) {
+                        successors.insert(succ, block_successors(&succ));
+                        stack.push((succ, 0));
+                    }
+                }
+                None => post_order.push(block),
+            }
+        }
+        let blocks = post_order.into_iter().rev().collect::<Vec<_>>();
+        let rpo_indices = blocks
+            .iter()
+            .enumerate()
+            .map(|(idx, block)| (*block, idx))
+            .collect::<HashMap<_, _>>();
+
+        let mut predecessors: HashMap<Block, Vec<Block>> = HashMap::new();
+        for block in &blocks {
+            for succ in &successors[block] {
+                predecessors.entry(*succ).or_default().push(*block);
+            }
+        }
+
+        // Immediate dominators, using 'A Simple, Fast Dominance Algorithm' by Cooper, Harvey and
+        // Kennedy.
+        let mut idoms = HashMap::new();
+        idoms.insert(entry_block, entry_block);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for block in blocks.iter().skip(1) {
+                let mut new_idom = None;
+                for pred in &predecessors[block] {
+                    if idoms.contains_key(pred) {
+                        new_idom = Some(match new_idom {
+                            None => *pred,
+                            Some(idom) => intersect(&idoms, &rpo_indices, *pred, idom),
+                        });
+                    }
+                }
+                let new_idom = new_idom.expect("Reachable blocks have a processed predecessor.");
+                if idoms.insert(*block, new_idom) != Some(new_idom) {
+                    changed = true;
+                }
+            }
+        }
+
+        let mut dom_children: HashMap<Block, Vec<Block>> = HashMap::new();
+        for block in blocks.iter().skip(1) {
+            dom_children.entry(idoms[block]).or_default().push(*block);
+        }
+
+        let mut dom_frontiers: HashMap<Block, HashSet<Block>> = HashMap::new();
+        for block in &blocks {
+            let preds = predecessors.get(block).map_or(&[][..], Vec::as_slice);
+            if preds.len() > 1 {
+                for pred in preds {
+                    let mut runner = *pred;
+                    while runner != idoms[block] {
+                        dom_frontiers.entry(runner).or_default().insert(*block);
+                        runner = idoms[&runner];
+                    }
+                }
+            }
+        }
+
+        ControlFlowGraph {
+            blocks,
+            rpo_indices,
+            successors,
+            predecessors,
+            idoms,
+            dom_children,
+            dom_frontiers,
+        }
+    }
+
+    fn is_reachable(&self, block: &Block) -> bool {
+        self.rpo_indices.contains_key(block)
+    }
+
+    fn successors(&self, block: &Block) -> &[Block] {
+        self.successors.get(block).map_or(&[][..], Vec::as_slice)
+    }
+
+    pub(crate) fn predecessors(&self, block: &Block) -> &[Block] {
+        self.predecessors.get(block).map_or(&[][..], Vec::as_slice)
+    }
+
+    fn dominator_tree_children(&self, block: &Block) -> &[Block] {
+        self.dom_children.get(block).map_or(&[][..], Vec::as_slice)
+    }
+
+    fn dominance_frontier(&self, block: &Block) -> impl Iterator<Item = &Block> {
+        self.dom_frontiers.get(block).into_iter().flatten()
+    }
+
+    /// Whether every path from the entry block to `block` goes through `dominator`.
+    pub(crate) fn dominates(&self, dominator: &Block, block: &Block) -> bool {
+        let entry_block = self.blocks[0];
+        let mut runner = *block;
+        loop {
+            if runner == *dominator {
+                return true;
+            }
+            if runner == entry_block {
+                return false;
+            }
+            runner = self.idoms[&runner];
+        }
+    }
+}
+
+fn intersect(
+    idoms: &HashMap<Block, Block>,
+    rpo_indices: &HashMap<Block, usize>,
+    mut lhs: Block,
+    mut rhs: Block,
+) -> Block {
+    while lhs != rhs {
+        while rpo_indices[&lhs] > rpo_indices[&rhs] {
+            lhs = idoms[&lhs];
+        }
+        while rpo_indices[&rhs] > rpo_indices[&lhs] {
+            rhs = idoms[&rhs];
+        }
+    }
+    lhs
+}
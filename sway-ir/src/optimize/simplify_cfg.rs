@@ -0,0 +1,220 @@
+//! Control flow graph simplification.
+//!
+//! - fold branches - replace conditional branches on a constant, or to the same block either way,
+//!   with unconditional branches, and then remove the blocks which are no longer reachable.
+//! - remove trampolines - remove empty blocks which just branch to another block by retargeting
+//!   their predecessors.
+//! - merge blocks - append a block to its only predecessor when that predecessor branches nowhere
+//!   else.
+
+use crate::{
+    block::Block,
+    constant::{Constant, ConstantValue},
+    context::Context,
+    error::IrError,
+    function::Function,
+    instruction::Instruction,
+    optimize::{dce::remove_unreachable_blocks, mem2reg::ControlFlowGraph},
+    value::ValueDatum,
+};
+
+/// Fold constant branches, remove trampoline blocks and merge straight-line blocks in `function`.
+pub fn simplify_cfg(context: &mut Context, function: &Function) -> Result<bool, IrError> {
+    // Unreachable blocks count as predecessors below, so they need to go first.
+    let mut modified = remove_unreachable_blocks(context, function)?;
+    loop {
+        if fold_cond_branches(context, function) {
+            remove_unreachable_blocks(context, function)?;
+            modified = true;
+            continue;
+        }
+
+        if remove_trampolines(context, function)? {
+            modified = true;
+            continue;
+        }
+
+        if merge_blocks(context, function)? {
+            modified = true;
+            continue;
+        }
+
+        // Other passes here... always continue to the top if pass returns true.
+        break;
+    }
+    Ok(modified)
+}
+
+fn fold_cond_branches(context: &mut Context, function: &Function) -> bool {
+    // Find a candidate `cbr` which always goes to the same block, and the block it no longer goes
+    // to, if any.
+    let candidate = function.block_iter(context).find_map(|block| {
+        let (cond_value, true_block, false_block) = match block.get_term_inst(context) {
+            Some(Instruction::ConditionalBranch {
+                cond_value,
+                true_block,
+                false_block,
+            }) => (*cond_value, *true_block, *false_block),
+            _otherwise => return None,
+        };
+        if true_block == false_block {
+            return Some((block, true_block, None));
+        }
+        match &context.values[cond_value.0].value {
+            ValueDatum::Constant(Constant {
+                value: ConstantValue::Bool(true),
+                ..
+            }) => Some((block, true_block, Some(false_block))),
+            ValueDatum::Constant(Constant {
+                value: ConstantValue::Bool(false),
+                ..
+            }) => Some((block, false_block, Some(true_block))),
+            _otherwise => None,
+        }
+    });
+
+    if let Some((block, taken_block, not_taken_block)) = candidate {
+        if let Some(not_taken_block) = not_taken_block {
+            not_taken_block.remove_phi_val_coming_from(context, &block);
+        }
+
+        // Replace the `cbr` in place, so its metadata is kept.
+        let term_val = *context.blocks[block.0].instructions.last().unwrap();
+        context.values[term_val.0].value =
+            ValueDatum::Instruction(Instruction::Branch(taken_block));
+        return true;
+    }
+
+    false
+}
+
+fn remove_trampolines(context: &mut Context, function: &Function) -> Result<bool, IrError> {
+    // Find a candidate block which has an empty phi and just a `br`.
+    let entry_block = function.get_entry_block(context);
+    let cfg = ControlFlowGraph::new(context, function);
+    let candidate = function.block_iter(context).find_map(|block| {
+        if block == entry_block || block.num_instructions(context) != 1 {
+            return None;
+        }
+        let to_block = match block.get_term_inst(context) {
+            Some(Instruction::Branch(to_block)) if *to_block != block => *to_block,
+            _otherwise => return None,
+        };
+        if !matches!(
+            &context.values[block.get_phi(context).0].value,
+            ValueDatum::Instruction(Instruction::Phi(pairs)) if pairs.is_empty()
+        ) {
+            return None;
+        }
+
+        // A predecessor which already branches to `to_block` would need two phi entries there.
+        let preds = cfg.predecessors(&block).to_vec();
+        if preds.is_empty()
+            || preds
+                .iter()
+                .any(|pred| pred.successors(context).contains(&to_block))
+        {
+            return None;
+        }
+
+        // A `cbr` sets the phi values for both of its destinations before it branches, so if it
+        // went straight to `to_block` it would clobber the phi value on its other path too.  That
+        // only matters if the phi value may still be live there, i.e., in a loop where `to_block`
+        // dominates the `cbr`.
+        let phi_val = to_block.get_phi_val_coming_from(context, &block);
+        if phi_val.is_some()
+            && preds.iter().any(|pred| {
+                matches!(
+                    pred.get_term_inst(context),
+                    Some(Instruction::ConditionalBranch { .. })
+                ) && cfg.dominates(&to_block, pred)
+            })
+        {
+            return None;
+        }
+
+        Some((block, to_block, preds, phi_val))
+    });
+
+    if let Some((block, to_block, preds, phi_val)) = candidate {
+        for pred in &preds {
+            retarget_branch(context, pred, block, to_block);
+        }
+
+        // The value which came from the trampoline now comes from each of its predecessors.
+        if let Some(phi_val) = phi_val {
+            to_block.remove_phi_val_coming_from(context, &block);
+            for pred in preds {
+                to_block.add_phi(context, pred, phi_val);
+            }
+        }
+
+        function.remove_block(context, &block)?;
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+fn merge_blocks(context: &mut Context, function: &Function) -> Result<bool, IrError> {
+    // Find a candidate block which is the only successor of its only predecessor.
+    let entry_block = function.get_entry_block(context);
+    let candidate = function.block_iter(context).find_map(|block| {
+        let to_block = match block.get_term_inst(context) {
+            Some(Instruction::Branch(to_block)) => *to_block,
+            _otherwise => return None,
+        };
+        if to_block != block && to_block != entry_block && to_block.num_predecessors(context) == 1 {
+            Some((block, to_block))
+        } else {
+            None
+        }
+    });
+
+    if let Some((block, to_block)) = candidate {
+        // The phi in `to_block` can only have a value from `block`, so use that directly.
+        if let Some(phi_val) = to_block.get_phi_val_coming_from(context, &block) {
+            let to_block_phi = to_block.get_phi(context);
+            function.replace_value(context, to_block_phi, phi_val, None);
+        }
+
+        // Replace the `br` with everything from `to_block` except its phi.
+        let br_val = context.blocks[block.0].instructions.pop().unwrap();
+        context.values.remove(br_val.0);
+        let mut to_block_instructions = context.blocks[to_block.0].instructions.split_off(1);
+        context.blocks[block.0]
+            .instructions
+            .append(&mut to_block_instructions);
+
+        // The successors of `to_block` are now the successors of `block`.
+        for succ in block.successors(context) {
+            succ.update_phi_source_block(context, to_block, block);
+        }
+
+        function.remove_block(context, &to_block)?;
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+fn retarget_branch(context: &mut Context, block: &Block, old_dest: Block, new_dest: Block) {
+    let retarget = |dest: &mut Block| {
+        if *dest == old_dest {
+            *dest = new_dest;
+        }
+    };
+    let term_val = *context.blocks[block.0].instructions.last().unwrap();
+    match &mut context.values[term_val.0].value {
+        ValueDatum::Instruction(Instruction::Branch(to_block)) => retarget(to_block),
+        ValueDatum::Instruction(Instruction::ConditionalBranch {
+            true_block,
+            false_block,
+            ..
+        }) => {
+            retarget(true_block);
+            retarget(false_block);
+        }
+        _otherwise => (),
+    }
+}
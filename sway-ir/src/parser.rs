@@ -34,6 +34,12 @@ mod ir_builder {
                 / _ s:contract() eoi() {
                     s
                 }
+                / _ s:predicate() eoi() {
+                    s
+                }
+                / _ s:library() eoi() {
+                    s
+                }
 
             rule script() -> IrAstModule
                 = "script" _ "{" _ fn_decls:fn_decl()* "}" _ metadata:metadata_decl()* {
@@ -53,8 +59,26 @@ mod ir_builder {
                     }
                 }
 
+            rule predicate() -> IrAstModule
+                = "predicate" _ "{" _ fn_decls:fn_decl()* "}" _ metadata:metadata_decl()* {
+                    IrAstModule {
+                        kind: crate::module::Kind::Predicate,
+                        fn_decls,
+                        metadata
+                    }
+                }
+
+            rule library() -> IrAstModule
+                = "library" _ "{" _ fn_decls:fn_decl()* "}" _ metadata:metadata_decl()* {
+                    IrAstModule {
+                        kind: crate::module::Kind::Library,
+                        fn_decls,
+                        metadata
+                    }
+                }
+
             rule fn_decl() -> IrAstFnDecl
-                = "fn" _ name:id() _ selector:selector_id()? _ "(" _
+                = is_public:is_public() "fn" _ name:id() _ selector:selector_id()? _ "(" _
                       args:(fn_arg() ** comma()) ")" _ "->" _ ret_type:ast_ty() "{" _
                       locals:fn_local()*
                       blocks:block_decl()*
@@ -65,18 +89,24 @@ mod ir_builder {
                         ret_type,
                         locals,
                         blocks,
-                        selector
+                        selector,
+                        is_public,
                     }
                 }
 
+            rule is_public() -> bool
+                = p:("pub" _)? {
+                    p.is_some()
+                }
+
             rule selector_id() -> [u8; 4]
                 = "<" _ s:$(['0'..='9' | 'a'..='f' | 'A'..='F']*<8>) _ ">" _ {
                     string_to_hex::<4>(s)
                 }
 
-            rule fn_arg() -> (IrAstTy, String, Option<MdIdxRef>)
-                = name:id() mdi:metadata_idx()? ":" _ ty:ast_ty() {
-                    (ty, name, mdi)
+            rule fn_arg() -> (IrAstTy, String, bool, Option<MdIdxRef>)
+                = name:id() mdi:metadata_idx()? ":" _ p:("mut" _ ptr())? ty:ast_ty() {
+                    (ty, name, p.is_some(), mdi)
                 }
 
             rule fn_local() -> (IrAstTy, String, bool, Option<IrAstOperation>)
@@ -123,6 +153,7 @@ mod ir_builder {
 
             rule operation() -> IrAstOperation
                 = op_asm()
+                / op_binary_op()
                 / op_branch()
                 / op_bitcast()
                 / op_call()
@@ -159,6 +190,11 @@ mod ir_builder {
                     )
                 }
 
+            rule op_binary_op() -> IrAstOperation
+                = op:binary_op_kind() l:id() comma() r:id() {
+                    IrAstOperation::BinaryOp(op, l, r)
+                }
+
             rule op_bitcast() -> IrAstOperation
                 = "bitcast" _ val:id() "to" _ ty:ast_ty() {
                     IrAstOperation::BitCast(val, ty)
@@ -272,8 +308,13 @@ mod ir_builder {
                     IrAstOperation::Store(val, dst)
                 }
 
+            rule binary_op_kind() -> String
+                = op:$("add" / "sub" / "mul" / "div" / "mod" / "and" / "or" / "xor" / "lsh" / "rsh") _ {
+                    op.to_string()
+                }
+
             rule cmp_pred() -> String
-                = p:$("eq") _ {
+                = p:$("eq" / "ne" / "lt" / "gt" / "le" / "ge") _ {
                     p.to_string()
                 }
 
@@ -485,7 +526,7 @@ mod ir_builder {
         context::Context,
         error::IrError,
         function::Function,
-        instruction::{Instruction, Predicate, Register},
+        instruction::{BinaryOpKind, Instruction, Predicate, Register},
         irtype::{Aggregate, Type},
         metadata::{MetadataIndex, Metadatum},
         module::{Kind, Module},
@@ -503,11 +544,12 @@ mod ir_builder {
     #[derive(Debug)]
     struct IrAstFnDecl {
         name: String,
-        args: Vec<(IrAstTy, String, Option<MdIdxRef>)>,
+        args: Vec<(IrAstTy, String, bool, Option<MdIdxRef>)>,
         ret_type: IrAstTy,
         locals: Vec<(IrAstTy, String, bool, Option<IrAstOperation>)>,
         blocks: Vec<IrAstBlock>,
         selector: Option<[u8; 4]>,
+        is_public: bool,
     }
 
     #[derive(Debug)]
@@ -532,6 +574,7 @@ mod ir_builder {
             Vec<IrAstAsmOp>,
             Option<MdIdxRef>,
         ),
+        BinaryOp(String, String, String),
         BitCast(String, IrAstTy),
         Br(String),
         Call(String, Vec<String>),
@@ -715,13 +758,14 @@ mod ir_builder {
         md_map: &HashMap<MdIdxRef, MetadataIndex>,
         unresolved_calls: &mut Vec<(Block, Value, String, Vec<Value>, Option<MetadataIndex>)>,
     ) -> Result<(), IrError> {
-        let args: Vec<(String, Type, Option<MetadataIndex>)> = fn_decl
+        let args: Vec<(String, Type, bool, Option<MetadataIndex>)> = fn_decl
             .args
             .iter()
-            .map(|(ty, name, md_idx)| {
+            .map(|(ty, name, is_pointer, md_idx)| {
                 (
                     name.into(),
                     ty.to_ir_type(context),
+                    *is_pointer,
                     md_idx.map(|mdi| md_map.get(&mdi).copied().unwrap()),
                 )
             })
@@ -734,16 +778,19 @@ mod ir_builder {
             args.clone(),
             ret_type,
             fn_decl.selector,
-            false,
+            fn_decl.is_public,
         );
 
         // Gather all the (new) arg values by name into a map.
         let mut arg_map: HashMap<String, Value> =
-            HashMap::from_iter(args.into_iter().map(|(name, _, _)| {
-                let arg_val = func.get_arg(context, &name).unwrap();
-                (name, arg_val)
+            HashMap::from_iter(args.iter().map(|(name, _, _, _)| {
+                let arg_val = func.get_arg(context, name).unwrap();
+                (name.clone(), arg_val)
             }));
-        let mut ptr_map = HashMap::<String, Pointer>::new();
+        // Pointer args are referred to by name just like locals.
+        let mut ptr_map = HashMap::<String, Pointer>::from_iter(args.into_iter().filter_map(
+            |(name, _, _, _)| func.get_pointer_arg(context, &name).map(|ptr| (name, ptr)),
+        ));
         for (ty, name, is_mutable, initializer) in fn_decl.locals {
             let initializer = initializer.map(|const_init| {
                 if let IrAstOperation::Const(val) = const_init {
@@ -837,6 +884,24 @@ mod ir_builder {
                         .ins(context)
                         .asm_block(args, body, return_type, return_name, md_idx)
                 }
+                IrAstOperation::BinaryOp(op_str, lhs, rhs) => block.ins(context).binary_op(
+                    match op_str.as_str() {
+                        "add" => BinaryOpKind::Add,
+                        "sub" => BinaryOpKind::Sub,
+                        "mul" => BinaryOpKind::Mul,
+                        "div" => BinaryOpKind::Div,
+                        "mod" => BinaryOpKind::Mod,
+                        "and" => BinaryOpKind::And,
+                        "or" => BinaryOpKind::Or,
+                        "xor" => BinaryOpKind::Xor,
+                        "lsh" => BinaryOpKind::Lsh,
+                        "rsh" => BinaryOpKind::Rsh,
+                        _ => unreachable!("Bug in binary op kind rule."),
+                    },
+                    *val_map.get(&lhs).unwrap(),
+                    *val_map.get(&rhs).unwrap(),
+                    opt_ins_md_idx,
+                ),
                 IrAstOperation::BitCast(val, ty) => {
                     let to_ty = ty.to_ir_type(context);
                     block
@@ -876,6 +941,11 @@ mod ir_builder {
                 IrAstOperation::Cmp(pred_str, lhs, rhs) => block.ins(context).cmp(
                     match pred_str.as_str() {
                         "eq" => Predicate::Equal,
+                        "ne" => Predicate::NotEqual,
+                        "lt" => Predicate::LessThan,
+                        "gt" => Predicate::GreaterThan,
+                        "le" => Predicate::LessThanOrEqual,
+                        "ge" => Predicate::GreaterThanOrEqual,
                         _ => unreachable!("Bug in `cmp` predicate rule."),
                     },
                     *val_map.get(&lhs).unwrap(),
@@ -12,7 +12,7 @@ use crate::{
     constant::{Constant, ConstantValue},
     context::Context,
     function::{Function, FunctionContent},
-    instruction::{Instruction, Predicate, Register},
+    instruction::{BinaryOpKind, Instruction, Predicate, Register},
     irtype::Type,
     metadata::{MetadataIndex, Metadatum},
     module::{Kind, ModuleContent},
@@ -100,7 +100,7 @@ fn module_to_doc<'a>(
         match module.kind {
             Kind::Contract => "contract",
             Kind::Library => "library",
-            Kind::Predicate => "predicate ",
+            Kind::Predicate => "predicate",
             Kind::Script => "script",
         }
     )))
@@ -157,8 +157,13 @@ fn function_to_doc<'a>(
                         _ => unreachable!("Unexpected non argument value for function arguments."),
                     };
                     Doc::text(format!(
-                        "{name}{}: {}",
+                        "{name}{}: {}{}",
                         md_namer.meta_as_string(context, span_md_idx, false),
+                        if function.pointer_arguments.contains_key(name) {
+                            "mut ptr "
+                        } else {
+                            ""
+                        },
                         ty.as_string(context),
                     ))
                 })
@@ -295,6 +300,29 @@ fn instruction_to_doc<'a>(
             Instruction::AsmBlock(asm, args) => {
                 asm_block_to_doc(context, md_namer, namer, ins_value, asm, args, span_md_idx)
             }
+            Instruction::BinaryOp { op, lhs, rhs } => {
+                let op_str = match op {
+                    BinaryOpKind::Add => "add",
+                    BinaryOpKind::Sub => "sub",
+                    BinaryOpKind::Mul => "mul",
+                    BinaryOpKind::Div => "div",
+                    BinaryOpKind::Mod => "mod",
+                    BinaryOpKind::And => "and",
+                    BinaryOpKind::Or => "or",
+                    BinaryOpKind::Xor => "xor",
+                    BinaryOpKind::Lsh => "lsh",
+                    BinaryOpKind::Rsh => "rsh",
+                };
+                maybe_constant_to_doc(context, md_namer, namer, lhs)
+                    .append(maybe_constant_to_doc(context, md_namer, namer, rhs))
+                    .append(Doc::text_line(format!(
+                        "{} = {op_str} {}, {}{}",
+                        namer.name(context, ins_value),
+                        namer.name(context, lhs),
+                        namer.name(context, rhs),
+                        md_namer.meta_as_string(context, span_md_idx, true)
+                    )))
+            }
             Instruction::BitCast(value, ty) => maybe_constant_to_doc(
                 context, md_namer, namer, value,
             )
@@ -337,6 +365,11 @@ fn instruction_to_doc<'a>(
             Instruction::Cmp(pred, lhs_value, rhs_value) => {
                 let pred_str = match pred {
                     Predicate::Equal => "eq",
+                    Predicate::NotEqual => "ne",
+                    Predicate::LessThan => "lt",
+                    Predicate::GreaterThan => "gt",
+                    Predicate::LessThanOrEqual => "le",
+                    Predicate::GreaterThanOrEqual => "ge",
                 };
                 maybe_constant_to_doc(context, md_namer, namer, lhs_value)
                     .append(maybe_constant_to_doc(context, md_namer, namer, rhs_value))
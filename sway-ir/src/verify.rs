@@ -95,6 +95,7 @@ impl<'a> InstructionVerifier<'a> {
             if let ValueDatum::Instruction(instruction) = instruction {
                 match instruction {
                     Instruction::AsmBlock(..) => (),
+                    Instruction::BinaryOp { lhs, rhs, .. } => self.verify_binary_op(lhs, rhs)?,
                     Instruction::BitCast(value, ty) => self.verify_bitcast(value, ty)?,
                     Instruction::Branch(block) => self.verify_br(block)?,
                     Instruction::Call(func, args) => self.verify_call(func, args)?,
@@ -170,6 +171,29 @@ impl<'a> InstructionVerifier<'a> {
         Ok(())
     }
 
+    fn verify_binary_op(&self, lhs: &Value, rhs: &Value) -> Result<(), IrError> {
+        // Binary operators only apply to integers of the same width.
+        match (lhs.get_type(self.context), rhs.get_type(self.context)) {
+            (Some(lhs_ty), Some(rhs_ty)) => match (lhs_ty, rhs_ty) {
+                (Type::Uint(lhs_nbits), Type::Uint(rhs_nbits)) => {
+                    if lhs_nbits != rhs_nbits {
+                        Err(IrError::VerifyBinaryOpTypeMismatch(
+                            lhs_ty.as_string(self.context),
+                            rhs_ty.as_string(self.context),
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                }
+                _otherwise => Err(IrError::VerifyBinaryOpBadTypes(
+                    lhs_ty.as_string(self.context),
+                    rhs_ty.as_string(self.context),
+                )),
+            },
+            _otherwise => Err(IrError::VerifyBinaryOpUnknownTypes),
+        }
+    }
+
     fn verify_bitcast(&self, value: &Value, ty: &Type) -> Result<(), IrError> {
         // The to and from types must be copy-types, excluding short strings, and the same size.
         let val_ty = match value.get_type(self.context) {
@@ -257,6 +281,25 @@ impl<'a> InstructionVerifier<'a> {
                 }
             }
 
+            // A pointer arg must be passed a pointer to the start of one of our locals, which is
+            // what it is replaced with when the callee is inlined.
+            for ((arg_name, _), passed_val) in callee_content.arguments.iter().zip(args.iter()) {
+                if callee_content.pointer_arguments.contains_key(arg_name) {
+                    match &self.context.values[passed_val.0].value {
+                        ValueDatum::Instruction(Instruction::GetPointer {
+                            base_ptr,
+                            offset: 0,
+                            ..
+                        }) if self.is_local_pointer(base_ptr) => (),
+                        _otherwise => {
+                            return Err(IrError::VerifyCallPointerArgNotLocalPointer(
+                                callee_content.name.clone(),
+                            ))
+                        }
+                    }
+                }
+            }
+
             Ok(())
         }
     }
@@ -284,11 +327,12 @@ impl<'a> InstructionVerifier<'a> {
 
     fn verify_cmp(
         &self,
-        _pred: &Predicate,
+        pred: &Predicate,
         lhs_value: &Value,
         rhs_value: &Value,
     ) -> Result<(), IrError> {
-        // Comparisons must be between integers at this stage.
+        // Comparisons must be between integers at this stage, although bools may be tested for
+        // equality.
         match (
             lhs_value.get_type(self.context),
             rhs_value.get_type(self.context),
@@ -304,6 +348,11 @@ impl<'a> InstructionVerifier<'a> {
                         Ok(())
                     }
                 }
+                (Type::Bool, Type::Bool)
+                    if matches!(pred, Predicate::Equal | Predicate::NotEqual) =>
+                {
+                    Ok(())
+                }
                 _otherwise => Err(IrError::VerifyCmpBadTypes(
                     lhs_ty.as_string(self.context),
                     rhs_ty.as_string(self.context),
@@ -599,6 +648,7 @@ impl<'a> InstructionVerifier<'a> {
         self.cur_function
             .local_storage
             .values()
+            .chain(self.cur_function.pointer_arguments.values())
             .any(|local_ptr| local_ptr == ptr)
     }
 
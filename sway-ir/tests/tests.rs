@@ -80,6 +80,45 @@ fn constants() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[allow(clippy::needless_collect)]
+#[test]
+fn dce() {
+    run_tests("dce", |ir: &mut sway_ir::Context| {
+        let fn_idcs: Vec<_> = ir.functions.iter().map(|func| func.0).collect();
+        fn_idcs
+            .into_iter()
+            .all(|fn_idx| sway_ir::optimize::dce(ir, &sway_ir::function::Function(fn_idx)).unwrap())
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[allow(clippy::needless_collect)]
+#[test]
+fn mem2reg() {
+    run_tests("mem2reg", |ir: &mut sway_ir::Context| {
+        let fn_idcs: Vec<_> = ir.functions.iter().map(|func| func.0).collect();
+        fn_idcs.into_iter().all(|fn_idx| {
+            sway_ir::optimize::mem2reg(ir, &sway_ir::function::Function(fn_idx)).unwrap()
+        })
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[allow(clippy::needless_collect)]
+#[test]
+fn simplify_cfg() {
+    run_tests("simplify_cfg", |ir: &mut sway_ir::Context| {
+        let fn_idcs: Vec<_> = ir.functions.iter().map(|func| func.0).collect();
+        fn_idcs.into_iter().all(|fn_idx| {
+            sway_ir::optimize::simplify_cfg(ir, &sway_ir::function::Function(fn_idx)).unwrap()
+        })
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[test]
 fn serialize() {
     // This isn't running a pass, it's just confirming that the IR can be loaded and printed, and
@@ -72,6 +72,94 @@ impl u8 {
     }
 }
 
+impl i64 {
+    /// The smallest value that can be represented by this integer type,
+    /// -2<sup>63</sup>.
+    pub fn min() -> i64 {
+        // Negative values are stored sign-extended to a full word, in two's complement.
+        asm(r1: 9223372036854775808) {
+            r1: i64
+        }
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>63</sup> - 1.
+    pub fn max() -> i64 {
+        9223372036854775807
+    }
+
+    /// The size of this integer type in bits.
+    pub fn bits() -> u32 {
+        64
+    }
+}
+
+impl i32 {
+    /// The smallest value that can be represented by this integer type,
+    /// -2<sup>31</sup>.
+    pub fn min() -> i32 {
+        // Negative values are stored sign-extended to a full word, in two's complement.
+        asm(r1: 18446744071562067968) {
+            r1: i32
+        }
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>31</sup> - 1.
+    pub fn max() -> i32 {
+        2147483647
+    }
+
+    /// The size of this integer type in bits.
+    pub fn bits() -> u32 {
+        32
+    }
+}
+
+impl i16 {
+    /// The smallest value that can be represented by this integer type,
+    /// -2<sup>15</sup>.
+    pub fn min() -> i16 {
+        // Negative values are stored sign-extended to a full word, in two's complement.
+        asm(r1: 18446744073709518848) {
+            r1: i16
+        }
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>15</sup> - 1.
+    pub fn max() -> i16 {
+        32767
+    }
+
+    /// The size of this integer type in bits.
+    pub fn bits() -> u32 {
+        16
+    }
+}
+
+impl i8 {
+    /// The smallest value that can be represented by this integer type,
+    /// -2<sup>7</sup>.
+    pub fn min() -> i8 {
+        // Negative values are stored sign-extended to a full word, in two's complement.
+        asm(r1: 18446744073709551488) {
+            r1: i8
+        }
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>7</sup> - 1.
+    pub fn max() -> i8 {
+        127
+    }
+
+    /// The size of this integer type in bits.
+    pub fn bits() -> u32 {
+        8
+    }
+}
+
 impl b256 {
     /// The smallest value that can be represented by this type.
     pub fn min() -> b256 {
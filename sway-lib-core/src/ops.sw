@@ -40,6 +40,30 @@ impl Add for u8 {
     }
 }
 
+impl Add for i64 {
+    fn add(self, other: Self) -> Self {
+        from_word_i64(add_signed(to_word_i64(self), to_word_i64(other), 64))
+    }
+}
+
+impl Add for i32 {
+    fn add(self, other: Self) -> Self {
+        from_word_i32(add_signed(to_word_i32(self), to_word_i32(other), 32))
+    }
+}
+
+impl Add for i16 {
+    fn add(self, other: Self) -> Self {
+        from_word_i16(add_signed(to_word_i16(self), to_word_i16(other), 16))
+    }
+}
+
+impl Add for i8 {
+    fn add(self, other: Self) -> Self {
+        from_word_i8(add_signed(to_word_i8(self), to_word_i8(other), 8))
+    }
+}
+
 pub trait Subtract {
     fn subtract(self, other: Self) -> Self;
 }
@@ -80,6 +104,30 @@ impl Subtract for u8 {
     }
 }
 
+impl Subtract for i64 {
+    fn subtract(self, other: Self) -> Self {
+        from_word_i64(subtract_signed(to_word_i64(self), to_word_i64(other), 64))
+    }
+}
+
+impl Subtract for i32 {
+    fn subtract(self, other: Self) -> Self {
+        from_word_i32(subtract_signed(to_word_i32(self), to_word_i32(other), 32))
+    }
+}
+
+impl Subtract for i16 {
+    fn subtract(self, other: Self) -> Self {
+        from_word_i16(subtract_signed(to_word_i16(self), to_word_i16(other), 16))
+    }
+}
+
+impl Subtract for i8 {
+    fn subtract(self, other: Self) -> Self {
+        from_word_i8(subtract_signed(to_word_i8(self), to_word_i8(other), 8))
+    }
+}
+
 pub trait Multiply {
     fn multiply(self, other: Self) -> Self;
 }
@@ -120,6 +168,30 @@ impl Multiply for u8 {
     }
 }
 
+impl Multiply for i64 {
+    fn multiply(self, other: Self) -> Self {
+        from_word_i64(multiply_signed(to_word_i64(self), to_word_i64(other), 64))
+    }
+}
+
+impl Multiply for i32 {
+    fn multiply(self, other: Self) -> Self {
+        from_word_i32(multiply_signed(to_word_i32(self), to_word_i32(other), 32))
+    }
+}
+
+impl Multiply for i16 {
+    fn multiply(self, other: Self) -> Self {
+        from_word_i16(multiply_signed(to_word_i16(self), to_word_i16(other), 16))
+    }
+}
+
+impl Multiply for i8 {
+    fn multiply(self, other: Self) -> Self {
+        from_word_i8(multiply_signed(to_word_i8(self), to_word_i8(other), 8))
+    }
+}
+
 pub trait Divide {
     fn divide(self, other: Self) -> Self;
 }
@@ -160,6 +232,30 @@ impl Divide for u8 {
     }
 }
 
+impl Divide for i64 {
+    fn divide(self, other: Self) -> Self {
+        from_word_i64(divide_signed(to_word_i64(self), to_word_i64(other), 64))
+    }
+}
+
+impl Divide for i32 {
+    fn divide(self, other: Self) -> Self {
+        from_word_i32(divide_signed(to_word_i32(self), to_word_i32(other), 32))
+    }
+}
+
+impl Divide for i16 {
+    fn divide(self, other: Self) -> Self {
+        from_word_i16(divide_signed(to_word_i16(self), to_word_i16(other), 16))
+    }
+}
+
+impl Divide for i8 {
+    fn divide(self, other: Self) -> Self {
+        from_word_i8(divide_signed(to_word_i8(self), to_word_i8(other), 8))
+    }
+}
+
 pub trait Mod {
     fn modulo(self, other: Self) -> Self;
 }
@@ -200,6 +296,30 @@ impl Mod for u8 {
     }
 }
 
+impl Mod for i64 {
+    fn modulo(self, other: Self) -> Self {
+        from_word_i64(modulo_signed(to_word_i64(self), to_word_i64(other)))
+    }
+}
+
+impl Mod for i32 {
+    fn modulo(self, other: Self) -> Self {
+        from_word_i32(modulo_signed(to_word_i32(self), to_word_i32(other)))
+    }
+}
+
+impl Mod for i16 {
+    fn modulo(self, other: Self) -> Self {
+        from_word_i16(modulo_signed(to_word_i16(self), to_word_i16(other)))
+    }
+}
+
+impl Mod for i8 {
+    fn modulo(self, other: Self) -> Self {
+        from_word_i8(modulo_signed(to_word_i8(self), to_word_i8(other)))
+    }
+}
+
 pub trait Shiftable {
     fn lsh(self, other: u64) -> Self;
     fn rsh(self, other: u64) -> Self;
@@ -265,6 +385,42 @@ impl Shiftable for u8 {
     }
 }
 
+impl Shiftable for i64 {
+    fn lsh(self, other: u64) -> Self {
+        from_word_i64(sign_extend(to_word_i64(self).lsh(other), 64))
+    }
+    fn rsh(self, other: u64) -> Self {
+        from_word_i64(rsh_signed(to_word_i64(self), other))
+    }
+}
+
+impl Shiftable for i32 {
+    fn lsh(self, other: u64) -> Self {
+        from_word_i32(sign_extend(to_word_i32(self).lsh(other), 32))
+    }
+    fn rsh(self, other: u64) -> Self {
+        from_word_i32(rsh_signed(to_word_i32(self), other))
+    }
+}
+
+impl Shiftable for i16 {
+    fn lsh(self, other: u64) -> Self {
+        from_word_i16(sign_extend(to_word_i16(self).lsh(other), 16))
+    }
+    fn rsh(self, other: u64) -> Self {
+        from_word_i16(rsh_signed(to_word_i16(self), other))
+    }
+}
+
+impl Shiftable for i8 {
+    fn lsh(self, other: u64) -> Self {
+        from_word_i8(sign_extend(to_word_i8(self).lsh(other), 8))
+    }
+    fn rsh(self, other: u64) -> Self {
+        from_word_i8(rsh_signed(to_word_i8(self), other))
+    }
+}
+
 pub trait Eq {
     fn eq(self, other: Self) -> bool;
 } {
@@ -273,7 +429,7 @@ pub trait Eq {
     }
 }
 
-trait OrdEq: Ord + Eq {
+pub trait OrdEq: Ord + Eq {
 } {
     fn ge(self, other: Self) -> bool {
         self.gt(other) || self.eq(other)
@@ -328,6 +484,30 @@ impl Eq for u8 {
     }
 }
 
+impl Eq for i64 {
+    fn eq(self, other: Self) -> bool {
+        to_word_i64(self).eq(to_word_i64(other))
+    }
+}
+
+impl Eq for i32 {
+    fn eq(self, other: Self) -> bool {
+        to_word_i32(self).eq(to_word_i32(other))
+    }
+}
+
+impl Eq for i16 {
+    fn eq(self, other: Self) -> bool {
+        to_word_i16(self).eq(to_word_i16(other))
+    }
+}
+
+impl Eq for i8 {
+    fn eq(self, other: Self) -> bool {
+        to_word_i8(self).eq(to_word_i8(other))
+    }
+}
+
 impl Eq for b256 {
     fn eq(self, other: Self) -> bool {
         // Both self and other are addresses of the values, so we can use MEQ.
@@ -404,6 +584,42 @@ impl Ord for u8 {
     }
 }
 
+impl Ord for i64 {
+    fn gt(self, other: Self) -> bool {
+        bias(to_word_i64(self)).gt(bias(to_word_i64(other)))
+    }
+    fn lt(self, other: Self) -> bool {
+        bias(to_word_i64(self)).lt(bias(to_word_i64(other)))
+    }
+}
+
+impl Ord for i32 {
+    fn gt(self, other: Self) -> bool {
+        bias(to_word_i32(self)).gt(bias(to_word_i32(other)))
+    }
+    fn lt(self, other: Self) -> bool {
+        bias(to_word_i32(self)).lt(bias(to_word_i32(other)))
+    }
+}
+
+impl Ord for i16 {
+    fn gt(self, other: Self) -> bool {
+        bias(to_word_i16(self)).gt(bias(to_word_i16(other)))
+    }
+    fn lt(self, other: Self) -> bool {
+        bias(to_word_i16(self)).lt(bias(to_word_i16(other)))
+    }
+}
+
+impl Ord for i8 {
+    fn gt(self, other: Self) -> bool {
+        bias(to_word_i8(self)).gt(bias(to_word_i8(other)))
+    }
+    fn lt(self, other: Self) -> bool {
+        bias(to_word_i8(self)).lt(bias(to_word_i8(other)))
+    }
+}
+
 impl Ord for b256 {
     fn gt(self, other: Self) -> bool {
         let (self_word_1, self_word_2, self_word_3, self_word_4) = decompose(self);
@@ -547,6 +763,14 @@ impl OrdEq for u16 {
 }
 impl OrdEq for u8 {
 }
+impl OrdEq for i64 {
+}
+impl OrdEq for i32 {
+}
+impl OrdEq for i16 {
+}
+impl OrdEq for i8 {
+}
 impl OrdEq for b256 {
 }
 
@@ -584,3 +808,223 @@ fn decompose(val: b256) -> (u64, u64, u64, u64) {
     let w4 = get_word_from_b256(val, 24);
     (w1, w2, w3, w4)
 }
+
+/////////////////////////////////////////////////
+// Signed Integer Helpers
+/////////////////////////////////////////////////
+
+// Signed integers of every width are held in a single word, in two's complement and sign-extended
+// to 64 bits. The helpers below work on those words without ever letting an intermediate result
+// wrap, so that overflow is detected explicitly and reverts, and so that they can be evaluated at
+// compile time.
+//
+// The compiler only lowers `==`, `!=`, `&`, `|` and `^` on signed integers to instructions, since
+// those don't depend on the sign. The VM has no signed arithmetic, ordering or shifts, so the
+// rest of the signed operators, and their overflow checks, are these library calls rather than
+// being lowered in the compiler.
+
+fn to_word_i64(value: i64) -> u64 {
+    asm(r1: value) {
+        r1: u64
+    }
+}
+
+fn from_word_i64(word: u64) -> i64 {
+    asm(r1: word) {
+        r1: i64
+    }
+}
+
+fn to_word_i32(value: i32) -> u64 {
+    asm(r1: value) {
+        r1: u64
+    }
+}
+
+fn from_word_i32(word: u64) -> i32 {
+    asm(r1: word) {
+        r1: i32
+    }
+}
+
+fn to_word_i16(value: i16) -> u64 {
+    asm(r1: value) {
+        r1: u64
+    }
+}
+
+fn from_word_i16(word: u64) -> i16 {
+    asm(r1: word) {
+        r1: i16
+    }
+}
+
+fn to_word_i8(value: i8) -> u64 {
+    asm(r1: value) {
+        r1: u64
+    }
+}
+
+fn from_word_i8(word: u64) -> i8 {
+    asm(r1: word) {
+        r1: i8
+    }
+}
+
+/// The sign bit of a word, 2<sup>63</sup>.
+fn sign_bit() -> u64 {
+    9223372036854775808
+}
+
+/// A word with every bit set.
+fn all_bits() -> u64 {
+    18446744073709551615
+}
+
+/// A word with only bit `index` set.
+fn bit(index: u64) -> u64 {
+    asm(r1: index, r2) {
+        sll r2 one r1;
+        r2: u64
+    }
+}
+
+fn is_negative(word: u64) -> bool {
+    word.binary_and(sign_bit()).neq(0)
+}
+
+/// Flip the sign bit, mapping the signed words onto `u64` in the same order.
+fn bias(word: u64) -> u64 {
+    word.binary_xor(sign_bit())
+}
+
+/// The two's complement negation of `word`, which must not be zero.
+fn negate(word: u64) -> u64 {
+    word.binary_xor(all_bits()).add(1)
+}
+
+/// The absolute value of a signed word, which is at most 2<sup>63</sup>.
+fn magnitude(word: u64) -> u64 {
+    if is_negative(word) {
+        negate(word)
+    } else {
+        word
+    }
+}
+
+/// Apply a sign to a magnitude, which must be representable.
+fn with_sign(magnitude: u64, negative: bool) -> u64 {
+    if negative && magnitude.neq(0) {
+        negate(magnitude)
+    } else {
+        magnitude
+    }
+}
+
+/// Sign-extend the low `bits` bits of `word` to the full word.
+fn sign_extend(word: u64, bits: u64) -> u64 {
+    if bits.eq(64) {
+        word
+    } else {
+        let mask = bit(bits).subtract(1);
+        let low = word.binary_and(mask);
+        if low.binary_and(bit(bits.subtract(1))).neq(0) {
+            low.binary_or(mask.binary_xor(all_bits()))
+        } else {
+            low
+        }
+    }
+}
+
+/// The largest magnitude a `bits` wide signed integer can hold, for a negative or a
+/// non-negative value.
+fn max_magnitude(bits: u64, negative: bool) -> u64 {
+    let half = bit(bits.subtract(1));
+    if negative {
+        half
+    } else {
+        half.subtract(1)
+    }
+}
+
+fn overflow() {
+    asm(r1: 0) {
+        rvrt r1;
+    }
+}
+
+/// Revert unless the sign-extended `word` fits in a `bits` wide signed integer.
+fn check_range(word: u64, bits: u64) -> u64 {
+    if magnitude(word).gt(max_magnitude(bits, is_negative(word))) {
+        overflow();
+    }
+    word
+}
+
+fn add_signed(a: u64, b: u64, bits: u64) -> u64 {
+    let biased = bias(a);
+    let sum = if is_negative(b) {
+        let difference = magnitude(b);
+        if biased.lt(difference) {
+            overflow();
+        }
+        biased.subtract(difference)
+    } else {
+        if biased.gt(all_bits().subtract(b)) {
+            overflow();
+        }
+        biased.add(b)
+    };
+    check_range(bias(sum), bits)
+}
+
+fn subtract_signed(a: u64, b: u64, bits: u64) -> u64 {
+    let biased = bias(a);
+    let difference = if is_negative(b) {
+        let sum = magnitude(b);
+        if biased.gt(all_bits().subtract(sum)) {
+            overflow();
+        }
+        biased.add(sum)
+    } else {
+        if biased.lt(b) {
+            overflow();
+        }
+        biased.subtract(b)
+    };
+    check_range(bias(difference), bits)
+}
+
+fn multiply_signed(a: u64, b: u64, bits: u64) -> u64 {
+    let negative = is_negative(a).neq(is_negative(b));
+    let a_magnitude = magnitude(a);
+    let b_magnitude = magnitude(b);
+    if a_magnitude.neq(0) && b_magnitude.gt(max_magnitude(bits, negative).divide(a_magnitude)) {
+        overflow();
+    }
+    with_sign(a_magnitude.multiply(b_magnitude), negative)
+}
+
+/// Division truncates towards zero.
+fn divide_signed(a: u64, b: u64, bits: u64) -> u64 {
+    let negative = is_negative(a).neq(is_negative(b));
+    let quotient = magnitude(a).divide(magnitude(b));
+    if quotient.gt(max_magnitude(bits, negative)) {
+        overflow();
+    }
+    with_sign(quotient, negative)
+}
+
+/// The remainder takes the sign of the dividend.
+fn modulo_signed(a: u64, b: u64) -> u64 {
+    with_sign(magnitude(a).modulo(magnitude(b)), is_negative(a))
+}
+
+/// Arithmetic shift right, filling with the sign bit.
+fn rsh_signed(word: u64, shift: u64) -> u64 {
+    if is_negative(word) {
+        word.binary_xor(all_bits()).rsh(shift).binary_xor(all_bits())
+    } else {
+        word.rsh(shift)
+    }
+}
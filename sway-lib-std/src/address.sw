@@ -1,6 +1,8 @@
 library address;
 //! A wrapper around the b256 type to help enhance type-safety.
 
+use ::convert::{From, Into};
+
 /// The Address type, a struct wrappper around the inner `value`.
 pub struct Address {
     value: b256,
@@ -16,19 +18,17 @@ impl core::ops::Eq for Address {
     }
 }
 
-pub trait From {
-    fn from(b: b256) -> Self;
-} {
-    fn into(addr: Address) -> b256 {
-        addr.value
-    }
-}
-
 /// Functions for casting between the b256 and Address types.
-impl From for Address {
+impl From<b256> for Address {
     fn from(bits: b256) -> Address {
         Address {
             value: bits,
         }
     }
 }
+
+impl Into<b256> for Address {
+    fn into(self) -> b256 {
+        self.value
+    }
+}
@@ -0,0 +1,33 @@
+//! Allocation of memory on the VM heap.
+library alloc;
+
+/// Allocates `size` bytes on the heap, returning the address of the first byte.
+///
+/// The heap grows downwards from the top of memory and `aloc` moves the `hp` register down by
+/// `size`, so the newly allocated memory starts just above `hp`.
+/// Heap memory is never freed during the execution of a transaction.
+pub fn alloc(size: u64) -> u64 {
+    asm(size: size, ptr) {
+        aloc size;
+        addi ptr hp i1;
+        ptr: u64
+    }
+}
+
+/// Reallocates the `size` bytes at `ptr` to hold `new_size` bytes, returning the new address.
+///
+/// When growing, a new area is allocated and the original contents are copied into it.
+/// Otherwise `ptr` is returned unchanged.
+pub fn realloc(ptr: u64, size: u64, new_size: u64) -> u64 {
+    if new_size > size {
+        let new_ptr = alloc(new_size);
+        if size > 0 {
+            asm(dst: new_ptr, src: ptr, size: size) {
+                mcp dst src size;
+            };
+        }
+        new_ptr
+    } else {
+        ptr
+    }
+}
@@ -1,6 +1,8 @@
 library b512;
 //! A wrapper around 2 b256 types to support the usage of 64-byte values in Sway, which are needed when working with public keys and signatures.
 
+use ::convert::{From, Into};
+
 /// Stores two b256s in contiguous memory.
 /// Guaranteed to be contiguous for use with ec-recover: std::ecr::ec_recover().
 pub struct B512 {
@@ -8,14 +10,6 @@ pub struct B512 {
     2],
 }
 
-// @todo use generic form when possible
-pub trait From {
-    fn from(h: b256, l: b256) -> Self;
-} {
-    // @todo add into() when tuples land, as it would probably return 2 b256 values
-    // fn into() {...}
-}
-
 impl core::ops::Eq for B512 {
     fn eq(self, other: Self) -> bool {
         (self.bytes)[0] == (other.bytes)[0] && (self.bytes)[1] == (other.bytes)[1]
@@ -23,15 +17,21 @@ impl core::ops::Eq for B512 {
 }
 
 /// Functions for casting between B512 and raw byte arrays.
-impl From for B512 {
-    fn from(h: b256, l: b256) -> B512 {
+impl From<(b256, b256)> for B512 {
+    fn from(components: (b256, b256)) -> B512 {
         B512 {
-            bytes: [h,
-            l], 
+            bytes: [components.0,
+            components.1], 
         }
     }
 }
 
+impl Into<(b256, b256)> for B512 {
+    fn into(self) -> (b256, b256) {
+        ((self.bytes)[0], (self.bytes)[1])
+    }
+}
+
 /// Methods on the B512 type
 impl B512 {
     /// Initializes a new, zeroed B512.
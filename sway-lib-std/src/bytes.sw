@@ -0,0 +1,128 @@
+//! A growable array of bytes, stored on the heap.
+library bytes;
+
+use ::alloc::{alloc, realloc};
+use ::intrinsics::str_len;
+use ::mem::{addr_of, copy, read, write};
+use ::option::Option;
+
+/// A contiguous, growable array of bytes, stored on the heap.
+///
+/// Like a `Vec`, a `Bytes` is a pointer to a header on the heap, which holds the address of the
+/// bytes, the number of bytes there is room for, and the number of bytes. Unlike a `Vec<u8>`,
+/// which stores each element in a whole word, the bytes are packed.
+pub struct Bytes {
+    header: u64,
+}
+
+impl Bytes {
+    /// Constructs a new, empty `Bytes`.
+    ///
+    /// No memory is allocated for the bytes until they are pushed.
+    fn new() -> Self {
+        let header = alloc(24);
+        write::<u64>(header, alloc(0));
+        write::<u64>(header + 8, 0);
+        write::<u64>(header + 16, 0);
+        Bytes {
+            header: header,
+        }
+    }
+
+    /// Constructs a new, empty `Bytes` with room for `capacity` bytes.
+    fn with_capacity(capacity: u64) -> Self {
+        let header = alloc(24);
+        write::<u64>(header, alloc(capacity));
+        write::<u64>(header + 8, capacity);
+        write::<u64>(header + 16, 0);
+        Bytes {
+            header: header,
+        }
+    }
+
+    /// Appends a byte to the end, reallocating if there is no room for it.
+    fn push(self, byte: u8) {
+        let len = self.len();
+        self.reserve(1);
+        asm(ptr: self.as_ptr() + len, byte: byte) {
+            sb ptr byte i0;
+        };
+        write::<u64>(self.header + 16, len + 1);
+    }
+
+    /// Appends a copy of the bytes in `other` to the end, reallocating if there is no room for
+    /// them.
+    fn append(self, other: Bytes) {
+        let len = self.len();
+        let other_len = other.len();
+        if other_len > 0 {
+            self.reserve(other_len);
+            copy(self.as_ptr() + len, other.as_ptr(), other_len);
+            write::<u64>(self.header + 16, len + other_len);
+        }
+    }
+
+    /// Returns the byte at `index`, or `None` if it is out of bounds.
+    fn get(self, index: u64) -> Option<u8> {
+        if index < self.len() {
+            Option::Some(asm(ptr: self.as_ptr() + index, byte) {
+                lb byte ptr i0;
+                byte: u8
+            })
+        } else {
+            Option::None
+        }
+    }
+
+    /// Returns the number of bytes.
+    fn len(self) -> u64 {
+        read::<u64>(self.header + 16)
+    }
+
+    /// Returns `true` if there are no bytes.
+    fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes there is room for without reallocating.
+    fn capacity(self) -> u64 {
+        read::<u64>(self.header + 8)
+    }
+
+    /// Removes all of the bytes, keeping the capacity.
+    fn clear(self) {
+        write::<u64>(self.header + 16, 0);
+    }
+
+    /// Returns the address of the first byte.
+    ///
+    /// The address changes whenever the bytes are reallocated to make room for more.
+    fn as_ptr(self) -> u64 {
+        read::<u64>(self.header)
+    }
+
+    /// Makes sure there is room for at least `additional` more bytes, reallocating if necessary.
+    fn reserve(self, additional: u64) {
+        let required = self.len() + additional;
+        let capacity = self.capacity();
+        if required > capacity {
+            let mut new_capacity = if capacity == 0 { 8 } else { capacity * 2 };
+            while new_capacity < required {
+                new_capacity = new_capacity * 2;
+            }
+            write::<u64>(self.header, realloc(self.as_ptr(), capacity, new_capacity));
+            write::<u64>(self.header + 8, new_capacity);
+        }
+    }
+}
+
+/// Returns a new `Bytes` holding a copy of the bytes of the string `s`.
+///
+/// Fails to compile if `S` isn't a `str[N]`.
+pub fn bytes_from_str<S>(s: S) -> Bytes {
+    let len = str_len::<S>();
+    let bytes = ~Bytes::with_capacity(len);
+    copy(bytes.as_ptr(), addr_of(s), len);
+    write::<u64>(bytes.header + 16, len);
+    bytes
+}
@@ -0,0 +1,14 @@
+library convert;
+//! Traits for conversions between types.
+
+/// Used to do value-to-value conversions.
+pub trait From<T> {
+    /// Converts to this type from the input type.
+    fn from(t: T) -> Self;
+}
+
+/// Used to do value-to-value conversions while consuming the input value. The opposite of `From`.
+pub trait Into<T> {
+    /// Converts this type into the (usually inferred) input type.
+    fn into(self) -> T;
+}
@@ -1,6 +1,8 @@
 library hash;
 
+use ::bytes::Bytes;
 use ::core::num::*;
+use ::string::String;
 
 // Should this be a trait eventually? Do we want to allow people to customize what `!` does?
 // Scala says yes, Rust says perhaps...
@@ -67,3 +69,31 @@ pub fn keccak256_with_offset<T>(param: T, offset: u8) -> b256 {
 pub fn keccak256<T>(param: T) -> b256 {
     keccak256_with_offset(param, 0)
 }
+
+/// Returns the SHA-2-256 hash of the contents of `bytes`, rather than of the `Bytes` value itself
+pub fn sha256_bytes(bytes: Bytes) -> b256 {
+    let mut result_buffer: b256 = ~b256::min();
+    asm(hash: result_buffer, ptr: bytes.as_ptr(), bytes: bytes.len()) {
+        s256 hash ptr bytes; // Hash the next "bytes" number of bytes starting from "ptr" into "hash"
+        hash: b256 // Return
+    }
+}
+
+/// Returns the SHA-2-256 hash of the contents of `string`
+pub fn sha256_string(string: String) -> b256 {
+    sha256_bytes(string.as_bytes())
+}
+
+/// Returns the KECCAK-256 hash of the contents of `bytes`, rather than of the `Bytes` value itself
+pub fn keccak256_bytes(bytes: Bytes) -> b256 {
+    let mut result_buffer: b256 = ~b256::min();
+    asm(hash: result_buffer, ptr: bytes.as_ptr(), bytes: bytes.len()) {
+        k256 hash ptr bytes; // Hash the next "bytes" number of bytes starting from "ptr" into "hash"
+        hash: b256 // Return
+    }
+}
+
+/// Returns the KECCAK-256 hash of the contents of `string`
+pub fn keccak256_string(string: String) -> b256 {
+    keccak256_bytes(string.as_bytes())
+}
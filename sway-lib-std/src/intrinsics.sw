@@ -10,3 +10,9 @@ pub fn is_reference_type<T>() -> bool {
 pub fn size_of<T>() -> u64 {
     __size_of::<T>()
 }
+
+/// Returns the length in bytes of a string type `S`, not counting the padding that rounds its
+/// size up to a whole number of words. Fails to compile if `S` isn't a `str[N]`.
+pub fn str_len<S>() -> u64 {
+    __str_len::<S>()
+}
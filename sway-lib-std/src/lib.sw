@@ -6,7 +6,13 @@ dep logging;
 dep assert;
 dep option;
 dep result;
+dep alloc;
+dep mem;
+dep vec;
+dep bytes;
+dep string;
 dep constants;
+dep convert;
 dep contract_id;
 dep context;
 dep hash;
@@ -16,6 +22,8 @@ dep address;
 dep tx;
 dep chain;
 dep math;
+dep u128;
+dep u256;
 dep block;
 dep token;
 dep ecr;
@@ -0,0 +1,51 @@
+//! Low level utilities for working with raw memory.
+library mem;
+
+use ::alloc::alloc;
+use ::intrinsics::{is_reference_type, size_of};
+
+/// Returns the address of a value of reference type `T`.
+/// Copy types are held in registers and so have no address.
+pub fn addr_of<T>(val: T) -> u64 {
+    asm(ptr: val) {
+        ptr: u64
+    }
+}
+
+/// Copies `size` bytes from `src` to `dst`.
+pub fn copy(dst: u64, src: u64, size: u64) {
+    asm(dst: dst, src: src, size: size) {
+        mcp dst src size;
+    };
+}
+
+/// Reads a value of type `T` from the memory at `ptr`.
+/// Copy types are loaded into a register. Reference types are copied into newly allocated heap
+/// memory, so that the value returned is unaffected by later writes to `ptr`.
+pub fn read<T>(ptr: u64) -> T {
+    if is_reference_type::<T>() {
+        let size = size_of::<T>();
+        let val_ptr = alloc(size);
+        copy(val_ptr, ptr, size);
+        asm(ptr: val_ptr) {
+            ptr: T
+        }
+    } else {
+        asm(ptr: ptr, val) {
+            lw val ptr i0;
+            val: T
+        }
+    }
+}
+
+/// Writes a value of type `T` to the memory at `ptr`.
+/// Copy types are stored as a single word. Reference types are copied from wherever they are held.
+pub fn write<T>(ptr: u64, val: T) {
+    if is_reference_type::<T>() {
+        copy(ptr, addr_of(val), size_of::<T>());
+    } else {
+        asm(ptr: ptr, val: val) {
+            sw ptr val i0;
+        };
+    }
+}
@@ -1,5 +1,7 @@
 library storage;
 
+use ::hash::sha256;
+
 pub fn store<T>(key: b256, value: T) {
     asm(r1: key, r2: value) {
         sww r1 r2;
@@ -12,3 +14,30 @@ pub fn get<T>(key: b256) -> T {
         r2: T
     }
 }
+
+/// A persistent key-value mapping, for use as a `storage` field only:
+///
+/// ```
+/// storage {
+///     balances: StorageMap<Address, u64>,
+/// }
+/// ```
+///
+/// Each value is kept in its own slot, at the hash of the key and the field's own slot.
+pub struct StorageMap<K, V> {}
+
+impl<K, V> StorageMap<K, V> {
+    /// Stores `value` at `key`, overwriting any previous value.
+    #[storage(write)]
+    fn insert(self, key: K, value: V) {
+        let key = sha256((key, __get_storage_key()));
+        store::<V>(key, value);
+    }
+
+    /// Returns the value stored at `key`, or the zero value if nothing has been stored there.
+    #[storage(read)]
+    fn get(self, key: K) -> V {
+        let key = sha256((key, __get_storage_key()));
+        get::<V>(key)
+    }
+}
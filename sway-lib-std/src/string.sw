@@ -0,0 +1,82 @@
+//! A growable string, stored on the heap.
+library string;
+
+use ::bytes::{Bytes, bytes_from_str};
+use ::option::Option;
+
+/// A growable string of UTF-8 encoded bytes, stored on the heap.
+///
+/// A `String` is a wrapper around `Bytes`, so copies of a `String` all refer to the same bytes.
+pub struct String {
+    bytes: Bytes,
+}
+
+impl String {
+    /// Constructs a new, empty `String`.
+    fn new() -> Self {
+        String {
+            bytes: ~Bytes::new(),
+        }
+    }
+
+    /// Constructs a new, empty `String` with room for `capacity` bytes.
+    fn with_capacity(capacity: u64) -> Self {
+        String {
+            bytes: ~Bytes::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a byte to the end of the string.
+    fn push(self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
+    /// Appends a copy of `other` to the end of the string.
+    fn append(self, other: String) {
+        self.bytes.append(other.bytes);
+    }
+
+    /// Returns the byte at `index`, or `None` if it is out of bounds.
+    fn get(self, index: u64) -> Option<u8> {
+        self.bytes.get(index)
+    }
+
+    /// Returns the length of the string in bytes.
+    fn len(self) -> u64 {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the string is empty.
+    fn is_empty(self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the number of bytes there is room for without reallocating.
+    fn capacity(self) -> u64 {
+        self.bytes.capacity()
+    }
+
+    /// Truncates the string to be empty, keeping the capacity.
+    fn clear(self) {
+        self.bytes.clear();
+    }
+
+    /// Returns the address of the first byte of the string.
+    fn as_ptr(self) -> u64 {
+        self.bytes.as_ptr()
+    }
+
+    /// Returns the bytes of the string.
+    fn as_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+/// Returns a new `String` holding a copy of the bytes of the string `s`.
+///
+/// Fails to compile if `S` isn't a `str[N]`.
+pub fn string_from_str<S>(s: S) -> String {
+    String {
+        bytes: bytes_from_str(s),
+    }
+}
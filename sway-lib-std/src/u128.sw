@@ -0,0 +1,323 @@
+library u128;
+//! A 128-bit unsigned integer type, built from two 64-bit words.
+
+use ::convert::{From, Into};
+use ::revert::revert;
+
+/// The 128-bit unsigned integer type.
+/// Represented as two 64-bit components: `(upper, lower)`, where `value = (upper << 64) + lower`.
+/// Arithmetic that overflows or underflows reverts.
+pub struct U128 {
+    upper: u64,
+    lower: u64,
+}
+
+impl From<u64> for U128 {
+    fn from(value: u64) -> U128 {
+        U128 {
+            upper: 0,
+            lower: value,
+        }
+    }
+}
+
+/// Functions for casting between U128 and its `(upper, lower)` components.
+impl From<(u64, u64)> for U128 {
+    fn from(components: (u64, u64)) -> U128 {
+        U128 {
+            upper: components.0,
+            lower: components.1,
+        }
+    }
+}
+
+impl Into<(u64, u64)> for U128 {
+    fn into(self) -> (u64, u64) {
+        (self.upper, self.lower)
+    }
+}
+
+impl core::ops::Eq for U128 {
+    fn eq(self, other: Self) -> bool {
+        self.upper == other.upper && self.lower == other.lower
+    }
+}
+
+impl core::ops::Ord for U128 {
+    fn gt(self, other: Self) -> bool {
+        self.upper > other.upper || self.upper == other.upper && self.lower > other.lower
+    }
+
+    fn lt(self, other: Self) -> bool {
+        self.upper < other.upper || self.upper == other.upper && self.lower < other.lower
+    }
+}
+
+impl core::ops::OrdEq for U128 {
+}
+
+/// Methods on the U128 type
+impl U128 {
+    /// Initializes a new, zeroed U128.
+    pub fn new() -> U128 {
+        U128 {
+            upper: 0,
+            lower: 0,
+        }
+    }
+
+    /// The smallest value that can be represented by this integer type.
+    pub fn min() -> U128 {
+        ~U128::new()
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>128</sup> - 1.
+    pub fn max() -> U128 {
+        U128 {
+            upper: ~u64::max(),
+            lower: ~u64::max(),
+        }
+    }
+
+    /// The size of this integer type in bits.
+    pub fn bits() -> u32 {
+        128
+    }
+
+    /// Adds `other`, wrapping around at the boundary of the type. Also returns whether the
+    /// addition overflowed.
+    pub fn overflowing_add(self, other: Self) -> (U128, bool) {
+        let (lower, lower_carry) = add_with_carry(self.lower, other.lower, 0);
+        let (upper, carry) = add_with_carry(self.upper, other.upper, lower_carry);
+        (U128 {
+            upper: upper,
+            lower: lower,
+        }, carry == 1)
+    }
+
+    /// Subtracts `other`, wrapping around at the boundary of the type. Also returns whether the
+    /// subtraction underflowed.
+    pub fn overflowing_sub(self, other: Self) -> (U128, bool) {
+        let (lower, lower_borrow) = sub_with_borrow(self.lower, other.lower, 0);
+        let (upper, borrow) = sub_with_borrow(self.upper, other.upper, lower_borrow);
+        (U128 {
+            upper: upper,
+            lower: lower,
+        }, borrow == 1)
+    }
+
+    /// Multiplies by `other`, returning the full 256-bit product as its `(upper, lower)` halves,
+    /// which cannot overflow.
+    pub fn mul_wide(self, other: Self) -> (U128, U128) {
+        let (ll_upper, ll_lower) = mul_wide_u64(self.lower, other.lower);
+        let (lu_upper, lu_lower) = mul_wide_u64(self.lower, other.upper);
+        let (ul_upper, ul_lower) = mul_wide_u64(self.upper, other.lower);
+        let (uu_upper, uu_lower) = mul_wide_u64(self.upper, other.upper);
+
+        // The two cross products are both shifted up by one word.
+        let (cross_lower, carry_0) = add_with_carry(lu_lower, ul_lower, 0);
+        let (cross_upper, cross_carry) = add_with_carry(lu_upper, ul_upper, carry_0);
+
+        let (word_1, carry_1) = add_with_carry(ll_upper, cross_lower, 0);
+        let (word_2, carry_2) = add_with_carry(uu_lower, cross_upper, carry_1);
+        let (word_3, _) = add_with_carry(uu_upper, cross_carry, carry_2);
+        (U128 {
+            upper: word_3,
+            lower: word_2,
+        }, U128 {
+            upper: word_1,
+            lower: ll_lower,
+        })
+    }
+
+    /// Divides by `divisor`, returning the quotient and the remainder. Reverts if `divisor` is
+    /// zero.
+    pub fn div_rem(self, divisor: Self) -> (U128, U128) {
+        let zero = ~U128::new();
+        if divisor == zero {
+            revert(0);
+        }
+
+        let mut quotient = zero;
+        let mut remainder = zero;
+        let mut index = 128;
+        while index > 0 {
+            index = index - 1;
+            // The bit shifted out of the remainder is needed to tell whether the divisor fits.
+            let carry = remainder.upper >> 63;
+            remainder = (remainder << 1) | ((self >> index) & ~U128::from(1));
+            quotient = quotient << 1;
+            if carry == 1 || remainder >= divisor {
+                let (difference, _) = remainder.overflowing_sub(divisor);
+                remainder = difference;
+                quotient = quotient | ~U128::from(1);
+            }
+        }
+        (quotient, remainder)
+    }
+}
+
+impl core::ops::Add for U128 {
+    fn add(self, other: Self) -> Self {
+        let (sum, overflow) = self.overflowing_add(other);
+        if overflow {
+            revert(0);
+        }
+        sum
+    }
+}
+
+impl core::ops::Subtract for U128 {
+    fn subtract(self, other: Self) -> Self {
+        let (difference, underflow) = self.overflowing_sub(other);
+        if underflow {
+            revert(0);
+        }
+        difference
+    }
+}
+
+impl core::ops::Multiply for U128 {
+    fn multiply(self, other: Self) -> Self {
+        let (upper, lower) = self.mul_wide(other);
+        if upper != ~U128::new() {
+            revert(0);
+        }
+        lower
+    }
+}
+
+impl core::ops::Divide for U128 {
+    fn divide(self, other: Self) -> Self {
+        let (quotient, _) = self.div_rem(other);
+        quotient
+    }
+}
+
+impl core::ops::Mod for U128 {
+    fn modulo(self, other: Self) -> Self {
+        let (_, remainder) = self.div_rem(other);
+        remainder
+    }
+}
+
+impl core::ops::Shiftable for U128 {
+    fn lsh(self, other: u64) -> Self {
+        if other == 0 {
+            self
+        } else if other >= 128 {
+            ~U128::new()
+        } else if other >= 64 {
+            U128 {
+                upper: self.lower << (other - 64),
+                lower: 0,
+            }
+        } else {
+            U128 {
+                upper: (self.upper << other) | (self.lower >> (64 - other)),
+                lower: self.lower << other,
+            }
+        }
+    }
+
+    fn rsh(self, other: u64) -> Self {
+        if other == 0 {
+            self
+        } else if other >= 128 {
+            ~U128::new()
+        } else if other >= 64 {
+            U128 {
+                upper: 0,
+                lower: self.upper >> (other - 64),
+            }
+        } else {
+            U128 {
+                upper: self.upper >> other,
+                lower: (self.lower >> other) | (self.upper << (64 - other)),
+            }
+        }
+    }
+}
+
+impl core::ops::BitwiseAnd for U128 {
+    fn binary_and(self, other: Self) -> Self {
+        U128 {
+            upper: self.upper & other.upper,
+            lower: self.lower & other.lower,
+        }
+    }
+}
+
+impl core::ops::BitwiseOr for U128 {
+    fn binary_or(self, other: Self) -> Self {
+        U128 {
+            upper: self.upper | other.upper,
+            lower: self.lower | other.lower,
+        }
+    }
+}
+
+impl core::ops::BitwiseXor for U128 {
+    fn binary_xor(self, other: Self) -> Self {
+        U128 {
+            upper: self.upper ^ other.upper,
+            lower: self.lower ^ other.lower,
+        }
+    }
+}
+
+/////////////////////////////////////////////////
+// Internal Helpers
+/////////////////////////////////////////////////
+
+// None of these let a word wrap around, so they do not depend on how the VM handles overflow.
+
+/// Add two words and a carry of 0 or 1, returning the sum and the carry out.
+fn add_with_carry(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let room = ~u64::max() - b;
+    if a > room {
+        // `a + b - 2^64`, which leaves room for the carry.
+        (a - room - 1 + carry, 1)
+    } else if a == room && carry == 1 {
+        (0, 1)
+    } else {
+        (a + b + carry, 0)
+    }
+}
+
+/// Subtract a word and a borrow of 0 or 1, returning the difference and the borrow out.
+fn sub_with_borrow(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    if a > b {
+        (a - b - borrow, 0)
+    } else if a == b {
+        if borrow == 1 {
+            (~u64::max(), 1)
+        } else {
+            (0, 0)
+        }
+    } else {
+        // `a - b + 2^64`, which leaves room for the borrow.
+        (~u64::max() - (b - a) + 1 - borrow, 1)
+    }
+}
+
+/// Multiply two words, returning the full product as `(upper, lower)` words.
+fn mul_wide_u64(a: u64, b: u64) -> (u64, u64) {
+    // Split into 32-bit halves so that none of the partial products can overflow.
+    let mask = 4294967295;
+    let a_lower = a & mask;
+    let a_upper = a >> 32;
+    let b_lower = b & mask;
+    let b_upper = b >> 32;
+
+    let ll = a_lower * b_lower;
+    let lu = a_lower * b_upper;
+    let ul = a_upper * b_lower;
+    let uu = a_upper * b_upper;
+
+    let middle = (ll >> 32) + (lu & mask) + (ul & mask);
+    let lower = ((middle & mask) << 32) | (ll & mask);
+    let upper = uu + (lu >> 32) + (ul >> 32) + (middle >> 32);
+    (upper, lower)
+}
@@ -0,0 +1,338 @@
+library u256;
+//! A 256-bit unsigned integer type, built from four 64-bit words.
+
+use ::convert::{From, Into};
+use ::revert::revert;
+use ::u128::U128;
+
+/// The 256-bit unsigned integer type.
+/// Represented as four 64-bit components: `(a, b, c, d)`, where
+/// `value = (a << 192) + (b << 128) + (c << 64) + d`.
+/// Arithmetic that overflows or underflows reverts.
+pub struct U256 {
+    a: u64,
+    b: u64,
+    c: u64,
+    d: u64,
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> U256 {
+        U256 {
+            a: 0,
+            b: 0,
+            c: 0,
+            d: value,
+        }
+    }
+}
+
+impl From<U128> for U256 {
+    fn from(value: U128) -> U256 {
+        from_halves(~U128::new(), value)
+    }
+}
+
+/// Functions for casting between U256 and its `(a, b, c, d)` components.
+impl From<(u64, u64, u64, u64)> for U256 {
+    fn from(components: (u64, u64, u64, u64)) -> U256 {
+        U256 {
+            a: components.0,
+            b: components.1,
+            c: components.2,
+            d: components.3,
+        }
+    }
+}
+
+impl Into<(u64, u64, u64, u64)> for U256 {
+    fn into(self) -> (u64, u64, u64, u64) {
+        (self.a, self.b, self.c, self.d)
+    }
+}
+
+/// Functions for casting between U256 and b256, which hold the same big-endian words.
+impl From<b256> for U256 {
+    fn from(bits: b256) -> U256 {
+        U256 {
+            a: asm(r1: bits, r2) {
+                lw r2 r1 i0;
+                r2: u64
+            },
+            b: asm(r1: bits, r2) {
+                lw r2 r1 i1;
+                r2: u64
+            },
+            c: asm(r1: bits, r2) {
+                lw r2 r1 i2;
+                r2: u64
+            },
+            d: asm(r1: bits, r2) {
+                lw r2 r1 i3;
+                r2: u64
+            },
+        }
+    }
+}
+
+impl Into<b256> for U256 {
+    fn into(self) -> b256 {
+        let bits: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+        asm(a: self.a, b: self.b, c: self.c, d: self.d, result: bits) {
+            sw result a i0;
+            sw result b i1;
+            sw result c i2;
+            sw result d i3;
+            result: b256
+        }
+    }
+}
+
+impl core::ops::Eq for U256 {
+    fn eq(self, other: Self) -> bool {
+        self.a == other.a && self.b == other.b && self.c == other.c && self.d == other.d
+    }
+}
+
+impl core::ops::Ord for U256 {
+    fn gt(self, other: Self) -> bool {
+        let (self_high, self_low) = halves(self);
+        let (other_high, other_low) = halves(other);
+        self_high > other_high || self_high == other_high && self_low > other_low
+    }
+
+    fn lt(self, other: Self) -> bool {
+        let (self_high, self_low) = halves(self);
+        let (other_high, other_low) = halves(other);
+        self_high < other_high || self_high == other_high && self_low < other_low
+    }
+}
+
+impl core::ops::OrdEq for U256 {
+}
+
+/// Methods on the U256 type
+impl U256 {
+    /// Initializes a new, zeroed U256.
+    pub fn new() -> U256 {
+        U256 {
+            a: 0,
+            b: 0,
+            c: 0,
+            d: 0,
+        }
+    }
+
+    /// The smallest value that can be represented by this integer type.
+    pub fn min() -> U256 {
+        ~U256::new()
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>256</sup> - 1.
+    pub fn max() -> U256 {
+        U256 {
+            a: ~u64::max(),
+            b: ~u64::max(),
+            c: ~u64::max(),
+            d: ~u64::max(),
+        }
+    }
+
+    /// The size of this integer type in bits.
+    pub fn bits() -> u32 {
+        256
+    }
+
+    /// Adds `other`, wrapping around at the boundary of the type. Also returns whether the
+    /// addition overflowed.
+    pub fn overflowing_add(self, other: Self) -> (U256, bool) {
+        let (self_high, self_low) = halves(self);
+        let (other_high, other_low) = halves(other);
+        let (low, low_carry) = self_low.overflowing_add(other_low);
+        let (high, high_carry) = self_high.overflowing_add(other_high);
+        let (high, carry) = if low_carry {
+            high.overflowing_add(~U128::from(1))
+        } else {
+            (high, false)
+        };
+        (from_halves(high, low), high_carry || carry)
+    }
+
+    /// Subtracts `other`, wrapping around at the boundary of the type. Also returns whether the
+    /// subtraction underflowed.
+    pub fn overflowing_sub(self, other: Self) -> (U256, bool) {
+        let (self_high, self_low) = halves(self);
+        let (other_high, other_low) = halves(other);
+        let (low, low_borrow) = self_low.overflowing_sub(other_low);
+        let (high, high_borrow) = self_high.overflowing_sub(other_high);
+        let (high, borrow) = if low_borrow {
+            high.overflowing_sub(~U128::from(1))
+        } else {
+            (high, false)
+        };
+        (from_halves(high, low), high_borrow || borrow)
+    }
+
+    /// Divides by `divisor`, returning the quotient and the remainder. Reverts if `divisor` is
+    /// zero.
+    pub fn div_rem(self, divisor: Self) -> (U256, U256) {
+        let zero = ~U256::new();
+        if divisor == zero {
+            revert(0);
+        }
+
+        let mut quotient = zero;
+        let mut remainder = zero;
+        let mut index = 256;
+        while index > 0 {
+            index = index - 1;
+            // The bit shifted out of the remainder is needed to tell whether the divisor fits.
+            let carry = remainder.a >> 63;
+            remainder = (remainder << 1) | ((self >> index) & ~U256::from(1));
+            quotient = quotient << 1;
+            if carry == 1 || remainder >= divisor {
+                let (difference, _) = remainder.overflowing_sub(divisor);
+                remainder = difference;
+                quotient = quotient | ~U256::from(1);
+            }
+        }
+        (quotient, remainder)
+    }
+}
+
+impl core::ops::Add for U256 {
+    fn add(self, other: Self) -> Self {
+        let (sum, overflow) = self.overflowing_add(other);
+        if overflow {
+            revert(0);
+        }
+        sum
+    }
+}
+
+impl core::ops::Subtract for U256 {
+    fn subtract(self, other: Self) -> Self {
+        let (difference, underflow) = self.overflowing_sub(other);
+        if underflow {
+            revert(0);
+        }
+        difference
+    }
+}
+
+impl core::ops::Multiply for U256 {
+    fn multiply(self, other: Self) -> Self {
+        let zero = ~U128::new();
+        let (self_high, self_low) = halves(self);
+        let (other_high, other_low) = halves(other);
+        // Both high halves being non-zero would put the product at or above 2^256.
+        if self_high != zero && other_high != zero {
+            revert(0);
+        }
+
+        let (low_high, low_low) = self_low.mul_wide(other_low);
+        let (cross_1_high, cross_1_low) = self_high.mul_wide(other_low);
+        let (cross_2_high, cross_2_low) = self_low.mul_wide(other_high);
+        if cross_1_high != zero || cross_2_high != zero {
+            revert(0);
+        }
+        from_halves(low_high + cross_1_low + cross_2_low, low_low)
+    }
+}
+
+impl core::ops::Divide for U256 {
+    fn divide(self, other: Self) -> Self {
+        let (quotient, _) = self.div_rem(other);
+        quotient
+    }
+}
+
+impl core::ops::Mod for U256 {
+    fn modulo(self, other: Self) -> Self {
+        let (_, remainder) = self.div_rem(other);
+        remainder
+    }
+}
+
+impl core::ops::Shiftable for U256 {
+    fn lsh(self, other: u64) -> Self {
+        let (high, low) = halves(self);
+        if other == 0 {
+            self
+        } else if other >= 256 {
+            ~U256::new()
+        } else if other >= 128 {
+            from_halves(low << (other - 128), ~U128::new())
+        } else {
+            from_halves((high << other) | (low >> (128 - other)), low << other)
+        }
+    }
+
+    fn rsh(self, other: u64) -> Self {
+        let (high, low) = halves(self);
+        if other == 0 {
+            self
+        } else if other >= 256 {
+            ~U256::new()
+        } else if other >= 128 {
+            from_halves(~U128::new(), high >> (other - 128))
+        } else {
+            from_halves(high >> other, (low >> other) | (high << (128 - other)))
+        }
+    }
+}
+
+impl core::ops::BitwiseAnd for U256 {
+    fn binary_and(self, other: Self) -> Self {
+        U256 {
+            a: self.a & other.a,
+            b: self.b & other.b,
+            c: self.c & other.c,
+            d: self.d & other.d,
+        }
+    }
+}
+
+impl core::ops::BitwiseOr for U256 {
+    fn binary_or(self, other: Self) -> Self {
+        U256 {
+            a: self.a | other.a,
+            b: self.b | other.b,
+            c: self.c | other.c,
+            d: self.d | other.d,
+        }
+    }
+}
+
+impl core::ops::BitwiseXor for U256 {
+    fn binary_xor(self, other: Self) -> Self {
+        U256 {
+            a: self.a ^ other.a,
+            b: self.b ^ other.b,
+            c: self.c ^ other.c,
+            d: self.d ^ other.d,
+        }
+    }
+}
+
+/////////////////////////////////////////////////
+// Internal Helpers
+/////////////////////////////////////////////////
+
+/// Split a U256 into its `(high, low)` U128 halves.
+fn halves(value: U256) -> (U128, U128) {
+    (~U128::from((value.a, value.b)), ~U128::from((value.c, value.d)))
+}
+
+/// Build a U256 from its `(high, low)` U128 halves.
+fn from_halves(high: U128, low: U128) -> U256 {
+    let (a, b) = high.into();
+    let (c, d) = low.into();
+    U256 {
+        a: a,
+        b: b,
+        c: c,
+        d: d,
+    }
+}
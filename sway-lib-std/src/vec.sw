@@ -0,0 +1,102 @@
+//! A growable array type, stored on the heap.
+library vec;
+
+use ::alloc::{alloc, realloc};
+use ::intrinsics::size_of;
+use ::mem::{read, write};
+use ::option::Option;
+
+/// A contiguous, growable array of values of type `T`, stored on the heap.
+///
+/// A `Vec` is a pointer to a header on the heap, which holds the address of the elements, the
+/// number of elements there is room for, and the number of elements. Methods update the header
+/// in place, so a `Vec` may be passed by value and all copies refer to the same elements.
+pub struct Vec<T> {
+    header: u64,
+}
+
+impl<T> Vec<T> {
+    /// Constructs a new, empty `Vec<T>`.
+    ///
+    /// No memory is allocated for the elements until they are pushed.
+    fn new() -> Self {
+        let header = alloc(24);
+        write::<u64>(header, alloc(0));
+        write::<u64>(header + 8, 0);
+        write::<u64>(header + 16, 0);
+        Vec {
+            header: header,
+        }
+    }
+
+    /// Constructs a new, empty `Vec<T>` with room for `capacity` elements.
+    fn with_capacity(capacity: u64) -> Self {
+        let header = alloc(24);
+        write::<u64>(header, alloc(capacity * size_of::<T>()));
+        write::<u64>(header + 8, capacity);
+        write::<u64>(header + 16, 0);
+        Vec {
+            header: header,
+        }
+    }
+
+    /// Appends an element to the back of the vector, reallocating it if it is full.
+    fn push(self, value: T) {
+        let len = self.len();
+        let capacity = self.capacity();
+        let size = size_of::<T>();
+        if len == capacity {
+            let new_capacity = if capacity == 0 { 1 } else { capacity * 2 };
+            let buf = realloc(self.buf(), capacity * size, new_capacity * size);
+            write::<u64>(self.header, buf);
+            write::<u64>(self.header + 8, new_capacity);
+        }
+        write::<T>(self.buf() + len * size, value);
+        write::<u64>(self.header + 16, len + 1);
+    }
+
+    /// Removes the last element from the vector and returns it, or `None` if it is empty.
+    fn pop(self) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            Option::None
+        } else {
+            write::<u64>(self.header + 16, len - 1);
+            Option::Some(read::<T>(self.buf() + (len - 1) * size_of::<T>()))
+        }
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    fn get(self, index: u64) -> Option<T> {
+        if index < self.len() {
+            Option::Some(read::<T>(self.buf() + index * size_of::<T>()))
+        } else {
+            Option::None
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    fn len(self) -> u64 {
+        read::<u64>(self.header + 16)
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements the vector can hold without reallocating.
+    fn capacity(self) -> u64 {
+        read::<u64>(self.header + 8)
+    }
+
+    /// Removes all elements from the vector, keeping its capacity.
+    fn clear(self) {
+        write::<u64>(self.header + 16, 0);
+    }
+
+    /// Returns the address of the elements.
+    fn buf(self) -> u64 {
+        read::<u64>(self.header)
+    }
+}
@@ -2,6 +2,8 @@ library evm_address;
 
 //! A wrapper around the b256 type to help enhance type-safety.
 
+use ::convert::{From, Into};
+
 /// The Address type, a struct wrappper around the inner `value`.
 pub struct EvmAddress {
     value: b256,
@@ -17,16 +19,8 @@ impl core::ops::Eq for EvmAddress {
     }
 }
 
-pub trait From {
-    fn from(b: b256) -> Self;
-} {
-    fn into(addr: EvmAddress) -> b256 {
-        addr.value
-    }
-}
-
 /// Functions for casting between the b256 and Address types.
-impl From for EvmAddress {
+impl From<b256> for EvmAddress {
     fn from(bits: b256) -> EvmAddress {
         // An ethereum address is only 20 bytes, so the first 12 are set to zero
         asm(r1: bits) {
@@ -38,3 +32,9 @@ impl From for EvmAddress {
         }
     }
 }
+
+impl Into<b256> for EvmAddress {
+    fn into(self) -> b256 {
+        self.value
+    }
+}
@@ -97,6 +97,7 @@ impl Token {
                 | TokenType::AbiDeclaration
                 | TokenType::ConstantDeclaration(_)
                 | TokenType::StorageFieldDeclaration
+                | TokenType::TypeAliasDeclaration
         )
     }
 }
@@ -275,8 +276,16 @@ fn handle_declaration(declaration: Declaration, tokens: &mut Vec<Token>) {
                 let ident = &field.name;
                 let token = Token::from_ident(ident, TokenType::StorageFieldDeclaration);
                 tokens.push(token);
+
+                if let Some(initializer) = field.initializer {
+                    handle_expression(initializer, tokens);
+                }
             }
         }
+        Declaration::TypeAliasDeclaration(type_alias_dec) => {
+            let token = Token::from_ident(&type_alias_dec.name, TokenType::TypeAliasDeclaration);
+            tokens.push(token);
+        }
     };
 }
 
@@ -450,6 +459,18 @@ fn handle_expression(exp: Expression, tokens: &mut Vec<Token>) {
         Expression::BuiltinGetTypeProperty { .. } => {
             //TODO handle built in get type property?
         }
+        Expression::BuiltinGetStorageKey { .. } => {}
+        Expression::Closure {
+            parameters, body, ..
+        } => {
+            for param in parameters {
+                handle_function_parameter(&param, tokens);
+            }
+            handle_expression(*body, tokens);
+        }
+        Expression::MutRef { expr, .. } => {
+            handle_expression(*expr, tokens);
+        }
     }
 }
 
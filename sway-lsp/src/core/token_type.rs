@@ -20,6 +20,7 @@ pub enum TokenType {
     TraitFunction,
     EnumVariant,
     StorageFieldDeclaration,
+    TypeAliasDeclaration,
 
     FunctionApplication,
     VariableExpression,
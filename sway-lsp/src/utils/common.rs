@@ -23,6 +23,10 @@ pub(crate) fn extract_var_body(var_dec: &VariableDeclaration) -> VarBody {
             Literal::U16(_) => VarBody::Type("u16".into()),
             Literal::U32(_) => VarBody::Type("u32".into()),
             Literal::U64(_) => VarBody::Type("u64".into()),
+            Literal::I8(_) => VarBody::Type("i8".into()),
+            Literal::I16(_) => VarBody::Type("i16".into()),
+            Literal::I32(_) => VarBody::Type("i32".into()),
+            Literal::I64(_) => VarBody::Type("i64".into()),
             Literal::Numeric(_) => VarBody::Type("u64".into()),
             Literal::String(len) => VarBody::Type(format!("str[{}]", len.as_str().len())),
             Literal::Boolean(_) => VarBody::Type("bool".into()),
@@ -8,6 +8,8 @@ pub enum ParseErrorKind {
     ExpectedAnItem,
     #[error("Expected a comma or closing parenthesis in function arguments.")]
     ExpectedCommaOrCloseParenInFnArgs,
+    #[error("Expected a comma or closing `|` in closure parameters.")]
+    ExpectedCommaOrPipeInClosureParams,
     #[error("Unrecognized op code.")]
     UnrecognizedOpCode,
     #[error("Unexpected token in statement.")]
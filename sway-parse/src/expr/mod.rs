@@ -35,6 +35,24 @@ pub enum Expr {
         condition: Box<Expr>,
         block: Braces<CodeBlockContents>,
     },
+    For {
+        for_token: ForToken,
+        value_pattern: Pattern,
+        in_token: InToken,
+        iterator: Box<Expr>,
+        block: Braces<CodeBlockContents>,
+    },
+    Range {
+        start: Box<Expr>,
+        double_dot_token: DoubleDotToken,
+        end: Box<Expr>,
+    },
+    Break {
+        break_token: BreakToken,
+    },
+    Continue {
+        continue_token: ContinueToken,
+    },
     FuncApp {
         func: Box<Expr>,
         args: Parens<Punctuated<Expr, CommaToken>>,
@@ -69,6 +87,11 @@ pub enum Expr {
         deref_token: DerefToken,
         expr: Box<Expr>,
     },
+    MutRef {
+        ampersand_token: AmpersandToken,
+        mut_token: MutToken,
+        expr: Box<Expr>,
+    },
     Not {
         bang_token: BangToken,
         expr: Box<Expr>,
@@ -168,6 +191,11 @@ pub enum Expr {
         eq_token: EqToken,
         expr: Box<Expr>,
     },
+    Closure {
+        params: ClosureParams,
+        return_type_opt: Option<(RightArrowToken, Ty)>,
+        body: Box<Expr>,
+    },
 }
 
 impl Spanned for Expr {
@@ -202,6 +230,12 @@ impl Spanned for Expr {
             Expr::While {
                 while_token, block, ..
             } => Span::join(while_token.span(), block.span()),
+            Expr::For {
+                for_token, block, ..
+            } => Span::join(for_token.span(), block.span()),
+            Expr::Range { start, end, .. } => Span::join(start.span(), end.span()),
+            Expr::Break { break_token } => break_token.span(),
+            Expr::Continue { continue_token } => continue_token.span(),
             Expr::FuncApp { func, args } => Span::join(func.span(), args.span()),
             Expr::Index { target, arg } => Span::join(target.span(), arg.span()),
             Expr::MethodCall { target, args, .. } => Span::join(target.span(), args.span()),
@@ -213,6 +247,11 @@ impl Spanned for Expr {
             } => Span::join(target.span(), field_span.clone()),
             Expr::Ref { ref_token, expr } => Span::join(ref_token.span(), expr.span()),
             Expr::Deref { deref_token, expr } => Span::join(deref_token.span(), expr.span()),
+            Expr::MutRef {
+                ampersand_token,
+                expr,
+                ..
+            } => Span::join(ampersand_token.span(), expr.span()),
             Expr::Not { bang_token, expr } => Span::join(bang_token.span(), expr.span()),
             Expr::Mul { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::Div { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
@@ -235,6 +274,84 @@ impl Spanned for Expr {
             Expr::Reassignment {
                 assignable, expr, ..
             } => Span::join(assignable.span(), expr.span()),
+            Expr::Closure { params, body, .. } => Span::join(params.span(), body.span()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ClosureParams {
+    Empty {
+        double_pipe_token: DoublePipeToken,
+    },
+    Args {
+        open_pipe_token: PipeToken,
+        args: Punctuated<FnArg, CommaToken>,
+        close_pipe_token: PipeToken,
+    },
+}
+
+impl ClosureParams {
+    pub fn into_args(self) -> Vec<FnArg> {
+        match self {
+            ClosureParams::Empty { .. } => Vec::new(),
+            ClosureParams::Args { args, .. } => args.into_iter().collect(),
+        }
+    }
+}
+
+impl Spanned for ClosureParams {
+    fn span(&self) -> Span {
+        match self {
+            ClosureParams::Empty { double_pipe_token } => double_pipe_token.span(),
+            ClosureParams::Args {
+                open_pipe_token,
+                close_pipe_token,
+                ..
+            } => Span::join(open_pipe_token.span(), close_pipe_token.span()),
+        }
+    }
+}
+
+impl Parse for ClosureParams {
+    fn parse(parser: &mut Parser) -> ParseResult<ClosureParams> {
+        if let Some(double_pipe_token) = parser.take() {
+            return Ok(ClosureParams::Empty { double_pipe_token });
+        }
+        let open_pipe_token = parser.parse()?;
+        let mut value_separator_pairs = Vec::new();
+        loop {
+            if let Some(close_pipe_token) = parser.take() {
+                let args = Punctuated {
+                    value_separator_pairs,
+                    final_value_opt: None,
+                };
+                return Ok(ClosureParams::Args {
+                    open_pipe_token,
+                    args,
+                    close_pipe_token,
+                });
+            }
+            let arg = parser.parse()?;
+            if let Some(close_pipe_token) = parser.take() {
+                let args = Punctuated {
+                    value_separator_pairs,
+                    final_value_opt: Some(Box::new(arg)),
+                };
+                return Ok(ClosureParams::Args {
+                    open_pipe_token,
+                    args,
+                    close_pipe_token,
+                });
+            }
+            match parser.take() {
+                Some(comma_token) => value_separator_pairs.push((arg, comma_token)),
+                None => {
+                    return Err(
+                        parser.emit_error(ParseErrorKind::ExpectedCommaOrPipeInClosureParams)
+                    )
+                }
+            }
         }
     }
 }
@@ -789,6 +906,15 @@ fn parse_unary_op(parser: &mut Parser, allow_struct_exprs: bool) -> ParseResult<
         let expr = Box::new(parse_unary_op(parser, allow_struct_exprs)?);
         return Ok(Expr::Deref { deref_token, expr });
     }
+    if let Some(ampersand_token) = parser.take() {
+        let mut_token = parser.parse()?;
+        let expr = Box::new(parse_unary_op(parser, allow_struct_exprs)?);
+        return Ok(Expr::MutRef {
+            ampersand_token,
+            mut_token,
+            expr,
+        });
+    }
     if let Some(bang_token) = parser.take() {
         let expr = Box::new(parse_unary_op(parser, allow_struct_exprs)?);
         return Ok(Expr::Not { bang_token, expr });
@@ -967,6 +1093,52 @@ fn parse_atom(parser: &mut Parser, allow_struct_exprs: bool) -> ParseResult<Expr
             block,
         });
     }
+    if let Some(for_token) = parser.take() {
+        let value_pattern = parser.parse()?;
+        let in_token = parser.parse()?;
+        let iterator = parse_condition(parser)?;
+        let iterator = match parser.take() {
+            Some(double_dot_token) => Expr::Range {
+                start: Box::new(iterator),
+                double_dot_token,
+                end: Box::new(parse_condition(parser)?),
+            },
+            None => iterator,
+        };
+        let block = parser.parse()?;
+        return Ok(Expr::For {
+            for_token,
+            value_pattern,
+            in_token,
+            iterator: Box::new(iterator),
+            block,
+        });
+    }
+    if parser.peek::<PipeToken>().is_some() || parser.peek::<DoublePipeToken>().is_some() {
+        let params = parser.parse()?;
+        let (return_type_opt, body) = match parser.take() {
+            Some(right_arrow_token) => {
+                let ty = parser.parse()?;
+                let block = parser.parse()?;
+                (Some((right_arrow_token, ty)), Box::new(Expr::Block(block)))
+            }
+            None => (
+                None,
+                Box::new(parse_reassignment(parser, allow_struct_exprs)?),
+            ),
+        };
+        return Ok(Expr::Closure {
+            params,
+            return_type_opt,
+            body,
+        });
+    }
+    if let Some(break_token) = parser.take() {
+        return Ok(Expr::Break { break_token });
+    }
+    if let Some(continue_token) = parser.take() {
+        return Ok(Expr::Continue { continue_token });
+    }
     if parser.peek::<OpenAngleBracketToken>().is_some()
         || parser.peek::<DoubleColonToken>().is_some()
         || parser.peek::<TildeToken>().is_some()
@@ -1157,7 +1329,8 @@ impl Expr {
                 | Expr::Asm(..)
                 | Expr::If(..)
                 | Expr::Match { .. }
-                | Expr::While { .. },
+                | Expr::While { .. }
+                | Expr::For { .. },
         )
     }
 }
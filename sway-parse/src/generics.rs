@@ -2,7 +2,50 @@ use crate::priv_prelude::*;
 
 #[derive(Clone, Debug)]
 pub struct GenericParams {
-    pub parameters: AngleBrackets<Punctuated<Ident, CommaToken>>,
+    pub parameters: AngleBrackets<Punctuated<GenericParam, CommaToken>>,
+}
+
+/// A single parameter of a `GenericParams` list: either a type parameter `T` or a const
+/// parameter `const N: u64`.
+#[derive(Clone, Debug)]
+pub enum GenericParam {
+    Type(Ident),
+    Const {
+        const_token: ConstToken,
+        name: Ident,
+        colon_token: ColonToken,
+        ty: Ty,
+    },
+}
+
+impl Spanned for GenericParam {
+    fn span(&self) -> Span {
+        match self {
+            GenericParam::Type(name) => name.span().clone(),
+            GenericParam::Const {
+                const_token, ty, ..
+            } => Span::join(const_token.span(), ty.span()),
+        }
+    }
+}
+
+impl Parse for GenericParam {
+    fn parse(parser: &mut Parser) -> ParseResult<GenericParam> {
+        match parser.take() {
+            Some(const_token) => {
+                let name = parser.parse()?;
+                let colon_token = parser.parse()?;
+                let ty = parser.parse()?;
+                Ok(GenericParam::Const {
+                    const_token,
+                    name,
+                    colon_token,
+                    ty,
+                })
+            }
+            None => Ok(GenericParam::Type(parser.parse()?)),
+        }
+    }
 }
 
 impl Parse for GenericParams {
@@ -13,12 +56,12 @@ impl Parse for GenericParams {
             if let Some(close_angle_bracket_token) = parser.take() {
                 break (None, close_angle_bracket_token);
             };
-            let ident = parser.parse()?;
+            let param = parser.parse()?;
             if let Some(close_angle_bracket_token) = parser.take() {
-                break (Some(Box::new(ident)), close_angle_bracket_token);
+                break (Some(Box::new(param)), close_angle_bracket_token);
             };
             let comma_token = parser.parse()?;
-            value_separator_pairs.push((ident, comma_token));
+            value_separator_pairs.push((param, comma_token));
         };
         let punctuated = Punctuated {
             value_separator_pairs,
@@ -3,6 +3,8 @@ pub enum Intrinsic {
     IsReferenceType,
     SizeOf,
     SizeOfVal,
+    GetStorageKey,
+    StrLen,
 }
 
 impl Intrinsic {
@@ -12,6 +14,8 @@ impl Intrinsic {
             "__is_reference_type" => IsReferenceType,
             "__size_of" => SizeOf,
             "__size_of_val" => SizeOfVal,
+            "__get_storage_key" => GetStorageKey,
+            "__str_len" => StrLen,
             _ => return None,
         })
     }
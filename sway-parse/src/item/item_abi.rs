@@ -4,6 +4,7 @@ use crate::priv_prelude::*;
 pub struct ItemAbi {
     pub abi_token: AbiToken,
     pub name: Ident,
+    pub generics: Option<GenericParams>,
     pub abi_items: Braces<Vec<(Annotated<FnSignature>, SemicolonToken)>>,
     pub abi_defs_opt: Option<Braces<Vec<Annotated<ItemFn>>>>,
 }
@@ -23,11 +24,17 @@ impl Parse for ItemAbi {
     fn parse(parser: &mut Parser) -> ParseResult<ItemAbi> {
         let abi_token = parser.parse()?;
         let name = parser.parse()?;
+        let generics = if parser.peek::<OpenAngleBracketToken>().is_some() {
+            Some(parser.parse()?)
+        } else {
+            None
+        };
         let abi_items = parser.parse()?;
         let abi_defs_opt = Braces::try_parse(parser)?;
         Ok(ItemAbi {
             abi_token,
             name,
+            generics,
             abi_items,
             abi_defs_opt,
         })
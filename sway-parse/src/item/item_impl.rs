@@ -7,7 +7,7 @@ pub struct ItemImpl {
     pub trait_opt: Option<(PathType, ForToken)>,
     pub ty: Ty,
     pub where_clause_opt: Option<WhereClause>,
-    pub contents: Braces<Vec<Annotated<ItemFn>>>,
+    pub contents: Braces<Vec<Annotated<ImplItem>>>,
 }
 
 impl Spanned for ItemImpl {
@@ -16,6 +16,40 @@ impl Spanned for ItemImpl {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum ImplItem {
+    Fn(ItemFn),
+    Const(ItemConst),
+    Type(ItemTypeAlias),
+}
+
+impl Spanned for ImplItem {
+    fn span(&self) -> Span {
+        match self {
+            ImplItem::Fn(item_fn) => item_fn.span(),
+            ImplItem::Const(item_const) => item_const.span(),
+            ImplItem::Type(item_type_alias) => item_type_alias.span(),
+        }
+    }
+}
+
+impl Parse for ImplItem {
+    fn parse(parser: &mut Parser) -> ParseResult<ImplItem> {
+        if parser.peek::<ConstToken>().is_some() || parser.peek2::<PubToken, ConstToken>().is_some()
+        {
+            let item_const = parser.parse()?;
+            return Ok(ImplItem::Const(item_const));
+        }
+        if parser.peek::<TypeToken>().is_some() {
+            let item_type_alias = parser.parse()?;
+            return Ok(ImplItem::Type(item_type_alias));
+        }
+        let item_fn = parser.parse()?;
+        Ok(ImplItem::Fn(item_fn))
+    }
+}
+
 impl Parse for ItemImpl {
     fn parse(parser: &mut Parser) -> ParseResult<ItemImpl> {
         let impl_token = parser.parse()?;
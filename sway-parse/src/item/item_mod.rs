@@ -0,0 +1,47 @@
+use crate::priv_prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct ItemMod {
+    pub visibility: Option<PubToken>,
+    pub mod_token: ModToken,
+    pub name: Ident,
+    pub kind: ItemModKind,
+}
+
+#[derive(Clone, Debug)]
+pub enum ItemModKind {
+    Inline(Braces<Vec<Item>>),
+    File(SemicolonToken),
+}
+
+impl Spanned for ItemMod {
+    fn span(&self) -> Span {
+        let start = match &self.visibility {
+            Some(pub_token) => pub_token.span(),
+            None => self.mod_token.span(),
+        };
+        let end = match &self.kind {
+            ItemModKind::Inline(items) => items.span(),
+            ItemModKind::File(semicolon_token) => semicolon_token.span(),
+        };
+        Span::join(start, end)
+    }
+}
+
+impl Parse for ItemMod {
+    fn parse(parser: &mut Parser) -> ParseResult<ItemMod> {
+        let visibility = parser.take();
+        let mod_token = parser.parse()?;
+        let name = parser.parse()?;
+        let kind = match parser.take() {
+            Some(semicolon_token) => ItemModKind::File(semicolon_token),
+            None => ItemModKind::Inline(parser.parse()?),
+        };
+        Ok(ItemMod {
+            visibility,
+            mod_token,
+            name,
+            kind,
+        })
+    }
+}
@@ -5,8 +5,9 @@ pub struct ItemTrait {
     pub visibility: Option<PubToken>,
     pub trait_token: TraitToken,
     pub name: Ident,
+    pub generics: Option<GenericParams>,
     pub super_traits: Option<(ColonToken, Traits)>,
-    pub trait_items: Braces<Vec<(Annotated<FnSignature>, SemicolonToken)>>,
+    pub trait_items: Braces<Vec<(Annotated<TraitItem>, SemicolonToken)>>,
     pub trait_defs_opt: Option<Braces<Vec<Annotated<ItemFn>>>>,
 }
 
@@ -24,6 +25,102 @@ impl Spanned for ItemTrait {
     }
 }
 
+#[derive(Clone, Debug)]
+pub enum TraitItem {
+    Fn(FnSignature),
+    Const(TraitItemConst),
+    Type(TraitItemType),
+}
+
+impl Spanned for TraitItem {
+    fn span(&self) -> Span {
+        match self {
+            TraitItem::Fn(fn_signature) => fn_signature.span(),
+            TraitItem::Const(trait_item_const) => trait_item_const.span(),
+            TraitItem::Type(trait_item_type) => trait_item_type.span(),
+        }
+    }
+}
+
+impl Parse for TraitItem {
+    fn parse(parser: &mut Parser) -> ParseResult<TraitItem> {
+        if parser.peek::<ConstToken>().is_some() {
+            let trait_item_const = parser.parse()?;
+            return Ok(TraitItem::Const(trait_item_const));
+        }
+        if parser.peek::<TypeToken>().is_some() {
+            let trait_item_type = parser.parse()?;
+            return Ok(TraitItem::Type(trait_item_type));
+        }
+        let fn_signature = parser.parse()?;
+        Ok(TraitItem::Fn(fn_signature))
+    }
+}
+
+/// An associated constant, e.g. `const DECIMALS: u8;`, with an optional default value.
+#[derive(Clone, Debug)]
+pub struct TraitItemConst {
+    pub const_token: ConstToken,
+    pub name: Ident,
+    pub colon_token: ColonToken,
+    pub ty: Ty,
+    pub eq_expr_opt: Option<(EqToken, Expr)>,
+}
+
+impl Spanned for TraitItemConst {
+    fn span(&self) -> Span {
+        let end = match &self.eq_expr_opt {
+            Some((_eq_token, expr)) => expr.span(),
+            None => self.ty.span(),
+        };
+        Span::join(self.const_token.span(), end)
+    }
+}
+
+impl Parse for TraitItemConst {
+    fn parse(parser: &mut Parser) -> ParseResult<TraitItemConst> {
+        let const_token = parser.parse()?;
+        let name = parser.parse()?;
+        let colon_token = parser.parse()?;
+        let ty = parser.parse()?;
+        let eq_expr_opt = match parser.take() {
+            Some(eq_token) => {
+                let expr = parser.parse()?;
+                Some((eq_token, expr))
+            }
+            None => None,
+        };
+        Ok(TraitItemConst {
+            const_token,
+            name,
+            colon_token,
+            ty,
+            eq_expr_opt,
+        })
+    }
+}
+
+/// An associated type, e.g. `type Item;`.
+#[derive(Clone, Debug)]
+pub struct TraitItemType {
+    pub type_token: TypeToken,
+    pub name: Ident,
+}
+
+impl Spanned for TraitItemType {
+    fn span(&self) -> Span {
+        Span::join(self.type_token.span(), self.name.span().clone())
+    }
+}
+
+impl Parse for TraitItemType {
+    fn parse(parser: &mut Parser) -> ParseResult<TraitItemType> {
+        let type_token = parser.parse()?;
+        let name = parser.parse()?;
+        Ok(TraitItemType { type_token, name })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Traits {
     pub prefix: PathType,
@@ -35,6 +132,11 @@ impl Parse for ItemTrait {
         let visibility = parser.take();
         let trait_token = parser.parse()?;
         let name = parser.parse()?;
+        let generics = if parser.peek::<OpenAngleBracketToken>().is_some() {
+            Some(parser.parse()?)
+        } else {
+            None
+        };
         let super_traits = match parser.take() {
             Some(colon_token) => {
                 let traits = parser.parse()?;
@@ -48,6 +150,7 @@ impl Parse for ItemTrait {
             visibility,
             trait_token,
             name,
+            generics,
             super_traits,
             trait_items,
             trait_defs_opt,
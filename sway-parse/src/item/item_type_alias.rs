@@ -0,0 +1,47 @@
+use crate::priv_prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct ItemTypeAlias {
+    pub visibility: Option<PubToken>,
+    pub type_token: TypeToken,
+    pub name: Ident,
+    pub generics: Option<GenericParams>,
+    pub eq_token: EqToken,
+    pub ty: Ty,
+    pub semicolon_token: SemicolonToken,
+}
+
+impl Spanned for ItemTypeAlias {
+    fn span(&self) -> Span {
+        let start = match &self.visibility {
+            Some(pub_token) => pub_token.span(),
+            None => self.type_token.span(),
+        };
+        Span::join(start, self.semicolon_token.span())
+    }
+}
+
+impl Parse for ItemTypeAlias {
+    fn parse(parser: &mut Parser) -> ParseResult<ItemTypeAlias> {
+        let visibility = parser.take();
+        let type_token = parser.parse()?;
+        let name = parser.parse()?;
+        let generics = if parser.peek::<OpenAngleBracketToken>().is_some() {
+            Some(parser.parse()?)
+        } else {
+            None
+        };
+        let eq_token = parser.parse()?;
+        let ty = parser.parse()?;
+        let semicolon_token = parser.parse()?;
+        Ok(ItemTypeAlias {
+            visibility,
+            type_token,
+            name,
+            generics,
+            eq_token,
+            ty,
+            semicolon_token,
+        })
+    }
+}
@@ -5,9 +5,11 @@ pub mod item_const;
 pub mod item_enum;
 pub mod item_fn;
 pub mod item_impl;
+pub mod item_mod;
 pub mod item_storage;
 pub mod item_struct;
 pub mod item_trait;
+pub mod item_type_alias;
 pub mod item_use;
 
 pub type Item = Annotated<ItemKind>;
@@ -33,6 +35,8 @@ pub enum ItemKind {
     Abi(ItemAbi),
     Const(ItemConst),
     Storage(ItemStorage),
+    Mod(ItemMod),
+    TypeAlias(ItemTypeAlias),
 }
 
 impl Spanned for ItemKind {
@@ -47,6 +51,8 @@ impl Spanned for ItemKind {
             ItemKind::Abi(item_abi) => item_abi.span(),
             ItemKind::Const(item_const) => item_const.span(),
             ItemKind::Storage(item_storage) => item_storage.span(),
+            ItemKind::Mod(item_mod) => item_mod.span(),
+            ItemKind::TypeAlias(item_type_alias) => item_type_alias.span(),
         }
     }
 }
@@ -93,6 +99,14 @@ impl Parse for ItemKind {
             let item_storage = parser.parse()?;
             return Ok(ItemKind::Storage(item_storage));
         }
+        if parser.peek::<ModToken>().is_some() || parser.peek2::<PubToken, ModToken>().is_some() {
+            let item_mod = parser.parse()?;
+            return Ok(ItemKind::Mod(item_mod));
+        }
+        if parser.peek::<TypeToken>().is_some() || parser.peek2::<PubToken, TypeToken>().is_some() {
+            let item_type_alias = parser.parse()?;
+            return Ok(ItemKind::TypeAlias(item_type_alias));
+        }
         Err(parser.emit_error(ParseErrorKind::ExpectedAnItem))
     }
 }
@@ -127,6 +141,8 @@ impl Parse for TypeField {
 pub enum FnArgs {
     Static(Punctuated<FnArg, CommaToken>),
     NonStatic {
+        /// Present when the receiver is `&mut self` rather than `self`.
+        ref_self: Option<(AmpersandToken, MutToken)>,
         self_token: SelfToken,
         args_opt: Option<(CommaToken, Punctuated<FnArg, CommaToken>)>,
     },
@@ -149,12 +165,21 @@ impl ParseToEnd for FnArgs {
     fn parse_to_end<'a, 'e>(
         mut parser: Parser<'a, 'e>,
     ) -> ParseResult<(FnArgs, ParserConsumed<'a>)> {
-        match parser.take() {
+        let ref_self = match parser.take() {
+            Some(ampersand_token) => Some((ampersand_token, parser.parse()?)),
+            None => None,
+        };
+        let self_token = match ref_self {
+            Some(_) => Some(parser.parse()?),
+            None => parser.take(),
+        };
+        match self_token {
             Some(self_token) => {
                 match parser.take() {
                     Some(comma_token) => {
                         let (args, consumed) = parser.parse_to_end()?;
                         let fn_args = FnArgs::NonStatic {
+                            ref_self,
                             self_token,
                             args_opt: Some((comma_token, args)),
                         };
@@ -162,6 +187,7 @@ impl ParseToEnd for FnArgs {
                     }
                     None => {
                         let fn_args = FnArgs::NonStatic {
+                            ref_self,
                             self_token,
                             args_opt: None,
                         };
@@ -42,6 +42,7 @@ define_keyword!(ContractToken, "contract");
 define_keyword!(PredicateToken, "predicate");
 define_keyword!(LibraryToken, "library");
 define_keyword!(DepToken, "dep");
+define_keyword!(ModToken, "mod");
 define_keyword!(PubToken, "pub");
 define_keyword!(UseToken, "use");
 define_keyword!(AsToken, "as");
@@ -51,6 +52,7 @@ define_keyword!(SelfToken, "self");
 define_keyword!(FnToken, "fn");
 define_keyword!(TraitToken, "trait");
 define_keyword!(ImplToken, "impl");
+define_keyword!(TypeToken, "type");
 define_keyword!(ForToken, "for");
 define_keyword!(AbiToken, "abi");
 define_keyword!(ConstToken, "const");
@@ -64,6 +66,9 @@ define_keyword!(MatchToken, "match");
 define_keyword!(MutToken, "mut");
 define_keyword!(LetToken, "let");
 define_keyword!(WhileToken, "while");
+define_keyword!(BreakToken, "break");
+define_keyword!(ContinueToken, "continue");
+define_keyword!(InToken, "in");
 define_keyword!(WhereToken, "where");
 define_keyword!(RefToken, "ref");
 define_keyword!(DerefToken, "deref");
@@ -151,7 +156,8 @@ define_token!(
     [Equals, GreaterThan],
     [GreaterThan, Equals]
 );
-define_token!(DotToken, "`.`", [Dot], []);
+define_token!(DotToken, "`.`", [Dot], [Dot]);
+define_token!(DoubleDotToken, "`..`", [Dot, Dot], [Dot]);
 define_token!(BangToken, "`!`", [Bang], [Equals]);
 define_token!(PercentToken, "`%`", [Percent], []);
 define_token!(AddToken, "`+`", [Add], []);
@@ -30,20 +30,22 @@ pub use crate::{
     expr::{
         asm::{AsmBlock, AsmRegisterDeclaration},
         op_code::Instruction,
-        AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
+        AbiCastArgs, ClosureParams, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
         ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
     },
-    generics::{GenericArgs, GenericParams},
+    generics::{GenericArgs, GenericParam, GenericParams},
     intrinsics::*,
     item::{
         item_abi::ItemAbi,
         item_const::ItemConst,
         item_enum::ItemEnum,
         item_fn::ItemFn,
-        item_impl::ItemImpl,
+        item_impl::{ImplItem, ItemImpl},
+        item_mod::{ItemMod, ItemModKind},
         item_storage::{ItemStorage, StorageField},
         item_struct::ItemStruct,
-        item_trait::{ItemTrait, Traits},
+        item_trait::{ItemTrait, TraitItem, TraitItemConst, TraitItemType, Traits},
+        item_type_alias::ItemTypeAlias,
         item_use::{ItemUse, UseTree},
         FnArg, FnArgs, FnSignature, Item, ItemKind, TypeField,
     },
@@ -85,3 +87,20 @@ pub fn parse_file(src: Arc<str>, path: Option<Arc<PathBuf>>) -> Result<Program,
     };
     Ok(program)
 }
+
+pub fn parse_module_file(
+    src: Arc<str>,
+    path: Option<Arc<PathBuf>>,
+) -> Result<Vec<Item>, ParseFileError> {
+    let token_stream = match lex(&src, 0, src.len(), path) {
+        Ok(token_stream) => token_stream,
+        Err(error) => return Err(ParseFileError::Lex(error)),
+    };
+    let mut errors = Vec::new();
+    let parser = Parser::new(&token_stream, &mut errors);
+    let items = match parser.parse_to_end() {
+        Ok((items, _parser_consumed)) => items,
+        Err(_error_emitted) => return Err(ParseFileError::Parse(errors)),
+    };
+    Ok(items)
+}
@@ -8,21 +8,23 @@ pub use {
         expr::{
             asm::{AsmBlock, AsmImmediate},
             op_code::{parse_instruction, Instruction},
-            CodeBlockContents, Expr,
+            ClosureParams, CodeBlockContents, Expr,
         },
-        generics::{GenericArgs, GenericParams},
+        generics::{GenericArgs, GenericParam, GenericParams},
         intrinsics::*,
         item::{
             item_abi::ItemAbi,
             item_const::ItemConst,
             item_enum::ItemEnum,
             item_fn::ItemFn,
-            item_impl::ItemImpl,
+            item_impl::{ImplItem, ItemImpl},
+            item_mod::{ItemMod, ItemModKind},
             item_storage::ItemStorage,
             item_struct::ItemStruct,
-            item_trait::{ItemTrait, Traits},
+            item_trait::{ItemTrait, TraitItem, TraitItemConst, TraitItemType, Traits},
+            item_type_alias::ItemTypeAlias,
             item_use::ItemUse,
-            FnSignature, Item, ItemKind, TypeField,
+            FnArg, FnSignature, Item, ItemKind, TypeField,
         },
         keywords::*,
         literal::{LitChar, LitInt, LitIntType, LitString, Literal},
@@ -13,6 +13,17 @@ pub enum Ty {
     Infer {
         underscore_token: UnderscoreToken,
     },
+    Fn {
+        fn_token: FnToken,
+        arguments: Parens<Punctuated<Ty, CommaToken>>,
+        return_type_opt: Option<(RightArrowToken, Box<Ty>)>,
+    },
+    /// A mutable reference, e.g. `&mut u64`, which may only be the type of a function parameter.
+    MutRef {
+        ampersand_token: AmpersandToken,
+        mut_token: MutToken,
+        ty: Box<Ty>,
+    },
 }
 
 impl Spanned for Ty {
@@ -23,6 +34,22 @@ impl Spanned for Ty {
             Ty::Array(array_type) => array_type.span(),
             Ty::Str { str_token, length } => Span::join(str_token.span(), length.span()),
             Ty::Infer { underscore_token } => underscore_token.span(),
+            Ty::Fn {
+                fn_token,
+                arguments,
+                return_type_opt,
+            } => {
+                let end = match return_type_opt {
+                    Some((_right_arrow_token, ty)) => ty.span(),
+                    None => arguments.span(),
+                };
+                Span::join(fn_token.span(), end)
+            }
+            Ty::MutRef {
+                ampersand_token,
+                ty,
+                ..
+            } => Span::join(ampersand_token.span(), ty.span()),
         }
     }
 }
@@ -51,6 +78,30 @@ impl Parse for Ty {
         if let Some(underscore_token) = parser.take() {
             return Ok(Ty::Infer { underscore_token });
         }
+        if let Some(fn_token) = parser.take() {
+            let arguments = parser.parse()?;
+            let return_type_opt = match parser.take() {
+                Some(right_arrow_token) => {
+                    let ty = parser.parse()?;
+                    Some((right_arrow_token, ty))
+                }
+                None => None,
+            };
+            return Ok(Ty::Fn {
+                fn_token,
+                arguments,
+                return_type_opt,
+            });
+        }
+        if let Some(ampersand_token) = parser.take() {
+            let mut_token = parser.parse()?;
+            let ty = parser.parse()?;
+            return Ok(Ty::MutRef {
+                ampersand_token,
+                mut_token,
+                ty,
+            });
+        }
         if parser.peek::<OpenAngleBracketToken>().is_some()
             || parser.peek::<DoubleColonToken>().is_some()
             || parser.peek::<Ident>().is_some()
@@ -285,10 +285,67 @@ pub fn run(filter_regex: Option<regex::Regex>) {
             "should_pass/language/nested_while_and_if",
             ProgramState::Return(1),
         ),
+        (
+            "should_pass/language/break_and_continue",
+            ProgramState::Return(1),
+        ),
+        ("should_pass/language/for_loops", ProgramState::Return(1)),
+        ("should_pass/language/closures", ProgramState::Return(1)),
+        (
+            "should_pass/language/trait_constraints",
+            ProgramState::Return(1),
+        ),
+        (
+            "should_pass/language/generic_traits",
+            ProgramState::Return(1),
+        ),
+        (
+            "should_pass/language/associated_items",
+            ProgramState::Return(1),
+        ),
+        (
+            "should_pass/language/const_generics",
+            ProgramState::Return(1),
+        ),
+        ("should_pass/language/const_eval", ProgramState::Return(1)),
+        (
+            "should_pass/language/mut_ref_params",
+            ProgramState::Return(1),
+        ),
+        (
+            "should_pass/language/nested_modules",
+            ProgramState::Return(1),
+        ),
+        (
+            "should_pass/language/library_generic_fns",
+            ProgramState::Return(1),
+        ),
+        ("should_pass/language/type_aliases", ProgramState::Return(1)),
+        (
+            "should_pass/language/signed_integers",
+            ProgramState::Return(1),
+        ),
+        (
+            "should_pass/language/signed_integer_add_overflow",
+            ProgramState::Revert(0),
+        ),
+        (
+            "should_pass/language/signed_integer_sub_overflow",
+            ProgramState::Revert(0),
+        ),
+        (
+            "should_pass/language/signed_integer_div_overflow",
+            ProgramState::Revert(0),
+        ),
+        (
+            "should_pass/language/signed_integer_mul_overflow",
+            ProgramState::Revert(0),
+        ),
         (
             "should_pass/language/is_reference_type",
             ProgramState::Return(1),
         ),
+        ("should_pass/language/str_len", ProgramState::Return(1)),
         (
             "should_pass/language/contract_caller_as_type",
             ProgramState::Return(42),
@@ -402,6 +459,18 @@ pub fn run(filter_regex: Option<regex::Regex>) {
             "should_pass/test_contracts/issue_1512_repro",
             ProgramState::Revert(0),
         ),
+        (
+            "should_pass/test_contracts/type_alias_contract",
+            ProgramState::Revert(0),
+        ),
+        (
+            "should_pass/test_contracts/signed_integer_contract",
+            ProgramState::Revert(0),
+        ),
+        (
+            "should_pass/test_contracts/generic_abi_contract",
+            ProgramState::Revert(0),
+        ),
     ];
 
     number_of_tests_run += positive_project_names_with_abi
@@ -455,6 +524,7 @@ pub fn run(filter_regex: Option<regex::Regex>) {
         "should_fail/supertrait_does_not_exist",
         "should_fail/chained_if_let_missing_branch",
         "should_fail/abort_control_flow",
+        "should_fail/break_outside_loop",
         "should_fail/match_expressions_non_exhaustive",
         "should_fail/empty_impl",
         "should_fail/disallow_turbofish",
@@ -479,6 +549,24 @@ pub fn run(filter_regex: Option<regex::Regex>) {
         "should_fail/impure_trait_read_calls_impure_write",
         "should_fail/trait_impl_purity_mismatch",
         "should_fail/trait_pure_calls_impure",
+        "should_fail/storage_field_type_not_supported",
+        "should_fail/storage_initializer_not_constant",
+        "should_fail/closure_captures_variable",
+        "should_fail/trait_constraint_not_satisfied",
+        "should_fail/trait_constraint_same_name_other_trait",
+        "should_fail/str_len_not_str",
+        "should_fail/str_len_generic_not_str",
+        "should_fail/generic_trait_needs_type_arguments",
+        "should_fail/missing_associated_type",
+        "should_fail/associated_type_of_type_parameter",
+        "should_fail/array_length_not_const_generic",
+        "should_fail/const_eval_overflow",
+        "should_fail/const_eval_non_constant",
+        "should_fail/mut_ref_of_immutable",
+        "should_fail/private_module_path_access",
+        "should_fail/private_item_import",
+        "should_fail/type_alias_type_arguments",
+        "should_fail/signed_integer_literal_out_of_range",
     ];
     number_of_tests_run += negative_project_names.iter().fold(0, |acc, name| {
         if filter(name) {
@@ -0,0 +1,10 @@
+script;
+
+// `T` is a type parameter, not a const generic parameter.
+fn first<T>(a: [u64; T]) -> u64 {
+    a[0]
+}
+
+fn main() -> u64 {
+    first([1, 2, 3])
+}
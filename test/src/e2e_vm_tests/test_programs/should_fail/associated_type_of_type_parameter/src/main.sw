@@ -0,0 +1,33 @@
+script;
+
+trait Container {
+    type Item;
+
+    fn first(self) -> Self::Item;
+}
+
+struct Pair {
+    a: u64,
+    b: u64,
+}
+
+impl Container for Pair {
+    type Item = u64;
+
+    fn first(self) -> Self::Item {
+        self.a
+    }
+}
+
+// Associated types can only be referred to through `Self` so far.
+fn first_of<T>(container: T) -> T::Item where T: Container {
+    container.first()
+}
+
+fn main() -> u64 {
+    let p = Pair {
+        a: 1,
+        b: 2,
+    };
+    first_of(p)
+}
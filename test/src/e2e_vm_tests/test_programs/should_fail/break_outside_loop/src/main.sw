@@ -0,0 +1,13 @@
+script;
+
+fn helper() {
+    // a function body is not part of the loop it is called from
+    break;
+}
+
+fn main() {
+    while true {
+        helper();
+    }
+    continue;
+}
@@ -0,0 +1,8 @@
+script;
+
+fn main() -> u64 {
+    let offset = 10;
+    // Closures can't capture local variables.
+    let add_offset = |x: u64| x + offset;
+    add_offset(1)
+}
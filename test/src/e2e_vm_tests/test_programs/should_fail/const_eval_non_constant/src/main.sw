@@ -0,0 +1,18 @@
+script;
+
+fn triangle(n: u64) -> u64 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < n {
+        i = i + 1;
+        total = total + i;
+    }
+    total
+}
+
+// Loops and reassignment can't be evaluated at compile time.
+const TRIANGLE: u64 = triangle(4);
+
+fn main() -> u64 {
+    TRIANGLE
+}
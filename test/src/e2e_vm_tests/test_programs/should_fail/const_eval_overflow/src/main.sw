@@ -0,0 +1,12 @@
+script;
+
+const BIG: u64 = 18446744073709551615;
+const TOO_BIG: u64 = BIG + 1;
+const SMALL: u8 = 255;
+const TOO_SMALL: u8 = SMALL * 2;
+const UNDERFLOW: u64 = 1 - 2;
+const QUOTIENT: u64 = 10 / (BIG - BIG);
+
+fn main() -> u64 {
+    TOO_BIG + UNDERFLOW + QUOTIENT
+}
@@ -0,0 +1,23 @@
+script;
+
+trait Convert<T> {
+    fn convert(self) -> T;
+}
+
+struct Meters {
+    value: u64,
+}
+
+// `Convert` takes a type argument.
+impl Convert for Meters {
+    fn convert(self) -> u64 {
+        self.value
+    }
+}
+
+fn main() -> u64 {
+    let m = Meters {
+        value: 1,
+    };
+    m.convert()
+}
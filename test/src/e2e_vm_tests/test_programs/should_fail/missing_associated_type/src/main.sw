@@ -0,0 +1,27 @@
+script;
+
+trait Container {
+    type Item;
+
+    fn first(self) -> Self::Item;
+}
+
+struct Pair {
+    a: u64,
+    b: u64,
+}
+
+// `Container` requires the `Item` associated type to be given.
+impl Container for Pair {
+    fn first(self) -> u64 {
+        self.a
+    }
+}
+
+fn main() -> u64 {
+    let p = Pair {
+        a: 1,
+        b: 2,
+    };
+    p.first()
+}
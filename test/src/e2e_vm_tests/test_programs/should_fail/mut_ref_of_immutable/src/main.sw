@@ -0,0 +1,10 @@
+script;
+
+fn increment(x: &mut u64) {
+    x = x + 1;
+}
+
+fn main() {
+    let x = 0;
+    increment(&mut x);
+}
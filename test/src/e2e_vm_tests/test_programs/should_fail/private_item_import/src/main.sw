@@ -0,0 +1,20 @@
+script;
+
+mod outer {
+    pub mod open {
+        fn hidden() -> u64 {
+            7
+        }
+
+        pub fn shown() -> u64 {
+            hidden()
+        }
+    }
+}
+
+// `hidden` is private to `outer::open`.
+use outer::open::hidden;
+
+fn main() -> u64 {
+    hidden() + outer::open::shown()
+}
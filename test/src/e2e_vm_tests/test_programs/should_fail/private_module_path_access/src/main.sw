@@ -0,0 +1,18 @@
+script;
+
+mod outer {
+    mod inner {
+        pub fn secret() -> u64 {
+            42
+        }
+    }
+
+    pub fn reveal() -> u64 {
+        inner::secret()
+    }
+}
+
+fn main() -> u64 {
+    // `inner` is private to `outer`.
+    outer::inner::secret() + outer::reveal()
+}
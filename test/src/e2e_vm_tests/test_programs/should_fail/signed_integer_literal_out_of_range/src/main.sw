@@ -0,0 +1,7 @@
+script;
+
+fn main() {
+    // `i8` only goes up to 127.
+    let too_large = 128i8;
+    let also_too_large: i8 = 200;
+}
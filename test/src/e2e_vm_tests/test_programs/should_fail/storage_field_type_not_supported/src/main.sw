@@ -0,0 +1,16 @@
+contract;
+
+abi Test {
+    fn foo();
+}
+
+storage {
+    x: u64,
+    // Contracts have no representation in storage.
+    c: Contract,
+}
+
+impl Test for Contract {
+    fn foo() {
+    }
+}
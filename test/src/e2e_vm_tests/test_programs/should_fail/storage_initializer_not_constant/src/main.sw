@@ -0,0 +1,24 @@
+contract;
+
+abi Test {
+    fn foo();
+}
+
+fn five() -> u64 {
+    let mut i = 0;
+    while i < 5 {
+        i = i + 1;
+    }
+    i
+}
+
+storage {
+    x: u64 = 5,
+    // Initializers must be evaluated at compile time, which rules out loops.
+    y: u64 = five(),
+}
+
+impl Test for Contract {
+    fn foo() {
+    }
+}
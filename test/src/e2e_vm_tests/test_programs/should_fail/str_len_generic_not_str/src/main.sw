@@ -0,0 +1,12 @@
+script;
+
+use std::bytes::bytes_from_str;
+
+struct S {
+    a: u64,
+}
+
+fn main() -> u64 {
+    let bytes = bytes_from_str(S { a: 42 });
+    bytes.len()
+}
@@ -0,0 +1,9 @@
+script;
+
+struct S {
+    a: u64,
+}
+
+fn main() -> u64 {
+    __str_len::<u64>() + __str_len::<S>() + __str_len::<[u8; 4]>()
+}
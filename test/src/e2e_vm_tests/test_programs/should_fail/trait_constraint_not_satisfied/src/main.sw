@@ -0,0 +1,24 @@
+script;
+
+trait Score {
+    fn score(self) -> u64;
+}
+
+impl Score for u64 {
+    fn score(self) -> u64 {
+        self
+    }
+}
+
+struct Player {
+    points: u64,
+}
+
+fn total_score<T>(a: T, b: T) -> u64 where T: Score {
+    a.score() + b.score()
+}
+
+fn main() -> u64 {
+    // `Player` does not implement `Score`.
+    total_score(Player { points: 1 }, Player { points: 2 })
+}
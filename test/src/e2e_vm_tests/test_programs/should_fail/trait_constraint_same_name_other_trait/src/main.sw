@@ -0,0 +1,28 @@
+script;
+
+mod points {
+    pub trait Score {
+        fn score(self) -> u64;
+    }
+}
+
+mod ranking {
+    pub trait Score {
+        fn score(self) -> u64;
+    }
+}
+
+impl points::Score for u64 {
+    fn score(self) -> u64 {
+        self
+    }
+}
+
+fn total_score<T>(a: T, b: T) -> u64 where T: ranking::Score {
+    a.score() + b.score()
+}
+
+fn main() -> u64 {
+    // `u64` implements `points::Score`, not `ranking::Score`.
+    total_score(1, 2)
+}
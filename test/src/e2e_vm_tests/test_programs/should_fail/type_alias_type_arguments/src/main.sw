@@ -0,0 +1,12 @@
+script;
+
+type Pair<T> = (T, T);
+type Amount = u64;
+
+fn main() -> u64 {
+    // `Pair` takes exactly one type argument.
+    let pair: Pair<u64, u64> = (1, 2);
+    // `Amount` takes no type arguments.
+    let amount: Amount<u64> = 3;
+    pair.0 + amount
+}
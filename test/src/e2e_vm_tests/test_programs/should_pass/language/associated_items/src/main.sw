@@ -0,0 +1,86 @@
+script;
+
+trait Token {
+    const DECIMALS: u8;
+    const SCALE: u64 = 10;
+    type Amount;
+
+    fn amount(self) -> Self::Amount;
+} {
+    fn scaled(self) -> u64 {
+        Self::SCALE * 2
+    }
+}
+
+struct Coin {
+    value: u64,
+}
+
+struct Flag {
+    set: bool,
+}
+
+impl Token for Coin {
+    const DECIMALS: u8 = 9;
+    type Amount = u64;
+
+    fn amount(self) -> Self::Amount {
+        self.value * Self::SCALE
+    }
+}
+
+impl Token for Flag {
+    const DECIMALS: u8 = 0;
+    const SCALE: u64 = 1;
+    type Amount = bool;
+
+    fn amount(self) -> bool {
+        self.set
+    }
+}
+
+impl Token for u64 {
+    const DECIMALS: u8 = 18;
+    type Amount = u64;
+
+    fn amount(self) -> u64 {
+        self
+    }
+}
+
+impl Coin {
+    const ZERO: u64 = 0;
+}
+
+enum Side {
+    Buy: (),
+    Sell: (),
+}
+
+impl Side {
+    const COUNT: u64 = 2;
+}
+
+fn decimals<T>(token: T) -> u8 where T: Token {
+    T::DECIMALS
+}
+
+fn main() -> bool {
+    let coin = Coin {
+        value: 3,
+    };
+    let flag = Flag {
+        set: true,
+    };
+
+    decimals(coin) == 9
+        && decimals(flag) == 0
+        && decimals(5) == 18
+        && coin.amount() == 30
+        && flag.amount()
+        && coin.scaled() == 20
+        && flag.scaled() == 2
+        && Coin::ZERO == 0
+        && u64::DECIMALS == 18
+        && Side::COUNT == 2
+}
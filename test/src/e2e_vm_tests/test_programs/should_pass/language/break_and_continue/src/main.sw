@@ -0,0 +1,48 @@
+script;
+
+use std::assert::assert;
+
+fn main() -> bool {
+    // test `break` in a loop which would otherwise never terminate:
+    let mut counter = 0;
+    while true {
+        counter = counter + 1;
+        if counter == 7 {
+            break;
+        }
+    }
+    assert(counter == 7);
+
+    // test `continue` by summing only the odd numbers below 10:
+    let mut i = 0;
+    let mut sum = 0;
+    while i < 10 {
+        i = i + 1;
+        if i % 2 == 0 {
+            continue;
+        }
+        sum = sum + i;
+    }
+    assert(sum == 25);
+
+    // test that `break` and `continue` only affect the innermost loop:
+    let mut outer = 0;
+    let mut total = 0;
+    while outer < 3 {
+        outer = outer + 1;
+        let mut inner = 0;
+        while true {
+            inner = inner + 1;
+            if inner == 2 {
+                continue;
+            }
+            if inner > 4 {
+                break;
+            }
+            total = total + inner;
+        }
+    }
+    assert(total == 24);
+
+    true
+}
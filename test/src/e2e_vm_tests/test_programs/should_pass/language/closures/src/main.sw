@@ -0,0 +1,58 @@
+script;
+
+use std::assert::assert;
+
+fn apply(f: fn(u64) -> u64, x: u64) -> u64 {
+    f(x)
+}
+
+fn apply_twice<T>(f: fn(T) -> T, x: T) -> T {
+    f(f(x))
+}
+
+fn compose(f: fn(u64) -> u64, g: fn(u64) -> u64, x: u64) -> u64 {
+    g(f(x))
+}
+
+fn count_matching(values: [u64; 5], pred: fn(u64) -> bool) -> u64 {
+    let mut count = 0;
+    for value in values {
+        if pred(value) {
+            count = count + 1;
+        }
+    }
+    count
+}
+
+fn main() -> bool {
+    // closures bound with `let` and called directly:
+    let add_one = |x: u64| x + 1;
+    assert(add_one(41) == 42);
+
+    let nothing = || 7;
+    assert(nothing() == 7);
+
+    // closures with a block body and an explicit return type:
+    let clamp = |x: u64, max: u64| -> u64 {
+        if x > max {
+            return max;
+        }
+        x
+    };
+    assert(clamp(5, 10) == 5);
+    assert(clamp(15, 10) == 10);
+
+    // passing closures to functions, both by name and as literals:
+    assert(apply(add_one, 1) == 2);
+    assert(apply(|x: u64| x * 3, 4) == 12);
+    assert(apply_twice(add_one, 40) == 42);
+    assert(apply_twice(|b: bool| !b, true));
+    assert(compose(add_one, |x: u64| x * 2, 3) == 8);
+    assert(count_matching([1, 2, 3, 4, 5], |x: u64| x % 2 == 0) == 2);
+
+    // aliasing a closure:
+    let also_add_one = add_one;
+    assert(also_add_one(1) == 2);
+
+    true
+}
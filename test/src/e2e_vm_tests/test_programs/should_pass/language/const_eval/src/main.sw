@@ -0,0 +1,57 @@
+script;
+
+struct Fees {
+    base: u64,
+    rates: [u64; 3],
+}
+
+enum Mode {
+    Off: (),
+    On: u64,
+}
+
+const BASE: u64 = 7;
+// Constants may refer to those declared later.
+const TOTAL: u64 = FEE * 2 - 6;
+const FEE: u64 = 3 * BASE + 1;
+const SMALL: u8 = 200 / 4;
+const SHIFTED: u64 = (1 << 10) | (BASE % 4);
+const LIMIT: u64 = max(FEE, 10) + double(BASE);
+const ENABLED: bool = FEE > BASE && !(LIMIT == 0);
+const PAIR: (u64, bool) = (FEE, ENABLED);
+const FEES: Fees = Fees {
+    base: BASE,
+    rates: [FEE, LIMIT, clamp(100)],
+};
+const SECOND_RATE: u64 = FEES.rates[1];
+const MODE: Mode = Mode::On(PAIR.0);
+
+fn max(a: u64, b: u64) -> u64 {
+    if a > b { a } else { b }
+}
+
+fn double(n: u64) -> u64 {
+    let twice = n + n;
+    twice
+}
+
+fn clamp(n: u64) -> u64 {
+    if n > 50 {
+        return 50;
+    }
+    n
+}
+
+fn main() -> bool {
+    const LOCAL: u64 = TOTAL + SECOND_RATE;
+    const LOCAL_FEES: Fees = FEES;
+
+    let mode_ok = match MODE {
+        Mode::On(n) => n == 22,
+        Mode::Off => false,
+    };
+
+    FEE == 22 && TOTAL == 38 && SMALL == 50 && SHIFTED == 1027 && LIMIT == 36 && ENABLED
+        && PAIR.0 == 22 && PAIR.1 && FEES.base == 7 && FEES.rates[2] == 50 && SECOND_RATE == 36
+        && LOCAL == 74 && LOCAL_FEES.rates[0] == 22 && mode_ok
+}
@@ -0,0 +1,41 @@
+script;
+
+use std::assert::assert;
+
+struct Buf<const N: u64> {
+    data: [u64; N],
+}
+
+fn sum<const N: u64>(a: [u64; N]) -> u64 {
+    let mut total = 0;
+    for x in a {
+        total = total + x;
+    }
+    total
+}
+
+fn len<T, const N: u64>(a: [T; N]) -> u64 {
+    N
+}
+
+fn last<const N: u64>(a: [u64; N]) -> u64 {
+    a[N - 1]
+}
+
+fn main() -> bool {
+    // `N` is inferred from the argument at each call site:
+    assert(sum([1, 2, 3]) == 6);
+    assert(sum([1, 2, 3, 4, 5]) == 15);
+    assert(len([true, false, true, true]) == 4);
+    assert(len([(1, 2)]) == 1);
+    assert(last([7, 8, 9]) == 9);
+
+    // and from the fields of a struct instantiation:
+    let buf = Buf {
+        data: [10, 20],
+    };
+    assert(sum(buf.data) == 30);
+    assert(len(buf.data) == 2);
+
+    true
+}
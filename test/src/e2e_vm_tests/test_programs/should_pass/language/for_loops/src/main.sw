@@ -0,0 +1,51 @@
+script;
+
+use std::assert::assert;
+
+fn sum_range(start: u64, end: u64) -> u64 {
+    let mut sum = 0;
+    for i in start..end {
+        sum = sum + i;
+    }
+    sum
+}
+
+fn main() -> bool {
+    // ranges are half open:
+    assert(sum_range(0, 5) == 10);
+    assert(sum_range(3, 3) == 0);
+    assert(sum_range(5, 2) == 0);
+
+    // iterate over the elements of an array:
+    let array = [1, 2, 3, 4, 5];
+    let mut sum = 0;
+    for x in array {
+        sum = sum + x;
+    }
+    assert(sum == 15);
+
+    // iterate with a tuple pattern:
+    let pairs = [(1, 10), (2, 20), (3, 30)];
+    let mut total = 0;
+    for (a, b) in pairs {
+        total = total + a * b;
+    }
+    assert(total == 140);
+
+    // nested loops, `continue` and `break`:
+    let mut count = 0;
+    for i in 0..10 {
+        if i % 2 == 1 {
+            continue;
+        }
+        for _ in 0..i {
+            count = count + 1;
+        }
+        if i == 6 {
+            break;
+        }
+    }
+    assert(count == 12);
+
+    true
+}
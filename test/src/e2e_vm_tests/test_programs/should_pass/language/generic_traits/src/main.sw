@@ -0,0 +1,53 @@
+script;
+
+use std::convert::{From, Into};
+
+struct Meters {
+    value: u64,
+}
+
+impl From<u64> for Meters {
+    fn from(value: u64) -> Meters {
+        Meters {
+            value: value,
+        }
+    }
+}
+
+impl From<bool> for Meters {
+    fn from(b: bool) -> Meters {
+        Meters {
+            value: if b { 1 } else { 0 },
+        }
+    }
+}
+
+impl Into<u64> for Meters {
+    fn into(self) -> u64 {
+        self.value
+    }
+}
+
+trait Scale<T> {
+    fn scale(self, factor: T) -> Self;
+} {
+    fn scale_twice(self, factor: T) -> Self {
+        self.scale(factor).scale(factor)
+    }
+}
+
+impl Scale<u64> for Meters {
+    fn scale(self, factor: u64) -> Meters {
+        Meters {
+            value: self.value * factor,
+        }
+    }
+}
+
+fn main() -> bool {
+    let a = ~Meters::from(5);
+    let b = ~Meters::from(true);
+    let c = a.scale_twice(3);
+
+    a.into() == 5 && b.into() == 1 && c.into() == 45
+}
@@ -0,0 +1,7 @@
+script;
+
+use generic_fns_lib::{first, forty_two, id};
+
+fn main() -> bool {
+    id(7) == 7 && first(true, 3) && forty_two() == 42
+}
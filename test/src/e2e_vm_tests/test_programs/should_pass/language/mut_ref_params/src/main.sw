@@ -0,0 +1,46 @@
+script;
+
+struct Counter {
+    count: u64,
+}
+
+impl Counter {
+    fn increment(&mut self) {
+        self.count = self.count + 1;
+    }
+
+    fn add(&mut self, n: u64) {
+        self.count = self.count + n;
+    }
+}
+
+fn double(x: &mut u64) {
+    x = x * 2;
+}
+
+fn double_twice(x: &mut u64) {
+    double(&mut x);
+    double(&mut x);
+}
+
+fn swap(a: &mut u64, b: &mut u64) {
+    let tmp = a;
+    a = b;
+    b = tmp;
+}
+
+fn main() -> bool {
+    let mut x = 3;
+    double(&mut x);
+    double_twice(&mut x);
+
+    let mut a = 1;
+    let mut b = 2;
+    swap(&mut a, &mut b);
+
+    let mut counter = Counter { count: 0 };
+    counter.increment();
+    counter.add(10);
+
+    x == 24 && a == 2 && b == 1 && counter.count == 11
+}
@@ -0,0 +1,37 @@
+script;
+
+mod math;
+
+mod shapes {
+    pub mod square {
+        pub struct Square {
+            side: u64,
+        }
+
+        pub fn new(side: u64) -> Square {
+            Square { side: side }
+        }
+
+        pub fn side(square: Square) -> u64 {
+            square.side
+        }
+    }
+
+    pub mod area {
+        use super::square::Square;
+
+        pub fn of_square(square: Square) -> u64 {
+            let side = super::square::side(square);
+            crate::math::mul(side, side)
+        }
+    }
+}
+
+use shapes::square::Square;
+
+fn main() -> bool {
+    let square: Square = shapes::square::new(3);
+    let area = shapes::area::of_square(square);
+    let doubled = self::math::double(area);
+    area == 9 && doubled == 18
+}
@@ -0,0 +1,9 @@
+mod detail;
+
+pub fn mul(a: u64, b: u64) -> u64 {
+    detail::product(a, b)
+}
+
+pub fn double(x: u64) -> u64 {
+    self::mul(x, 2)
+}
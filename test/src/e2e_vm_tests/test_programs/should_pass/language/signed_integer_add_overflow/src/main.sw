@@ -0,0 +1,7 @@
+script;
+
+fn main() -> bool {
+    // i8::max() + 1 overflows, so this reverts.
+    let x = i8::max() + 1;
+    x == 0i8
+}
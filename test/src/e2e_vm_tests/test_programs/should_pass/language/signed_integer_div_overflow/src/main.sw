@@ -0,0 +1,7 @@
+script;
+
+fn main() -> bool {
+    // The quotient of i32::min() / -1 is i32::max() + 1, so this reverts.
+    let x = i32::min() / (0i32 - 1);
+    x == 0i32
+}
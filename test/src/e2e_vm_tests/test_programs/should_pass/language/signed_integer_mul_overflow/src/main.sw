@@ -0,0 +1,7 @@
+script;
+
+fn main() -> bool {
+    // i16::max() * 2 overflows, so this reverts.
+    let x = i16::max() * 2;
+    x == 0i16
+}
@@ -0,0 +1,7 @@
+script;
+
+fn main() -> bool {
+    // i64::min() - 1 overflows, so this reverts.
+    let x = i64::min() - 1;
+    x == 0i64
+}
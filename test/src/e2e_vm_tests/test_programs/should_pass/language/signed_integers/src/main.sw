@@ -0,0 +1,64 @@
+script;
+
+// Evaluated at compile time, through the signed arithmetic in `core::ops`.
+const NEG_TWO: i64 = 0i64 - 2;
+
+fn sign(x: i32) -> i32 {
+    if x < 0 {
+        0i32 - 1
+    } else if x > 0 {
+        1
+    } else {
+        0
+    }
+}
+
+fn describe(x: i8) -> u64 {
+    match x {
+        0i8 => 0,
+        1i8 => 1,
+        _ => 2,
+    }
+}
+
+fn main() -> bool {
+    let a = 0i64 - 7;
+    let b = 2i64;
+
+    // Arithmetic
+    let arithmetic = a + b == 0i64 - 5
+        && a - b == 0i64 - 9
+        && a * b == 0i64 - 14
+        && a * a == 49i64
+        && a / b == 0i64 - 3
+        && a % b == 0i64 - 1
+        && NEG_TWO * NEG_TWO == 4i64;
+
+    // Comparisons
+    let comparisons = a < b
+        && b > a
+        && a <= a
+        && b >= a
+        && NEG_TWO > a
+        && i64::min() < i64::max()
+        && sign(0i32 - 40) == 0i32 - 1
+        && sign(40i32) == 1i32
+        && sign(0i32) == 0i32;
+
+    // Shifts fill with the sign bit to the right, and wrap into the sign bit to the left.
+    let shifts = a >> 1 == 0i64 - 4
+        && b >> 1 == 1i64
+        && b << 2 == 8i64
+        && 1i8 << 7 == i8::min()
+        && i16::min() >> 15 == 0i16 - 1;
+
+    // Narrow types
+    let narrow = 100i8 + 27 == i8::max()
+        && i8::min() + 1 == 0i8 - 127
+        && i32::max() - i32::max() == 0i32
+        && 0i16 - 300 < 0i16
+        && describe(1i8) == 1
+        && describe(0i8 - 1) == 2;
+
+    arithmetic && comparisons && shifts && narrow
+}
@@ -0,0 +1,19 @@
+script;
+
+use std::assert::assert;
+
+fn arg_str_len<S>(s: S) -> u64 {
+    __str_len::<S>()
+}
+
+fn main() -> bool {
+    assert(__str_len::<str[1]>() == 1);
+    assert(__str_len::<str[8]>() == 8);
+    assert(__str_len::<str[11]>() == 11);
+
+    // The padding of a string is not counted.
+    assert(arg_str_len("breakfast") == 9);
+    assert(arg_str_len("fuel") == 4);
+
+    true
+}
@@ -0,0 +1,59 @@
+script;
+
+use core::ops::*;
+
+trait Score {
+    fn score(self) -> u64;
+}
+
+struct Player {
+    points: u64,
+}
+
+impl Score for Player {
+    fn score(self) -> u64 {
+        self.points
+    }
+}
+
+impl Score for u64 {
+    fn score(self) -> u64 {
+        self
+    }
+}
+
+struct Wrapper<T> where T: Score {
+    inner: T,
+}
+
+impl<T> Wrapper<T> where T: Score {
+    fn doubled_score(self) -> u64 {
+        self.inner.score() * 2
+    }
+}
+
+fn max<T>(a: T, b: T) -> T where T: Ord {
+    if a > b { a } else { b }
+}
+
+fn total_score<T>(a: T, b: T) -> u64 where T: Score {
+    a.score() + b.score()
+}
+
+fn main() -> bool {
+    let a = Player {
+        points: 3,
+    };
+    let b = Player {
+        points: 4,
+    };
+    let wrapper = Wrapper {
+        inner: 5,
+    };
+
+    max(1, 2) == 2
+        && max(7, 5) == 7
+        && total_score(a, b) == 7
+        && total_score(10, 20) == 30
+        && wrapper.doubled_score() == 10
+}
@@ -0,0 +1,80 @@
+script;
+
+use std::option::Option;
+use std::result::Result;
+
+mod units {
+    pub type Amount = u64;
+}
+
+use units::Amount;
+
+enum MyError {
+    NotFound: (),
+    TooLarge: u64,
+}
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+type Coord = Point;
+type Pair<T> = (T, T);
+type Checked<T> = Result<T, MyError>;
+type Lookup = Checked<Option<(b256, Amount)>>;
+
+fn lookup(found: bool) -> Lookup {
+    if found {
+        Result::Ok(Option::Some((0x0000000000000000000000000000000000000000000000000000000000000001, 7)))
+    } else {
+        Result::Err(MyError::NotFound)
+    }
+}
+
+fn checked(value: Amount) -> Checked<Amount> {
+    if value > 10 {
+        Result::Err(MyError::TooLarge(value))
+    } else {
+        Result::Ok(value)
+    }
+}
+
+fn swap(pair: Pair<u64>) -> Pair<u64> {
+    (pair.1, pair.0)
+}
+
+fn main() -> bool {
+    let origin: Coord = Point { x: 1, y: 2 };
+    let swapped: Pair<u64> = swap((origin.x, origin.y));
+
+    let found = if let Result::Ok(entry) = lookup(true) {
+        if let Option::Some(value) = entry {
+            value.1
+        } else {
+            0
+        }
+    } else {
+        0
+    };
+    let not_found = if let Result::Err(error) = lookup(false) {
+        if let MyError::NotFound = error {
+            true
+        } else {
+            false
+        }
+    } else {
+        false
+    };
+    let too_large = if let Result::Err(error) = checked(11) {
+        if let MyError::TooLarge(value) = error {
+            value == 11
+        } else {
+            false
+        }
+    } else {
+        false
+    };
+
+    swapped.0 == 2 && swapped.1 == 1 && found == 7 && not_found && too_large
+}
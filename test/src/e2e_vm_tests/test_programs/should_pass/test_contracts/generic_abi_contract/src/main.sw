@@ -0,0 +1,18 @@
+contract;
+
+abi Foo<T> {
+    fn foo(value: T) -> T;
+    fn bar(value: u64) -> T;
+}
+
+// The type parameter is substituted before the selectors and the JSON ABI are generated, so both
+// of these methods take and return a `u64`.
+impl Foo<u64> for Contract {
+    fn foo(value: u64) -> u64 {
+        value + 1
+    }
+
+    fn bar(value: u64) -> u64 {
+        value * 2
+    }
+}
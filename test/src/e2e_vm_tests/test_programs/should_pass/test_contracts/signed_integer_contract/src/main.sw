@@ -0,0 +1,27 @@
+contract;
+
+abi SignedIntegers {
+    fn apply_delta(balance: i64, delta: i64) -> i64;
+    fn clamp(value: i32, low: i32, high: i32) -> i32;
+    fn narrow(a: i8, b: i16) -> (i8, i16);
+}
+
+impl SignedIntegers for Contract {
+    fn apply_delta(balance: i64, delta: i64) -> i64 {
+        balance + delta
+    }
+
+    fn clamp(value: i32, low: i32, high: i32) -> i32 {
+        if value < low {
+            low
+        } else if value > high {
+            high
+        } else {
+            value
+        }
+    }
+
+    fn narrow(a: i8, b: i16) -> (i8, i16) {
+        (a, b)
+    }
+}
@@ -0,0 +1,29 @@
+contract;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+type Coord = Point;
+type Pair<T> = (T, T);
+
+// The JSON ABI describes these methods in terms of `Point` and `(u64, u64)`, since SDKs have no
+// way of resolving the alias names.
+abi TypeAliases {
+    fn midpoint(a: Coord, b: Coord) -> Coord;
+    fn swap(pair: Pair<u64>) -> Pair<u64>;
+}
+
+impl TypeAliases for Contract {
+    fn midpoint(a: Coord, b: Coord) -> Coord {
+        Point {
+            x: (a.x + b.x) / 2,
+            y: (a.y + b.y) / 2,
+        }
+    }
+
+    fn swap(pair: Pair<u64>) -> Pair<u64> {
+        (pair.1, pair.0)
+    }
+}
@@ -0,0 +1,13 @@
+library generic_fns_lib;
+
+pub fn id<T>(x: T) -> T {
+    x
+}
+
+pub fn first<T, U>(a: T, b: U) -> T {
+    a
+}
+
+pub fn forty_two() -> u64 {
+    id(42)
+}
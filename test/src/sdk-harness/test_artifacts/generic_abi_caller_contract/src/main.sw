@@ -0,0 +1,23 @@
+contract;
+
+use generic_testing_abi::Foo;
+use std::contract_id::ContractId;
+
+abi GenericAbiCaller {
+    fn call_foo(value: u64, target: ContractId) -> u64;
+    fn call_bar(value: u64, target: ContractId) -> u64;
+}
+
+// The calls go through the typed cast, so their selectors must agree with those of
+// `impl Foo<u64> for Contract` in the target.
+impl GenericAbiCaller for Contract {
+    fn call_foo(value: u64, target: ContractId) -> u64 {
+        let foo = abi(Foo<u64>, target.value);
+        foo.foo(value)
+    }
+
+    fn call_bar(value: u64, target: ContractId) -> u64 {
+        let foo = abi(Foo<u64>, target.value);
+        foo.bar(value)
+    }
+}
@@ -0,0 +1,6 @@
+library generic_testing_abi;
+
+abi Foo<T> {
+    fn foo(value: T) -> T;
+    fn bar(value: u64) -> T;
+}
@@ -0,0 +1,112 @@
+script;
+
+use std::assert::assert;
+use std::bytes::{Bytes, bytes_from_str};
+use std::hash::{keccak256_bytes, keccak256_string, sha256_bytes, sha256_string};
+use std::option::*;
+use std::string::{String, string_from_str};
+
+fn main() {
+    test_bytes_push_and_get();
+    test_bytes_append();
+    test_bytes_clear();
+    test_bytes_from_str();
+    test_string();
+    test_hashing();
+}
+
+fn test_bytes_push_and_get() {
+    let bytes = ~Bytes::new();
+    assert(bytes.is_empty());
+    assert(bytes.capacity() == 0);
+
+    // Pushing more bytes than there is room for grows the buffer.
+    let mut i = 0;
+    while i < 20 {
+        bytes.push(i);
+        i = i + 1;
+    }
+
+    assert(bytes.len() == 20);
+    assert(bytes.capacity() >= 20);
+    i = 0;
+    while i < 20 {
+        assert(bytes.get(i).unwrap() == i);
+        i = i + 1;
+    }
+    assert(bytes.get(20).is_none());
+}
+
+fn test_bytes_append() {
+    let bytes = ~Bytes::with_capacity(2);
+    bytes.push(1);
+    bytes.push(2);
+    let other = ~Bytes::new();
+    other.push(3);
+    other.push(4);
+    other.push(5);
+
+    bytes.append(other);
+    assert(bytes.len() == 5);
+    assert(bytes.get(0).unwrap() == 1);
+    assert(bytes.get(4).unwrap() == 5);
+    // `other` is left as it was.
+    assert(other.len() == 3);
+
+    // Appending to itself doubles it up.
+    bytes.append(bytes);
+    assert(bytes.len() == 10);
+    assert(bytes.get(5).unwrap() == 1);
+    assert(bytes.get(9).unwrap() == 5);
+}
+
+fn test_bytes_clear() {
+    let bytes = ~Bytes::new();
+    bytes.push(1);
+    let capacity = bytes.capacity();
+
+    bytes.clear();
+    assert(bytes.is_empty());
+    assert(bytes.capacity() == capacity);
+    assert(bytes.get(0).is_none());
+}
+
+fn test_bytes_from_str() {
+    // 11 bytes, padded to 16 in memory. The padding isn't copied.
+    let bytes = bytes_from_str("hello world");
+    assert(bytes.len() == 11);
+    assert(bytes.get(0).unwrap() == 104); // 'h'
+    assert(bytes.get(10).unwrap() == 100); // 'd'
+    assert(bytes.get(11).is_none());
+
+    let bytes = bytes_from_str("12345678");
+    assert(bytes.len() == 8);
+}
+
+fn test_string() {
+    let string = string_from_str("hello");
+    assert(string.len() == 5);
+
+    string.push(32); // ' '
+    string.append(string_from_str("world"));
+    assert(string.len() == 11);
+    assert(string.get(5).unwrap() == 32);
+    assert(string.get(10).unwrap() == 100); // 'd'
+    assert(string.as_bytes().len() == 11);
+
+    string.clear();
+    assert(string.is_empty());
+}
+
+fn test_hashing() {
+    let string = string_from_str("hello ");
+    string.append(string_from_str("world"));
+
+    // The contents are hashed, not the header pointing at them.
+    assert(sha256_string(string) == 0xb94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9);
+    assert(sha256_bytes(string.as_bytes()) == 0xb94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9);
+    assert(keccak256_string(string) == 0x47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad);
+    assert(keccak256_bytes(string.as_bytes()) == 0x47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad);
+
+    assert(sha256_bytes(~Bytes::new()) == 0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855);
+}
@@ -0,0 +1,66 @@
+use fuel_tx::ContractId;
+use fuels::prelude::*;
+use fuels_abigen_macro::abigen;
+
+abigen!(
+    GenericAbiContract,
+    "test_projects/generic_abi/out/debug/generic_abi-abi.json",
+);
+abigen!(
+    GenericAbiCallerContract,
+    "test_artifacts/generic_abi_caller_contract/out/debug/generic_abi_caller_contract-abi.json",
+);
+
+async fn get_contracts() -> (GenericAbiContract, ContractId, GenericAbiCallerContract) {
+    let (provider, wallet) = setup_test_provider_and_wallet().await;
+    let compiled_1 =
+        Contract::load_sway_contract("test_projects/generic_abi/out/debug/generic_abi.bin")
+            .unwrap();
+    let compiled_2 = Contract::load_sway_contract(
+        "test_artifacts/generic_abi_caller_contract/out/debug/generic_abi_caller_contract.bin",
+    )
+    .unwrap();
+
+    let id_1 = Contract::deploy(&compiled_1, &provider, &wallet, TxParameters::default())
+        .await
+        .unwrap();
+    let id_2 = Contract::deploy(&compiled_2, &provider, &wallet, TxParameters::default())
+        .await
+        .unwrap();
+
+    let instance_1 = GenericAbiContract::new(id_1.to_string(), provider.clone(), wallet.clone());
+    let instance_2 =
+        GenericAbiCallerContract::new(id_2.to_string(), provider.clone(), wallet.clone());
+
+    (instance_1, id_1, instance_2)
+}
+
+#[tokio::test]
+async fn can_call_generic_abi_impl() {
+    let (instance, _, _) = get_contracts().await;
+
+    let result = instance.foo(41).call().await.unwrap();
+    assert_eq!(result.value, 42);
+    let result = instance.bar(21).call().await.unwrap();
+    assert_eq!(result.value, 42);
+}
+
+#[tokio::test]
+async fn typed_abi_cast_matches_impl_selectors() {
+    let (_, id, caller_instance) = get_contracts().await;
+
+    let result = caller_instance
+        .call_foo(41, id)
+        .set_contracts(&[id])
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(result.value, 42);
+    let result = caller_instance
+        .call_bar(21, id)
+        .set_contracts(&[id])
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(result.value, 42);
+}
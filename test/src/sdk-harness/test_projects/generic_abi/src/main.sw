@@ -0,0 +1,13 @@
+contract;
+
+use generic_testing_abi::Foo;
+
+impl Foo<u64> for Contract {
+    fn foo(value: u64) -> u64 {
+        value + 1
+    }
+
+    fn bar(value: u64) -> u64 {
+        value * 2
+    }
+}
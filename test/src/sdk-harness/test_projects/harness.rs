@@ -2,10 +2,12 @@
 
 mod auth;
 mod b256_ops;
+mod bytes;
 mod call_frames;
 mod context;
 mod contract_id_type;
 mod evm_ecr;
+mod generic_abi;
 mod hashing;
 mod intrinsics;
 mod logging;
@@ -14,5 +16,11 @@ mod reentrancy;
 mod registers;
 mod require;
 mod result;
+mod storage;
+mod storage_init;
+mod storage_map;
 mod token_ops;
 mod tx_fields;
+mod u128;
+mod u256;
+mod vec;
@@ -0,0 +1,103 @@
+use fuels::prelude::*;
+use fuels::test_helpers;
+use fuels_abigen_macro::abigen;
+
+abigen!(
+    StorageTestContract,
+    "test_projects/storage/out/debug/storage-abi.json"
+);
+
+async fn get_storage_instance() -> StorageTestContract {
+    let compiled =
+        Contract::load_sway_contract("test_projects/storage/out/debug/storage.bin").unwrap();
+    let (provider, wallet) = test_helpers::setup_test_provider_and_wallet().await;
+    let id = Contract::deploy(&compiled, &provider, &wallet, TxParameters::default())
+        .await
+        .unwrap();
+
+    StorageTestContract::new(id.to_string(), provider, wallet)
+}
+
+#[tokio::test]
+async fn can_store_str() {
+    let instance = get_storage_instance().await;
+    // Long enough to span two storage slots.
+    let value = String::from("The quick brown fox jumps over a lazy do");
+
+    instance.set_str(value.clone()).call().await.unwrap();
+    let result = instance.get_str().call().await.unwrap();
+    assert_eq!(result.value, value);
+}
+
+#[tokio::test]
+async fn can_store_array() {
+    let instance = get_storage_instance().await;
+
+    instance.set_array(vec![1, 2, 3]).call().await.unwrap();
+    let result = instance.get_array().call().await.unwrap();
+    assert_eq!(result.value, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn can_store_tuple() {
+    let instance = get_storage_instance().await;
+
+    instance
+        .set_tuple((42, true, [7; 32]))
+        .call()
+        .await
+        .unwrap();
+    let result = instance.get_tuple().call().await.unwrap();
+    assert_eq!(result.value, (42, true, [7; 32]));
+}
+
+#[tokio::test]
+async fn can_store_struct_with_str() {
+    let instance = get_storage_instance().await;
+    let value = Named {
+        name: String::from("Hello, Fuel!"),
+        value: 42,
+    };
+
+    instance.set_named(value.clone()).call().await.unwrap();
+    let result = instance.get_named().call().await.unwrap();
+    assert_eq!(result.value, value);
+}
+
+#[tokio::test]
+async fn can_store_enum() {
+    let instance = get_storage_instance().await;
+
+    instance.set_enum_word(42).call().await.unwrap();
+    let result = instance.get_enum_word().call().await.unwrap();
+    assert_eq!(result.value, 42);
+    let result = instance.get_enum_pair_sum().call().await.unwrap();
+    assert_eq!(result.value, 0);
+
+    instance.set_enum_pair(20, 22).call().await.unwrap();
+    let result = instance.get_enum_pair_sum().call().await.unwrap();
+    assert_eq!(result.value, 42);
+    let result = instance.get_enum_word().call().await.unwrap();
+    assert_eq!(result.value, 0);
+
+    instance.set_enum_hash([1; 32]).call().await.unwrap();
+    let result = instance.get_enum_hash().call().await.unwrap();
+    assert_eq!(result.value, [1; 32]);
+}
+
+#[tokio::test]
+async fn can_store_option() {
+    let instance = get_storage_instance().await;
+
+    // Untouched storage is all zeroes, which reads back as the first variant, `Some(0)`.
+    let result = instance.get_option_or(7).call().await.unwrap();
+    assert_eq!(result.value, 0);
+
+    instance.set_option(42).call().await.unwrap();
+    let result = instance.get_option_or(7).call().await.unwrap();
+    assert_eq!(result.value, 42);
+
+    instance.clear_option().call().await.unwrap();
+    let result = instance.get_option_or(7).call().await.unwrap();
+    assert_eq!(result.value, 7);
+}
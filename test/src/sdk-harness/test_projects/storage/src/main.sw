@@ -0,0 +1,169 @@
+contract;
+
+use std::option::*;
+
+enum MyEnum {
+    Word: u64,
+    Pair: (u64, u64),
+    Hash: b256,
+}
+
+struct Named {
+    name: str[12],
+    value: u64,
+}
+
+abi StorageTest {
+    #[storage(write)]
+    fn set_str(value: str[40]);
+    #[storage(read)]
+    fn get_str() -> str[40];
+
+    #[storage(write)]
+    fn set_array(value: [u64; 3]);
+    #[storage(read)]
+    fn get_array() -> [u64; 3];
+
+    #[storage(write)]
+    fn set_tuple(value: (u64, bool, b256));
+    #[storage(read)]
+    fn get_tuple() -> (u64, bool, b256);
+
+    #[storage(write)]
+    fn set_named(value: Named);
+    #[storage(read)]
+    fn get_named() -> Named;
+
+    #[storage(write)]
+    fn set_enum_word(value: u64);
+    #[storage(write)]
+    fn set_enum_pair(first: u64, second: u64);
+    #[storage(write)]
+    fn set_enum_hash(value: b256);
+    #[storage(read)]
+    fn get_enum_word() -> u64;
+    #[storage(read)]
+    fn get_enum_pair_sum() -> u64;
+    #[storage(read)]
+    fn get_enum_hash() -> b256;
+
+    #[storage(write)]
+    fn set_option(value: u64);
+    #[storage(write)]
+    fn clear_option();
+    #[storage(read)]
+    fn get_option_or(default: u64) -> u64;
+}
+
+storage {
+    string: str[40],
+    array: [u64; 3],
+    tuple: (u64, bool, b256),
+    named: Named,
+    my_enum: MyEnum,
+    option: Option<u64>,
+}
+
+impl StorageTest for Contract {
+    #[storage(write)]
+    fn set_str(value: str[40]) {
+        storage.string = value;
+    }
+
+    #[storage(read)]
+    fn get_str() -> str[40] {
+        storage.string
+    }
+
+    #[storage(write)]
+    fn set_array(value: [u64; 3]) {
+        storage.array = value;
+    }
+
+    #[storage(read)]
+    fn get_array() -> [u64; 3] {
+        storage.array
+    }
+
+    #[storage(write)]
+    fn set_tuple(value: (u64, bool, b256)) {
+        storage.tuple = value;
+    }
+
+    #[storage(read)]
+    fn get_tuple() -> (u64, bool, b256) {
+        storage.tuple
+    }
+
+    #[storage(write)]
+    fn set_named(value: Named) {
+        storage.named = value;
+    }
+
+    #[storage(read)]
+    fn get_named() -> Named {
+        storage.named
+    }
+
+    #[storage(write)]
+    fn set_enum_word(value: u64) {
+        storage.my_enum = MyEnum::Word(value);
+    }
+
+    #[storage(write)]
+    fn set_enum_pair(first: u64, second: u64) {
+        storage.my_enum = MyEnum::Pair((first, second));
+    }
+
+    #[storage(write)]
+    fn set_enum_hash(value: b256) {
+        storage.my_enum = MyEnum::Hash(value);
+    }
+
+    #[storage(read)]
+    fn get_enum_word() -> u64 {
+        if let MyEnum::Word(value) = storage.my_enum {
+            value
+        } else {
+            0
+        }
+    }
+
+    #[storage(read)]
+    fn get_enum_pair_sum() -> u64 {
+        if let MyEnum::Pair(pair) = storage.my_enum {
+            pair.0 + pair.1
+        } else {
+            0
+        }
+    }
+
+    #[storage(read)]
+    fn get_enum_hash() -> b256 {
+        if let MyEnum::Hash(value) = storage.my_enum {
+            value
+        } else {
+            0x0000000000000000000000000000000000000000000000000000000000000000
+        }
+    }
+
+    #[storage(write)]
+    fn set_option(value: u64) {
+        storage.option = Option::Some(value);
+    }
+
+    #[storage(write)]
+    fn clear_option() {
+        storage.option = Option::None;
+    }
+
+    #[storage(read)]
+    fn get_option_or(default: u64) -> u64 {
+        let option = storage.option;
+        if option.is_some() {
+            option.unwrap()
+        } else {
+            default
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use fuel_tx::{consts::MAX_GAS_PER_TX, Output, Salt, StorageSlot, Transaction};
+use fuels::prelude::*;
+use fuels::test_helpers;
+use fuels_abigen_macro::abigen;
+
+abigen!(
+    StorageInitTestContract,
+    "test_projects/storage_init/out/debug/storage_init-abi.json"
+);
+
+async fn get_storage_init_instance() -> StorageInitTestContract {
+    let compiled =
+        Contract::load_sway_contract("test_projects/storage_init/out/debug/storage_init.bin")
+            .unwrap();
+    let storage_slots: Vec<StorageSlot> = serde_json::from_str(
+        &std::fs::read_to_string(
+            "test_projects/storage_init/out/debug/storage_init-storage_slots.json",
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let (provider, wallet) = test_helpers::setup_test_provider_and_wallet().await;
+
+    // `Contract::deploy` always starts with empty storage, so the create transaction is built
+    // here in the same way as `forc deploy` builds it.
+    let salt = Salt::new([0; 32]);
+    let contract = fuel_tx::Contract::from(compiled.raw.clone());
+    let root = contract.root();
+    let state_root = fuel_tx::Contract::initial_state_root(storage_slots.iter());
+    let id = contract.id(&salt, &root, &state_root);
+    let tx = Transaction::create(
+        0,
+        MAX_GAS_PER_TX,
+        0,
+        0,
+        0,
+        salt,
+        vec![],
+        storage_slots,
+        vec![],
+        vec![Output::contract_created(id, state_root)],
+        vec![compiled.raw.into()],
+    );
+    provider.send_transaction(&tx).await.unwrap();
+
+    StorageInitTestContract::new(id.to_string(), provider, wallet)
+}
+
+#[tokio::test]
+async fn initializes_words() {
+    let instance = get_storage_init_instance().await;
+
+    let result = instance.get_word().call().await.unwrap();
+    assert_eq!(result.value, 42);
+    let result = instance.get_flag().call().await.unwrap();
+    assert!(result.value);
+    let result = instance.get_hash().call().await.unwrap();
+    assert_eq!(result.value, [1; 32]);
+}
+
+#[tokio::test]
+async fn initializes_str() {
+    let instance = get_storage_init_instance().await;
+
+    let result = instance.get_str().call().await.unwrap();
+    assert_eq!(result.value, "The quick brown fox jumps over a lazy do");
+}
+
+#[tokio::test]
+async fn initializes_aggregates() {
+    let instance = get_storage_init_instance().await;
+
+    let result = instance.get_array().call().await.unwrap();
+    assert_eq!(result.value, vec![1, 2, 3]);
+    let result = instance.get_tuple().call().await.unwrap();
+    assert_eq!(result.value, (7, true, [2; 32]));
+    let result = instance.get_named().call().await.unwrap();
+    assert_eq!(
+        result.value,
+        Named {
+            name: String::from("Hello, Fuel!"),
+            value: 99,
+        }
+    );
+}
+
+#[tokio::test]
+async fn initializes_enums() {
+    let instance = get_storage_init_instance().await;
+
+    let result = instance.get_enum_hash().call().await.unwrap();
+    assert_eq!(result.value, [3; 32]);
+    let result = instance.get_option_or(7).call().await.unwrap();
+    assert_eq!(result.value, 7);
+}
+
+#[tokio::test]
+async fn initializes_from_constant_expressions() {
+    let instance = get_storage_init_instance().await;
+
+    let result = instance.get_from_const().call().await.unwrap();
+    assert_eq!(result.value, 1234);
+    let result = instance.get_from_arithmetic().call().await.unwrap();
+    assert_eq!(result.value, 33);
+}
+
+#[tokio::test]
+async fn leaves_uninitialized_fields_zeroed() {
+    let instance = get_storage_init_instance().await;
+
+    let result = instance.get_uninitialized().call().await.unwrap();
+    assert_eq!(result.value, 0);
+}
+
+#[tokio::test]
+async fn can_overwrite_initialized_field() {
+    let instance = get_storage_init_instance().await;
+
+    instance.set_word(7).call().await.unwrap();
+    let result = instance.get_word().call().await.unwrap();
+    assert_eq!(result.value, 7);
+}
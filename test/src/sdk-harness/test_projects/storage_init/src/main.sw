@@ -0,0 +1,144 @@
+contract;
+
+use std::option::*;
+
+enum MyEnum {
+    Word: u64,
+    Hash: b256,
+}
+
+const MY_CONST: u64 = 1234;
+const BASE: u64 = 11;
+
+struct Named {
+    name: str[12],
+    value: u64,
+}
+
+abi StorageInitTest {
+    #[storage(read)]
+    fn get_word() -> u64;
+    #[storage(read)]
+    fn get_flag() -> bool;
+    #[storage(read)]
+    fn get_hash() -> b256;
+    #[storage(read)]
+    fn get_str() -> str[40];
+    #[storage(read)]
+    fn get_array() -> [u64; 3];
+    #[storage(read)]
+    fn get_tuple() -> (u64, bool, b256);
+    #[storage(read)]
+    fn get_named() -> Named;
+    #[storage(read)]
+    fn get_enum_hash() -> b256;
+    #[storage(read)]
+    fn get_option_or(default: u64) -> u64;
+    #[storage(read)]
+    fn get_uninitialized() -> u64;
+    #[storage(read)]
+    fn get_from_const() -> u64;
+    #[storage(read)]
+    fn get_from_arithmetic() -> u64;
+
+    #[storage(write)]
+    fn set_word(value: u64);
+}
+
+storage {
+    word: u64 = 42,
+    flag: bool = true,
+    hash: b256 = 0x0101010101010101010101010101010101010101010101010101010101010101,
+    string: str[40] = "The quick brown fox jumps over a lazy do",
+    array: [u64; 3] = [1, 2, 3],
+    tuple: (u64, bool, b256) = (
+        7,
+        true,
+        0x0202020202020202020202020202020202020202020202020202020202020202,
+    ),
+    named: Named = Named {
+        name: "Hello, Fuel!",
+        value: 99,
+    },
+    my_enum: MyEnum = MyEnum::Hash(0x0303030303030303030303030303030303030303030303030303030303030303),
+    option: Option<u64> = Option::None,
+    uninitialized: u64,
+    from_const: u64 = MY_CONST,
+    from_arithmetic: u64 = 3 * BASE,
+}
+
+impl StorageInitTest for Contract {
+    #[storage(read)]
+    fn get_word() -> u64 {
+        storage.word
+    }
+
+    #[storage(read)]
+    fn get_flag() -> bool {
+        storage.flag
+    }
+
+    #[storage(read)]
+    fn get_hash() -> b256 {
+        storage.hash
+    }
+
+    #[storage(read)]
+    fn get_str() -> str[40] {
+        storage.string
+    }
+
+    #[storage(read)]
+    fn get_array() -> [u64; 3] {
+        storage.array
+    }
+
+    #[storage(read)]
+    fn get_tuple() -> (u64, bool, b256) {
+        storage.tuple
+    }
+
+    #[storage(read)]
+    fn get_named() -> Named {
+        storage.named
+    }
+
+    #[storage(read)]
+    fn get_enum_hash() -> b256 {
+        if let MyEnum::Hash(value) = storage.my_enum {
+            value
+        } else {
+            0x0000000000000000000000000000000000000000000000000000000000000000
+        }
+    }
+
+    #[storage(read)]
+    fn get_option_or(default: u64) -> u64 {
+        let option = storage.option;
+        if option.is_some() {
+            option.unwrap()
+        } else {
+            default
+        }
+    }
+
+    #[storage(read)]
+    fn get_uninitialized() -> u64 {
+        storage.uninitialized
+    }
+
+    #[storage(read)]
+    fn get_from_const() -> u64 {
+        storage.from_const
+    }
+
+    #[storage(read)]
+    fn get_from_arithmetic() -> u64 {
+        storage.from_arithmetic
+    }
+
+    #[storage(write)]
+    fn set_word(value: u64) {
+        storage.word = value;
+    }
+}
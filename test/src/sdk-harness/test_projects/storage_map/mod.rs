@@ -0,0 +1,123 @@
+use fuels::prelude::*;
+use fuels::test_helpers;
+use fuels_abigen_macro::abigen;
+
+abigen!(
+    StorageMapTestContract,
+    "test_projects/storage_map/out/debug/storage_map-abi.json"
+);
+
+async fn get_storage_map_instance() -> StorageMapTestContract {
+    let compiled =
+        Contract::load_sway_contract("test_projects/storage_map/out/debug/storage_map.bin")
+            .unwrap();
+    let (provider, wallet) = test_helpers::setup_test_provider_and_wallet().await;
+    let id = Contract::deploy(&compiled, &provider, &wallet, TxParameters::default())
+        .await
+        .unwrap();
+
+    StorageMapTestContract::new(id.to_string(), provider, wallet)
+}
+
+#[tokio::test]
+async fn can_insert_and_get_u64_values() {
+    let instance = get_storage_map_instance().await;
+
+    instance
+        .insert_into_u64_to_u64_map(1, 42)
+        .call()
+        .await
+        .unwrap();
+    instance
+        .insert_into_u64_to_u64_map(2, 24)
+        .call()
+        .await
+        .unwrap();
+
+    let result = instance.get_from_u64_to_u64_map(1).call().await.unwrap();
+    assert_eq!(result.value, 42);
+    let result = instance.get_from_u64_to_u64_map(2).call().await.unwrap();
+    assert_eq!(result.value, 24);
+}
+
+#[tokio::test]
+async fn can_overwrite_values() {
+    let instance = get_storage_map_instance().await;
+
+    instance
+        .insert_into_u64_to_u64_map(1, 42)
+        .call()
+        .await
+        .unwrap();
+    instance
+        .insert_into_u64_to_u64_map(1, 43)
+        .call()
+        .await
+        .unwrap();
+
+    let result = instance.get_from_u64_to_u64_map(1).call().await.unwrap();
+    assert_eq!(result.value, 43);
+}
+
+#[tokio::test]
+async fn missing_keys_are_zero() {
+    let instance = get_storage_map_instance().await;
+
+    let result = instance.get_from_u64_to_u64_map(7).call().await.unwrap();
+    assert_eq!(result.value, 0);
+    let result = instance
+        .get_from_b256_to_bool_map([7; 32])
+        .call()
+        .await
+        .unwrap();
+    assert!(!result.value);
+}
+
+#[tokio::test]
+async fn can_insert_and_get_b256_keys() {
+    let instance = get_storage_map_instance().await;
+
+    instance
+        .insert_into_b256_to_bool_map([1; 32], true)
+        .call()
+        .await
+        .unwrap();
+
+    let result = instance
+        .get_from_b256_to_bool_map([1; 32])
+        .call()
+        .await
+        .unwrap();
+    assert!(result.value);
+    let result = instance
+        .get_from_b256_to_bool_map([2; 32])
+        .call()
+        .await
+        .unwrap();
+    assert!(!result.value);
+}
+
+#[tokio::test]
+async fn maps_with_the_same_type_do_not_share_slots() {
+    let instance = get_storage_map_instance().await;
+
+    instance
+        .insert_into_u64_to_u64_map(1, 42)
+        .call()
+        .await
+        .unwrap();
+    instance
+        .insert_into_other_u64_to_u64_map(1, 24)
+        .call()
+        .await
+        .unwrap();
+
+    let result = instance.get_from_u64_to_u64_map(1).call().await.unwrap();
+    assert_eq!(result.value, 42);
+    let result = instance
+        .get_from_other_u64_to_u64_map(1)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(result.value, 24);
+}
@@ -0,0 +1,58 @@
+contract;
+
+use std::storage::StorageMap;
+
+abi StorageMapTest {
+    #[storage(write)]
+    fn insert_into_u64_to_u64_map(key: u64, value: u64);
+    #[storage(read)]
+    fn get_from_u64_to_u64_map(key: u64) -> u64;
+
+    #[storage(write)]
+    fn insert_into_b256_to_bool_map(key: b256, value: bool);
+    #[storage(read)]
+    fn get_from_b256_to_bool_map(key: b256) -> bool;
+
+    #[storage(write)]
+    fn insert_into_other_u64_to_u64_map(key: u64, value: u64);
+    #[storage(read)]
+    fn get_from_other_u64_to_u64_map(key: u64) -> u64;
+}
+
+storage {
+    map1: StorageMap<u64, u64>,
+    map2: StorageMap<b256, bool>,
+    map3: StorageMap<u64, u64>,
+}
+
+impl StorageMapTest for Contract {
+    #[storage(write)]
+    fn insert_into_u64_to_u64_map(key: u64, value: u64) {
+        storage.map1.insert(key, value);
+    }
+
+    #[storage(read)]
+    fn get_from_u64_to_u64_map(key: u64) -> u64 {
+        storage.map1.get(key)
+    }
+
+    #[storage(write)]
+    fn insert_into_b256_to_bool_map(key: b256, value: bool) {
+        storage.map2.insert(key, value);
+    }
+
+    #[storage(read)]
+    fn get_from_b256_to_bool_map(key: b256) -> bool {
+        storage.map2.get(key)
+    }
+
+    #[storage(write)]
+    fn insert_into_other_u64_to_u64_map(key: u64, value: u64) {
+        storage.map3.insert(key, value);
+    }
+
+    #[storage(read)]
+    fn get_from_other_u64_to_u64_map(key: u64) -> u64 {
+        storage.map3.get(key)
+    }
+}
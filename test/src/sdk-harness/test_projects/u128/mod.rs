@@ -0,0 +1,8 @@
+use test_helpers::script_runner;
+
+#[tokio::test]
+async fn u128() {
+    let path_to_bin = "test_projects/u128/out/debug/u128.bin";
+    let return_val = script_runner(path_to_bin).await;
+    assert_eq!(1, return_val);
+}
@@ -0,0 +1,63 @@
+script;
+
+use std::assert::assert;
+use std::u128::U128;
+
+fn main() -> bool {
+    let zero = ~U128::new();
+    let one = ~U128::from(1);
+    let max_u64 = ~U128::from(~u64::max());
+
+    // Addition carries into the upper word.
+    let sum = max_u64 + one;
+    assert(sum == ~U128::from((1, 0)));
+    let (wrapped, overflow) = ~U128::max().overflowing_add(one);
+    assert(wrapped == zero);
+    assert(overflow);
+
+    // Subtraction borrows from the upper word.
+    assert(sum - one == max_u64);
+    let (wrapped, underflow) = zero.overflowing_sub(one);
+    assert(wrapped == ~U128::max());
+    assert(underflow);
+
+    // (2^64 - 1)^2 = 2^128 - 2^65 + 1
+    let product = max_u64 * max_u64;
+    assert(product == ~U128::from((18446744073709551614, 1)));
+    let (upper, lower) = ~U128::max().mul_wide(~U128::max());
+    assert(upper == ~U128::max() - one);
+    assert(lower == one);
+
+    // 0x0123456789abcdef_fedcba9876543210 divided by 10^18.
+    let dividend = ~U128::from((81985529216486895, 18364758544493064720));
+    let divisor = ~U128::from(1000000000000000000);
+    assert(dividend / divisor == ~U128::from(1512366075204170947));
+    assert(dividend % divisor == ~U128::from(332355369683137040));
+    assert(dividend / dividend == one);
+    assert(one / dividend == zero);
+
+    // Shifts
+    assert(dividend << 68 == ~U128::from((17134975606245761280, 0)));
+    assert(dividend >> 36 == ~U128::from((1193046, 8690466096927329193)));
+    assert(dividend << 128 == zero);
+    assert(one << 127 >> 127 == one);
+
+    // Bitwise operators
+    assert((dividend & ~U128::max()) == dividend);
+    assert((dividend | zero) == dividend);
+    assert((dividend ^ dividend) == zero);
+
+    // Comparisons
+    assert(max_u64 < sum);
+    assert(sum > max_u64);
+    assert(sum >= sum);
+    assert(zero <= one);
+    assert(~U128::min() == zero);
+    assert(~U128::bits() == 128);
+
+    let (upper, lower) = sum.into();
+    assert(upper == 1);
+    assert(lower == 0);
+
+    true
+}
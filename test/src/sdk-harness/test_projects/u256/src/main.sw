@@ -0,0 +1,63 @@
+script;
+
+use std::assert::assert;
+use std::u128::U128;
+use std::u256::U256;
+
+fn main() -> bool {
+    let zero = ~U256::new();
+    let one = ~U256::from(1);
+    let max_u128 = ~U256::from(~U128::max());
+
+    // Addition carries across the U128 halves.
+    let sum = max_u128 + one;
+    assert(sum == ~U256::from((0, 1, 0, 0)));
+    let (wrapped, overflow) = ~U256::max().overflowing_add(one);
+    assert(wrapped == zero);
+    assert(overflow);
+
+    // Subtraction borrows across the U128 halves.
+    assert(sum - one == max_u128);
+    let (wrapped, underflow) = zero.overflowing_sub(one);
+    assert(wrapped == ~U256::max());
+    assert(underflow);
+
+    // 10^30 * 10^30 = 10^60, as with 18-decimal fixed point amounts.
+    let big = ~U256::from((0, 0, 54210108624, 5076944270305263616));
+    let product = big * big;
+    assert(product == ~U256::from((159, 5703569335900062977, 15520040025107828953, 1152921504606846976)));
+
+    // 10^60 divided by 3 * 10^18.
+    let divisor = ~U256::from(3000000000000000000);
+    assert(product / divisor == ~U256::from((0, 979, 10673759930089534726, 16233402244732900693)));
+    assert(product % divisor == ~U256::from(1000000000000000000));
+    assert(product / product == one);
+
+    // Shifts
+    let top_bit = one << 255;
+    assert(top_bit >> 130 == ~U256::from((0, 0, 2305843009213693952, 0)));
+    assert(~U256::from((1, 0, 0, 5)) << 70 == ~U256::from((0, 0, 320, 0)));
+    assert(top_bit << 1 == zero);
+
+    // Bitwise operators
+    assert((product & ~U256::max()) == product);
+    assert((product | zero) == product);
+    assert((product ^ product) == zero);
+
+    // Comparisons
+    assert(max_u128 < sum);
+    assert(sum > max_u128);
+    assert(top_bit >= sum);
+    assert(zero <= one);
+    assert(~U256::min() == zero);
+    assert(~U256::bits() == 256);
+
+    // Conversions to and from b256 keep the big-endian word order.
+    let bits: b256 = 0x00000000000000010000000000000002000000000000000300000000000000ff;
+    let value = ~U256::from(bits);
+    assert(value == ~U256::from((1, 2, 3, 255)));
+    let round_trip: b256 = value.into();
+    assert(round_trip == bits);
+
+    true
+}
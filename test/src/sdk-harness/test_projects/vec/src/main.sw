@@ -0,0 +1,134 @@
+script;
+
+use std::assert::assert;
+use std::option::*;
+use std::vec::Vec;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn main() {
+    test_new_is_empty();
+    test_push_and_get();
+    test_pop();
+    test_clear();
+    test_with_capacity();
+    test_reference_types();
+    test_shared_by_value();
+}
+
+fn test_new_is_empty() {
+    let vec: Vec<u64> = ~Vec::new();
+
+    assert(vec.is_empty());
+    assert(vec.len() == 0);
+    assert(vec.capacity() == 0);
+    assert(vec.get(0).is_none());
+}
+
+fn test_push_and_get() {
+    let vec: Vec<u64> = ~Vec::new();
+
+    // Pushing more elements than there is room for grows the vector.
+    let mut i = 0;
+    while i < 10 {
+        vec.push(i * 3);
+        i = i + 1;
+    }
+
+    assert(!vec.is_empty());
+    assert(vec.len() == 10);
+    assert(vec.capacity() >= 10);
+    i = 0;
+    while i < 10 {
+        assert(vec.get(i).unwrap() == i * 3);
+        i = i + 1;
+    }
+    assert(vec.get(10).is_none());
+}
+
+fn test_pop() {
+    let vec: Vec<u64> = ~Vec::new();
+    vec.push(1);
+    vec.push(2);
+
+    assert(vec.pop().unwrap() == 2);
+    assert(vec.pop().unwrap() == 1);
+    assert(vec.pop().is_none());
+    assert(vec.is_empty());
+}
+
+fn test_clear() {
+    let vec: Vec<u64> = ~Vec::new();
+    vec.push(1);
+    vec.push(2);
+    vec.push(3);
+    let capacity = vec.capacity();
+
+    vec.clear();
+    assert(vec.is_empty());
+    assert(vec.capacity() == capacity);
+    assert(vec.get(0).is_none());
+
+    vec.push(4);
+    assert(vec.len() == 1);
+    assert(vec.get(0).unwrap() == 4);
+}
+
+fn test_with_capacity() {
+    let vec: Vec<u64> = ~Vec::with_capacity(4);
+    assert(vec.capacity() == 4);
+    assert(vec.is_empty());
+
+    vec.push(1);
+    vec.push(2);
+    vec.push(3);
+    vec.push(4);
+    assert(vec.capacity() == 4);
+
+    vec.push(5);
+    assert(vec.capacity() == 8);
+    assert(vec.get(4).unwrap() == 5);
+}
+
+fn test_reference_types() {
+    let vec: Vec<Point> = ~Vec::new();
+    vec.push(Point {
+        x: 1, y: 2
+    });
+    vec.push(Point {
+        x: 3, y: 4
+    });
+
+    let first = vec.get(0).unwrap();
+    assert(first.x == 1 && first.y == 2);
+
+    // Values read from the vector are copies, unaffected by later writes.
+    let second = vec.pop().unwrap();
+    vec.push(Point {
+        x: 5, y: 6
+    });
+    assert(second.x == 3 && second.y == 4);
+    let second = vec.get(1).unwrap();
+    assert(second.x == 5 && second.y == 6);
+
+    let hashes: Vec<b256> = ~Vec::new();
+    hashes.push(0x0101010101010101010101010101010101010101010101010101010101010101);
+    hashes.push(0x0202020202020202020202020202020202020202020202020202020202020202);
+    assert(hashes.get(1).unwrap() == 0x0202020202020202020202020202020202020202020202020202020202020202);
+}
+
+fn test_shared_by_value() {
+    let vec: Vec<u64> = ~Vec::new();
+    push_two(vec);
+
+    assert(vec.len() == 2);
+    assert(vec.get(1).unwrap() == 2);
+}
+
+fn push_two(vec: Vec<u64>) {
+    vec.push(1);
+    vec.push(2);
+}